@@ -128,13 +128,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Open by serial (if we have one)
-    if let Some(first_device) = hardware_devices.first() {
-        if let Some(ref serial) = first_device.serial_number {
-            println!("Opening by serial number '{serial}'...");
-            match Xr2280x::open_by_serial(&hid_api, serial) {
-                Ok(_device) => println!("✓ Successfully opened device by serial number"),
-                Err(e) => println!("✗ Failed to open by serial: {e}"),
-            }
+    if let Some(first_device) = hardware_devices.first()
+        && let Some(ref serial) = first_device.serial_number
+    {
+        println!("Opening by serial number '{serial}'...");
+        match Xr2280x::open_by_serial(&hid_api, serial) {
+            Ok(_device) => println!("✓ Successfully opened device by serial number"),
+            Err(e) => println!("✗ Failed to open by serial: {e}"),
         }
     }
 
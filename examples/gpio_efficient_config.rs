@@ -135,14 +135,7 @@ fn main() -> Result<()> {
     println!("   Individual writes (inefficient):");
     let start = Instant::now();
     for (i, pin) in demo_pins.iter().enumerate() {
-        device.gpio_write(
-            *pin,
-            if i % 2 == 0 {
-                GpioLevel::High
-            } else {
-                GpioLevel::Low
-            },
-        )?;
+        device.gpio_write(*pin, GpioLevel::from(i % 2 == 0))?;
     }
     let individual_write_time = start.elapsed();
     println!(
@@ -295,11 +295,7 @@ fn validate_parsed_interrupt_data(
 
                     // Extract parsed state bit for this pin
                     let parsed_bit = (parsed.current_state_group0 >> pin_num) & 1;
-                    let parsed_level = if parsed_bit == 1 {
-                        GpioLevel::High
-                    } else {
-                        GpioLevel::Low
-                    };
+                    let parsed_level = GpioLevel::from(parsed_bit == 1);
 
                     // Compare with actual hardware state
                     if parsed_level != actual_level {
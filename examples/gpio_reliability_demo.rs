@@ -422,11 +422,7 @@ fn simulate_bit_banging(device: &Xr2280x, pins: &[GpioPin]) -> Result<()> {
     // Send each bit
     for bit_pos in (0..8).rev() {
         let bit_value = (data_byte >> bit_pos) & 1;
-        let level = if bit_value == 1 {
-            GpioLevel::High
-        } else {
-            GpioLevel::Low
-        };
+        let level = GpioLevel::from(bit_value == 1);
 
         // Setup data
         device.gpio_write_fast(data_pin, level)?;
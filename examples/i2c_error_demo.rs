@@ -128,6 +128,8 @@ fn demo_request_error(_device: &Xr2280x) {
     let example_addr = xr2280x_hid::I2cAddress::new_7bit(0x48).unwrap();
     let example_error = Error::I2cRequestError {
         address: example_addr,
+        write_len: 32,
+        read_len: 32,
     };
     println!("   {example_error}");
     println!("   → Tells you exactly what parameters to check!");
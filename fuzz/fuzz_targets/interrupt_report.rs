@@ -0,0 +1,13 @@
+//! Feeds arbitrary bytes to the interrupt report parser, which must never panic.
+//!
+//! Run with `cargo +nightly fuzz run interrupt_report` from the repository root.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(events) = xr2280x_hid::try_parse_interrupt(data) {
+        assert!(events.len() <= 32);
+    }
+});
@@ -0,0 +1,227 @@
+//! Async wrapper for XR2280x devices (requires the `async` feature).
+//!
+//! [`AsyncXr2280x`] owns an [`Xr2280x`] on a dedicated worker thread. Every call is
+//! sent to that thread as a command over a channel and its result comes back through
+//! a oneshot channel, so blocking HID I/O never runs on the async executor and the
+//! device handle never has to cross threads.
+//!
+//! ```no_run
+//! use xr2280x_hid::{AsyncXr2280x, GpioLevel, GpioPin};
+//!
+//! # async fn example() -> xr2280x_hid::Result<()> {
+//! let device = AsyncXr2280x::open_first().await?;
+//!
+//! let found = device.i2c_scan_default().await?;
+//! println!("Found {} I2C devices", found.len());
+//!
+//! let pin = GpioPin::new(0)?;
+//! device.gpio_write(pin, GpioLevel::High).await?;
+//!
+//! // Anything not covered by a convenience method can run through `call`
+//! let info = device.call(|dev| Ok(dev.get_device_info())).await?;
+//! println!("Serial: {:?}", info.serial_number);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::device::Xr2280x;
+use crate::error::{Error, Result};
+use crate::gpio::{GpioDirection, GpioLevel, GpioPin};
+use hidapi::HidApi;
+use log::debug;
+use std::thread;
+use tokio::sync::{mpsc, oneshot};
+
+/// A unit of work executed against the device on the worker thread.
+type Command = Box<dyn FnOnce(&Xr2280x) + Send>;
+
+/// Async handle to an XR2280x device running on a dedicated worker thread.
+///
+/// Cloning the handle is cheap and all clones share the same device; commands are
+/// executed one at a time in the order they are received. The worker thread exits
+/// and the device is closed once every clone has been dropped.
+///
+/// Only the channel types of `tokio` are used, so the handle works with any async
+/// runtime.
+#[derive(Debug, Clone)]
+pub struct AsyncXr2280x {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl AsyncXr2280x {
+    /// Opens a device on a new worker thread using `open`.
+    ///
+    /// The closure runs on the worker thread, so the resulting handle never has to
+    /// be sent between threads. Errors from `open` are returned here.
+    pub async fn open_with<F>(open: F) -> Result<Self>
+    where
+        F: FnOnce() -> Result<Xr2280x> + Send + 'static,
+    {
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let (opened_tx, opened_rx) = oneshot::channel();
+        thread::Builder::new()
+            .name("xr2280x-worker".to_string())
+            .spawn(move || match open() {
+                Ok(device) => {
+                    let _ = opened_tx.send(Ok(()));
+                    run_worker(&device, receiver);
+                }
+                Err(e) => {
+                    let _ = opened_tx.send(Err(e));
+                }
+            })?;
+        opened_rx.await.map_err(|_| worker_stopped())??;
+        Ok(Self { commands })
+    }
+
+    /// Opens the first available XR2280x device on a new worker thread.
+    pub async fn open_first() -> Result<Self> {
+        Self::open_with(|| {
+            let hid_api = HidApi::new()?;
+            Xr2280x::device_open_first(&hid_api)
+        })
+        .await
+    }
+
+    /// Opens the XR2280x device with the given serial number on a new worker thread.
+    pub async fn open_by_serial(serial: &str) -> Result<Self> {
+        let serial = serial.to_string();
+        Self::open_with(move || {
+            let hid_api = HidApi::new()?;
+            Xr2280x::open_by_serial(&hid_api, &serial)
+        })
+        .await
+    }
+
+    /// Runs `f` against the device on the worker thread and returns its result.
+    ///
+    /// Returns [`Error::DeviceDisconnected`] if the worker thread has stopped.
+    pub async fn call<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Xr2280x) -> Result<T> + Send + 'static,
+    {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        let command: Command = Box::new(move |device| {
+            let _ = reply_tx.send(f(device));
+        });
+        self.commands.send(command).map_err(|_| worker_stopped())?;
+        reply_rx.await.map_err(|_| worker_stopped())?
+    }
+
+    /// Async version of [`Xr2280x::i2c_write_7bit`].
+    pub async fn i2c_write_7bit(&self, slave_addr: u8, data: &[u8]) -> Result<()> {
+        let data = data.to_vec();
+        self.call(move |dev| dev.i2c_write_7bit(slave_addr, &data))
+            .await
+    }
+
+    /// Async version of [`Xr2280x::i2c_read_7bit`], returning `len` bytes.
+    pub async fn i2c_read_7bit(&self, slave_addr: u8, len: usize) -> Result<Vec<u8>> {
+        self.call(move |dev| {
+            let mut buffer = vec![0u8; len];
+            dev.i2c_read_7bit(slave_addr, &mut buffer)?;
+            Ok(buffer)
+        })
+        .await
+    }
+
+    /// Async version of [`Xr2280x::i2c_write_read_7bit`], returning `read_len` bytes.
+    pub async fn i2c_write_read_7bit(
+        &self,
+        slave_addr: u8,
+        write_data: &[u8],
+        read_len: usize,
+    ) -> Result<Vec<u8>> {
+        let write_data = write_data.to_vec();
+        self.call(move |dev| {
+            let mut buffer = vec![0u8; read_len];
+            dev.i2c_write_read_7bit(slave_addr, &write_data, &mut buffer)?;
+            Ok(buffer)
+        })
+        .await
+    }
+
+    /// Async version of [`Xr2280x::i2c_scan_default`].
+    pub async fn i2c_scan_default(&self) -> Result<Vec<u8>> {
+        self.call(|dev| dev.i2c_scan_default()).await
+    }
+
+    /// Async version of [`Xr2280x::gpio_set_direction`].
+    pub async fn gpio_set_direction(&self, pin: GpioPin, direction: GpioDirection) -> Result<()> {
+        self.call(move |dev| dev.gpio_set_direction(pin, direction))
+            .await
+    }
+
+    /// Async version of [`Xr2280x::gpio_write`].
+    pub async fn gpio_write(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
+        self.call(move |dev| dev.gpio_write(pin, level)).await
+    }
+
+    /// Async version of [`Xr2280x::gpio_read`].
+    pub async fn gpio_read(&self, pin: GpioPin) -> Result<GpioLevel> {
+        self.call(move |dev| dev.gpio_read(pin)).await
+    }
+}
+
+/// Executes commands until every sender has been dropped.
+fn run_worker(device: &Xr2280x, mut commands: mpsc::UnboundedReceiver<Command>) {
+    debug!("XR2280x async worker started");
+    while let Some(command) = commands.blocking_recv() {
+        command(device);
+    }
+    debug!("XR2280x async worker stopped");
+}
+
+fn worker_stopped() -> Error {
+    Error::DeviceDisconnected {
+        message: "async device worker thread has stopped".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_commands_run_in_order_on_worker_thread() {
+        let device = AsyncXr2280x::open_with(|| Ok(Xr2280x::new_for_test(32)))
+            .await
+            .unwrap();
+        let caller = thread::current().id();
+
+        let gpio_count = device
+            .call(|dev| Ok(dev.get_capabilities().gpio_count))
+            .await
+            .unwrap();
+        assert_eq!(gpio_count, 32);
+
+        let worker = device.call(|_| Ok(thread::current().id())).await.unwrap();
+        assert_ne!(worker, caller);
+
+        let clone = device.clone();
+        let results = tokio::join!(
+            device.call(|_| Ok(1)),
+            clone.call(|_| Err::<u8, _>(Error::Timeout)),
+        );
+        assert_eq!(results.0.unwrap(), 1);
+        assert!(matches!(results.1, Err(Error::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn test_open_error_is_returned() {
+        let result = AsyncXr2280x::open_with(|| Err(Error::DeviceNotFound)).await;
+        assert!(matches!(result, Err(Error::DeviceNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_panicking_command_stops_worker() {
+        let device = AsyncXr2280x::open_with(|| Ok(Xr2280x::new_for_test(8)))
+            .await
+            .unwrap();
+        let result = device.call::<(), _>(|_| panic!("command failed")).await;
+        assert!(matches!(result, Err(Error::DeviceDisconnected { .. })));
+        let result = device.call(|_| Ok(())).await;
+        assert!(matches!(result, Err(Error::DeviceDisconnected { .. })));
+    }
+}
@@ -22,6 +22,8 @@ pub mod i2c {
     // Report ID 0x00 is used for I2C_SLAVE_OUT. Implicit in hidapi.
     // Report ID for I2C_SLAVE_IN assumed 0 (implicit).
     pub const REPORT_MAX_DATA_SIZE: usize = 32;
+    // 10-bit addressing uses the first data byte for the low address byte
+    pub const REPORT_MAX_10BIT_WRITE_SIZE: usize = REPORT_MAX_DATA_SIZE - 1;
     // Size of buffer passed to hidapi write() (hidapi handles Report ID internally)
     pub const OUT_REPORT_WRITE_BUF_SIZE: usize = 36; // Flags(1) + WrSize(1) + RdSize(1) + SlaveAddr(1) + Data(32)
     // Expected size of buffer received from hidapi read() (includes Report ID byte added by hidapi)
@@ -31,6 +33,15 @@ pub mod i2c {
     pub const REG_SCL_LOW: u16 = 0x0341;
     pub const REG_SCL_HIGH: u16 = 0x0342;
 
+    // SCL timing: REG_SCL_LOW/REG_SCL_HIGH count cycles of the 60 MHz I2C clock
+    pub const SCL_CLOCK_HZ: u32 = 60_000_000;
+    pub const MAX_SPEED_HZ: u32 = 400_000;
+    // Slowest speed whose half-period still fits the 16-bit SCL registers
+    pub const MIN_SPEED_HZ: u32 = 458;
+    // Minimum SCL low/high cycles for standard mode (<= 100 kHz) and fast mode
+    pub const SCL_MIN_CYCLES_STANDARD: (u32, u32) = (252, 240);
+    pub const SCL_MIN_CYCLES_FAST: (u32, u32) = (78, 36);
+
     // I2C_SLAVE_OUT Flags (Byte 0 of OUT report buffer)
     pub mod out_flags {
         /// Generate I2C START condition at beginning of transaction.
@@ -1,12 +1,16 @@
 //! Device discovery and management functionality for XR2280x HID devices.
 
 use crate::consts;
-use crate::error::{Error, Result};
-use crate::gpio::GpioWriteConfig;
+use crate::error::{
+    Error, Result, hid_error, multiple_devices_found, unsupported_config_persistence,
+};
+use crate::gpio::{DropBehavior, GpioOutputCache, GpioWriteConfig};
+use crate::i2c::{I2cRegisterCache, I2cTransport, Timeouts};
+use crate::pwm::PwmPinAssignments;
 use hidapi::{HidApi, HidDevice};
 use log::{debug, trace, warn};
 use std::collections::HashMap;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::sync::Mutex;
 
 // HID Report Structure Constants - Register Communication
@@ -43,7 +47,11 @@ mod read_register_offsets {
 /// This struct represents a complete device that may expose multiple
 /// USB HID interfaces (I2C and EDGE). This is the recommended structure for
 /// device enumeration as it groups logical interfaces by device.
+///
+/// Marked `#[non_exhaustive]` so enumeration can report more per-device details;
+/// obtain values from [`device_find_all`] and related functions.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct XrDeviceInfo {
     /// USB vendor ID (0x04E2 for Exar Corporation).
     pub vid: u16,
@@ -55,6 +63,31 @@ pub struct XrDeviceInfo {
     pub i2c_interface: Option<InterfaceInfo>,
     /// EDGE (GPIO/PWM/Interrupt) interface information if available.
     pub edge_interface: Option<InterfaceInfo>,
+    /// Position among connected devices with the same serial number, ordered by
+    /// interface path; 0 unless several boards share this serial number.
+    ///
+    /// See [`Xr2280x::open_by_serial_and_index`].
+    pub instance_index: usize,
+}
+
+impl XrDeviceInfo {
+    /// Platform-specific path of the I2C interface, for use with [`Xr2280x::open_by_path`].
+    pub fn i2c_path(&self) -> Option<&CStr> {
+        self.i2c_interface.as_ref().map(|i| i.path.as_c_str())
+    }
+
+    /// Platform-specific path of the EDGE interface, for use with [`Xr2280x::open_by_path`].
+    pub fn edge_path(&self) -> Option<&CStr> {
+        self.edge_interface.as_ref().map(|i| i.path.as_c_str())
+    }
+
+    /// USB interface numbers as `(i2c, edge)`, for the interfaces that are present.
+    pub fn interface_numbers(&self) -> (Option<i32>, Option<i32>) {
+        (
+            self.i2c_interface.as_ref().map(|i| i.interface_number),
+            self.edge_interface.as_ref().map(|i| i.interface_number),
+        )
+    }
 }
 
 /// Finds all XR2280x devices.
@@ -96,14 +129,18 @@ fn are_serial_numbers_similar(serial1: &str, serial2: &str) -> bool {
     diff_count == 1
 }
 
-/// Find a device with a similar serial number in the HashMap.
-/// Returns the key of the similar device if found.
-fn find_similar_serial_key(
-    devices_by_serial: &HashMap<String, XrDeviceInfo>,
+/// Find a device with a similar serial number among the `(key, device)` pairs that is
+/// also accepted by `related`. Returns the key of the similar device if found.
+fn find_similar_serial_key<'a, F>(
+    devices_by_serial: impl IntoIterator<Item = (&'a String, &'a XrDeviceInfo)>,
     target_serial: &str,
-) -> Option<String> {
-    for existing_serial in devices_by_serial.keys() {
-        if are_serial_numbers_similar(existing_serial, target_serial) {
+    mut related: F,
+) -> Option<String>
+where
+    F: FnMut(&XrDeviceInfo) -> bool,
+{
+    for (existing_serial, device) in devices_by_serial {
+        if are_serial_numbers_similar(existing_serial, target_serial) && related(device) {
             return Some(existing_serial.to_string());
         }
     }
@@ -111,56 +148,93 @@ fn find_similar_serial_key(
 }
 
 pub fn device_find(hid_api: &HidApi) -> impl Iterator<Item = XrDeviceInfo> + '_ {
-    use std::collections::HashMap;
+    group_interfaces(find_logical_devices(hid_api), false).into_iter()
+}
+
+/// Finds XR2280x devices like [`device_find`], but only groups interfaces whose serial
+/// numbers differ by one character if they also sit behind the same USB hub.
+///
+/// The serial number heuristic of [`device_find`] can pair the I2C interface of one
+/// XR22802 board with the EDGE interface of another one plugged in next to it. Here
+/// such interfaces are grouped only if their USB port chains show that they are
+/// functions of the same device or of the same hub (e.g. `1-2.1` and `1-2.2`, but not
+/// `1-2.1` and `1-3.2`). Interfaces with identical serial numbers are grouped as before.
+///
+/// The port chain is read from the hidapi path: directly for libusb-style paths
+/// (`1-2.1:1.0`) and through sysfs for `/dev/hidrawN` paths on Linux. On Windows and
+/// macOS the paths carry no topology, so interfaces with merely similar serial
+/// numbers are never grouped and show up as separate devices.
+pub fn device_find_strict(hid_api: &HidApi) -> impl Iterator<Item = XrDeviceInfo> + '_ {
+    group_interfaces(find_logical_devices(hid_api), true).into_iter()
+}
 
-    // First, collect all logical interfaces
-    let mut devices_by_serial: HashMap<String, XrDeviceInfo> = HashMap::new();
+/// Groups logical interfaces into devices by serial number.
+///
+/// With `strict`, interfaces with similar (not identical) serial numbers are only
+/// grouped if [`share_usb_parent`] confirms it from their paths. Boards that share
+/// a serial number become separate devices told apart by
+/// [`XrDeviceInfo::instance_index`]; see [`select_instance`] for how their
+/// interfaces are paired.
+fn group_interfaces(
+    interfaces: impl Iterator<Item = InterfaceInfo>,
+    strict: bool,
+) -> Vec<XrDeviceInfo> {
+    // First, collect all logical interfaces. Each serial number maps to one device per
+    // board carrying it.
+    let mut devices_by_serial: HashMap<String, Vec<XrDeviceInfo>> = HashMap::new();
     let mut devices_without_serial: Vec<XrDeviceInfo> = Vec::new();
 
-    for info in find_logical_devices(hid_api) {
+    for info in interfaces {
         if let Some(serial) = &info.serial_number {
             // First try exact match
-            let device_key = if devices_by_serial.contains_key(serial) {
-                serial.clone()
-            } else if let Some(similar_key) = find_similar_serial_key(&devices_by_serial, serial) {
-                // Found a device with similar serial number - group them together
-                debug!("Grouping devices with similar serial numbers: {similar_key} and {serial}");
-                similar_key
+            let similar_key = if devices_by_serial.contains_key(serial) {
+                None
             } else {
-                serial.clone()
+                let devices = devices_by_serial
+                    .iter()
+                    .flat_map(|(key, devices)| devices.iter().map(move |device| (key, device)));
+                find_similar_serial_key(devices, serial, |existing| {
+                    !strict || share_usb_parent(existing, &info)
+                })
             };
 
-            // Check if we would overwrite an existing interface
-            let would_overwrite = if let Some(existing_device) = devices_by_serial.get(&device_key)
-            {
-                match info.pid {
-                    consts::XR2280X_I2C_PID => existing_device.i2c_interface.is_some(),
-                    consts::XR2280X_EDGE_PID => existing_device.edge_interface.is_some(),
-                    _ => false,
+            // A device with a similar serial number takes the interface if its slot is
+            // free; otherwise the interface is grouped under its own serial number
+            let similar_slot = similar_key.and_then(|key| {
+                let devices = &devices_by_serial[&key];
+                let index = devices
+                    .iter()
+                    .position(|device| slot_is_free(device, info.pid))?;
+                debug!("Grouping devices with similar serial numbers: {key} and {serial}");
+                Some((key, index))
+            });
+            let (device_key, index) = match similar_slot {
+                Some((key, index)) => (key, Some(index)),
+                None => {
+                    let devices = devices_by_serial.get(serial).map_or(&[][..], Vec::as_slice);
+                    (serial.clone(), select_instance(devices, &info))
                 }
-            } else {
-                false
-            };
-
-            // If we would overwrite an existing interface, create a new device entry instead
-            let final_device_key = if would_overwrite {
-                debug!(
-                    "Interface slot already occupied for device {device_key}, creating separate entry for {serial}"
-                );
-                serial.clone() // Use the original serial as the key for a new device
-            } else {
-                device_key
             };
 
-            let device = devices_by_serial
-                .entry(final_device_key)
-                .or_insert_with(|| XrDeviceInfo {
+            let devices = devices_by_serial.entry(device_key).or_default();
+            let index = index.unwrap_or_else(|| {
+                if !devices.is_empty() {
+                    debug!(
+                        "Serial number {serial} is shared by several devices, creating separate entry for {:?}",
+                        info.path
+                    );
+                }
+                devices.push(XrDeviceInfo {
                     vid: info.vid,
                     serial_number: info.serial_number.clone(),
                     product_string: info.product_string.clone(),
                     i2c_interface: None,
                     edge_interface: None,
+                    instance_index: 0,
                 });
+                devices.len() - 1
+            });
+            let device = &mut devices[index];
 
             // Assign to appropriate interface based on PID
             match info.pid {
@@ -176,6 +250,7 @@ pub fn device_find(hid_api: &HidApi) -> impl Iterator<Item = XrDeviceInfo> + '_
                 product_string: info.product_string.clone(),
                 i2c_interface: None,
                 edge_interface: None,
+                instance_index: 0,
             };
 
             match info.pid {
@@ -189,26 +264,295 @@ pub fn device_find(hid_api: &HidApi) -> impl Iterator<Item = XrDeviceInfo> + '_
     }
 
     // Collect and sort devices deterministically
-    let mut all_devices: Vec<XrDeviceInfo> = devices_by_serial.into_values().collect();
-    all_devices.extend(devices_without_serial);
+    let mut all_devices: Vec<XrDeviceInfo> = devices_by_serial.into_values().flatten().collect();
 
-    // Sort by serial number for deterministic ordering
+    // Sort by serial number for deterministic ordering, then by interface path so the
+    // instances of a shared serial number keep their order between enumerations
+    let first_path =
+        |device: &XrDeviceInfo| device.i2c_path().or(device.edge_path()).map(CStr::to_owned);
     all_devices.sort_by(|a, b| {
-        match (&a.serial_number, &b.serial_number) {
-            (Some(a_serial), Some(b_serial)) => a_serial.cmp(b_serial),
-            (Some(_), None) => std::cmp::Ordering::Less, // Devices with serial come first
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => std::cmp::Ordering::Equal,
-        }
+        a.serial_number
+            .cmp(&b.serial_number)
+            .then_with(|| first_path(a).cmp(&first_path(b)))
     });
+    for i in 1..all_devices.len() {
+        if all_devices[i].serial_number == all_devices[i - 1].serial_number {
+            all_devices[i].instance_index = all_devices[i - 1].instance_index + 1;
+        }
+    }
+    // Devices with serial come first
+    all_devices.extend(devices_without_serial);
+
+    all_devices
+}
+
+/// Whether `device` has no interface yet for the product ID `pid`.
+fn slot_is_free(device: &XrDeviceInfo, pid: u16) -> bool {
+    match pid {
+        consts::XR2280X_I2C_PID => device.i2c_interface.is_none(),
+        consts::XR2280X_EDGE_PID => device.edge_interface.is_none(),
+        _ => true,
+    }
+}
+
+/// Picks which of the devices sharing the serial number of `info` takes it, or `None`
+/// if it belongs to a new device.
+///
+/// A device with a free slot sitting behind the same USB parent is preferred. Failing
+/// that, a device with a free slot is taken unless the USB topology shows it is a
+/// different board. Without topology (Windows, macOS), boards sharing a serial number
+/// may therefore have their I2C and EDGE interfaces paired the wrong way round.
+fn select_instance(devices: &[XrDeviceInfo], info: &InterfaceInfo) -> Option<usize> {
+    let candidates = || {
+        devices
+            .iter()
+            .enumerate()
+            .filter(|(_, device)| slot_is_free(device, info.pid))
+    };
+    candidates()
+        .find(|(_, device)| share_usb_parent(device, info))
+        .or_else(|| candidates().find(|(_, device)| !on_other_board(device, info)))
+        .map(|(index, _)| index)
+}
+
+/// Whether the USB port chains show that an interface is not part of the same board
+/// as the interfaces already grouped into `device`. False if any chain is unknown.
+fn on_other_board(device: &XrDeviceInfo, info: &InterfaceInfo) -> bool {
+    let Some(location) = usb_location(&info.path).filter(|l| usb_port_chain(l).is_some()) else {
+        return false;
+    };
+    let mut existing = device.i2c_interface.iter().chain(&device.edge_interface);
+    existing.any(|other| {
+        usb_location(&other.path).is_some_and(|other| {
+            usb_port_chain(&other).is_some() && !paths_share_usb_parent(&location, &other)
+        })
+    })
+}
+
+/// Whether an interface sits behind the same USB device or hub as the interfaces
+/// already grouped into `device`. False if any of the port chains is unknown.
+fn share_usb_parent(device: &XrDeviceInfo, info: &InterfaceInfo) -> bool {
+    let Some(location) = usb_location(&info.path) else {
+        return false;
+    };
+    let mut existing = device.i2c_interface.iter().chain(&device.edge_interface);
+    existing.all(|other| {
+        usb_location(&other.path).is_some_and(|other| paths_share_usb_parent(&location, &other))
+    })
+}
+
+/// A path containing the USB port chain of a hidapi path, if it can be found.
+///
+/// `/dev/hidrawN` paths are resolved through sysfs on Linux; other paths are
+/// returned as they are.
+fn usb_location(path: &CStr) -> Option<String> {
+    let path = path.to_str().ok()?;
+    #[cfg(target_os = "linux")]
+    if let Some(name) = path.strip_prefix("/dev/") {
+        let device = std::path::Path::new("/sys/class/hidraw")
+            .join(name)
+            .join("device");
+        return std::fs::canonicalize(device)
+            .ok()
+            .and_then(|resolved| resolved.to_str().map(str::to_string));
+    }
+    Some(path.to_string())
+}
+
+/// Extracts the USB port chain (`1-2.4`) from a path containing a USB interface
+/// component in `bus-ports:config.interface` form (`1-2.4:1.0`).
+fn usb_port_chain(path: &str) -> Option<&str> {
+    path.split('/').rev().find_map(|component| {
+        let (chain, interface) = component.split_once(':')?;
+        let (bus, ports) = chain.split_once('-')?;
+        let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        let valid = is_number(bus)
+            && ports.split('.').all(is_number)
+            && interface
+                .split_once('.')
+                .is_some_and(|(config, number)| is_number(config) && is_number(number));
+        valid.then_some(chain)
+    })
+}
+
+/// Whether two paths belong to interfaces of the same USB device or to devices on
+/// the same (non-root) hub.
+fn paths_share_usb_parent(path1: &str, path2: &str) -> bool {
+    let (Some(chain1), Some(chain2)) = (usb_port_chain(path1), usb_port_chain(path2)) else {
+        return false;
+    };
+    if chain1 == chain2 {
+        return true;
+    }
+    // Devices on a root port have no hub parent worth comparing
+    match (chain1.rsplit_once('.'), chain2.rsplit_once('.')) {
+        (Some((parent1, _)), Some((parent2, _))) => parent1 == parent2,
+        _ => false,
+    }
+}
+
+/// Fails with [`Error::MultipleDevicesFound`] if more than one candidate matched a
+/// selector that should identify a single device.
+fn ensure_single_match(serials: Vec<Option<String>>) -> Result<()> {
+    if serials.len() > 1 {
+        return Err(multiple_devices_found(serials));
+    }
+    Ok(())
+}
+
+/// Serial numbers of HID interface entries with duplicates removed, so that the several
+/// collections one interface exposes on Windows and macOS count as one device.
+fn distinct_serials(serials: impl IntoIterator<Item = Option<String>>) -> Vec<Option<String>> {
+    let mut serials: Vec<_> = serials.into_iter().collect();
+    serials.sort();
+    serials.dedup();
+    serials
+}
+
+/// Picks the single device whose serial number starts with `prefix`.
+fn select_by_serial_prefix(devices: Vec<XrDeviceInfo>, prefix: &str) -> Result<XrDeviceInfo> {
+    let mut matches: Vec<XrDeviceInfo> = devices
+        .into_iter()
+        .filter(|info| {
+            info.serial_number
+                .as_deref()
+                .is_some_and(|serial| serial.starts_with(prefix))
+        })
+        .collect();
+    ensure_single_match(matches.iter().map(|d| d.serial_number.clone()).collect())?;
+    matches.pop().ok_or_else(|| Error::DeviceNotFoundBySerial {
+        serial: prefix.to_string(),
+        message: "No XR2280x device found with a serial number starting with this prefix"
+            .to_string(),
+    })
+}
+
+/// Picks the device with serial number `serial` and instance index `index`.
+fn select_by_serial_and_index(
+    devices: Vec<XrDeviceInfo>,
+    serial: &str,
+    index: usize,
+) -> Result<XrDeviceInfo> {
+    let mut count = 0;
+    for info in devices {
+        if info.serial_number.as_deref() == Some(serial) {
+            if info.instance_index == index {
+                return Ok(info);
+            }
+            count += 1;
+        }
+    }
+    Err(Error::DeviceNotFoundBySerial {
+        serial: serial.to_string(),
+        message: format!(
+            "No XR2280x device instance {index} with this serial number (found {count})"
+        ),
+    })
+}
 
-    all_devices.into_iter()
+/// Converts a device path string for hidapi.
+fn path_to_cstring(path: &str) -> Result<CString> {
+    CString::new(path).map_err(|e| Error::DeviceNotFoundByPath {
+        path: path.to_string(),
+        message: format!("Path contains a nul byte at position {}", e.nul_position()),
+    })
+}
+
+/// Places a handle in the (I2C, EDGE) slot matching its product ID.
+fn split_by_pid<T>(pid: u16, device: T) -> Result<(Option<T>, Option<T>)> {
+    match pid {
+        consts::XR2280X_I2C_PID => Ok((Some(device), None)),
+        consts::XR2280X_EDGE_PID => Ok((None, Some(device))),
+        _ => Err(Error::ArgumentOutOfRange(format!(
+            "HID device with PID 0x{pid:04X} is not an XR2280x I2C (0x{:04X}) or EDGE (0x{:04X}) interface",
+            consts::XR2280X_I2C_PID,
+            consts::XR2280X_EDGE_PID
+        ))),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_report_sizes_from_descriptor() {
+        #[rustfmt::skip]
+        let descriptor = [
+            0x06, 0x00, 0xFF,       // Usage Page (Vendor)
+            0x09, 0x01,             // Usage
+            0xA1, 0x01,             // Collection (Application)
+            0x75, 0x08,             //   Report Size (8)
+            0x95, 0x24,             //   Report Count (36)
+            0x81, 0x02,             //   Input
+            0xA4,                   //   Push
+            0x95, 0x10,             //   Report Count (16)
+            0xB1, 0x02,             //   Feature
+            0xB4,                   //   Pop
+            0x91, 0x02,             //   Output (36 bytes after Pop)
+            0xC0,                   // End Collection
+        ];
+        let sizes = parse_report_sizes(&descriptor);
+        assert_eq!(
+            sizes,
+            ReportSizes {
+                input: 36,
+                output: 36,
+                feature: 16,
+                numbered: false,
+            }
+        );
+        assert!(sizes.i2c_mismatches().is_empty());
+
+        // Numbered reports are sized per report ID
+        #[rustfmt::skip]
+        let numbered = [
+            0x85, 0x01, 0x75, 0x08, 0x95, 0x04, 0x81, 0x02, // ID 1: 4-byte input
+            0x85, 0x02, 0x95, 0x20, 0x81, 0x02,             // ID 2: 32-byte input
+            0x75, 0x01, 0x95, 0x03, 0x91, 0x02,             // ID 2: 3-bit output
+        ];
+        let sizes = parse_report_sizes(&numbered);
+        assert_eq!((sizes.input, sizes.output, sizes.numbered), (32, 1, true));
+        assert_eq!(parse_report_sizes(&[0x75]), ReportSizes::default());
+    }
+
+    #[test]
+    fn test_report_size_validation() {
+        let sizes = |input, output, numbered| ReportSizes {
+            input,
+            output,
+            feature: 0,
+            numbered,
+        };
+        assert!(sizes(36, 36, false).i2c_mismatches().is_empty());
+        assert!(sizes(35, 36, true).i2c_mismatches().is_empty());
+
+        let mismatches = sizes(36, 64, true).i2c_mismatches();
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches[0].contains("output report is 64 bytes"));
+        assert!(mismatches[1].contains("input report is 37 bytes"));
+        assert_eq!(sizes(64, 36, false).i2c_mismatches().len(), 1);
+    }
+
+    #[test]
+    fn test_single_edge_handle_is_gpio_only() {
+        let (i2c, edge) = split_by_pid(consts::XR2280X_EDGE_PID, "edge").unwrap();
+        assert_eq!((i2c, edge), (None, Some("edge")));
+        let (i2c, edge) = split_by_pid(consts::XR2280X_I2C_PID, "i2c").unwrap();
+        assert_eq!((i2c, edge), (Some("i2c"), None));
+        assert!(matches!(
+            split_by_pid(0x1410, "uart"),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+
+        // Without an I2C handle, I2C calls fail instead of touching the EDGE interface
+        let device = Xr2280x::new_for_test(32);
+        assert!(matches!(
+            device.i2c_write_7bit(0x50, &[0]),
+            Err(Error::DeviceNotFound)
+        ));
+    }
+
     #[test]
     fn test_are_serial_numbers_similar() {
         // Test XR22802 case - serial numbers differing by first character
@@ -259,20 +603,27 @@ mod tests {
                 product_string: Some("Test Device".to_string()),
                 i2c_interface: None,
                 edge_interface: None,
+                instance_index: 0,
             },
         );
 
         // Should find similar serial "7507DA00"
         assert_eq!(
-            find_similar_serial_key(&devices, "7507DA00"),
+            find_similar_serial_key(&devices, "7507DA00", |_| true),
             Some("6507DA00".to_string())
         );
 
         // Should not find dissimilar serial "8507DB00"
-        assert_eq!(find_similar_serial_key(&devices, "8507DB00"), None);
+        assert_eq!(
+            find_similar_serial_key(&devices, "8507DB00", |_| true),
+            None
+        );
 
         // Should not find exact match (that would be handled by contains_key)
-        assert_eq!(find_similar_serial_key(&devices, "6507DA00"), None);
+        assert_eq!(
+            find_similar_serial_key(&devices, "6507DA00", |_| true),
+            None
+        );
 
         // Add another device with different serial pattern
         devices.insert(
@@ -283,21 +634,472 @@ mod tests {
                 product_string: Some("Test Device 2".to_string()),
                 i2c_interface: None,
                 edge_interface: None,
+                instance_index: 0,
             },
         );
 
         // Should still find the first device for XR22802 pattern
         assert_eq!(
-            find_similar_serial_key(&devices, "7507DA00"),
+            find_similar_serial_key(&devices, "7507DA00", |_| true),
             Some("6507DA00".to_string())
         );
 
         // Should find the second device for its pattern
         assert_eq!(
-            find_similar_serial_key(&devices, "ABCD1235"),
+            find_similar_serial_key(&devices, "ABCD1235", |_| true),
             Some("ABCD1234".to_string())
         );
     }
+
+    fn transport_error() -> Error {
+        Error::Hid(hidapi::HidError::HidApiError {
+            message: "transient".to_string(),
+        })
+    }
+
+    /// Returns a probe closure that replays the given responses in order.
+    fn mocked_probe(responses: Vec<Result<u16>>) -> impl FnMut() -> Result<u16> {
+        let mut responses = responses.into_iter();
+        move || responses.next().expect("probe called too many times")
+    }
+
+    fn interface(pid: u16, path: &str) -> InterfaceInfo {
+        InterfaceInfo {
+            vid: consts::EXAR_VID,
+            pid,
+            path: std::ffi::CString::new(path).unwrap(),
+            serial_number: Some("ABC123".to_string()),
+            product_string: None,
+            interface_number: if pid == consts::XR2280X_EDGE_PID {
+                1
+            } else {
+                0
+            },
+        }
+    }
+
+    #[test]
+    fn test_unresponsive_device_detected() {
+        // The I2C probe addresses 0x00, which NACKs: the firmware answered
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8)
+            .with_i2c_transport(crate::i2c::MockI2cTransport::default())
+            .with_register_transport(registers.clone());
+        assert!(device.is_responsive(1000));
+        assert_eq!(registers.reads(), vec![consts::edge::REG_FUNC_SEL_0]);
+
+        let device = Xr2280x::new_for_test(8).with_register_transport(
+            MockRegisterFile::default().with_failing_register(consts::edge::REG_FUNC_SEL_0),
+        );
+        assert!(!device.is_responsive(1000));
+
+        // A stuck I2C engine sends no IN report, and EDGE is not tried after it
+        let bus = crate::i2c::MockI2cTransport::default().with_hung_engine();
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8)
+            .with_i2c_transport(bus.clone())
+            .with_register_transport(registers.clone());
+        assert!(!device.is_responsive(5));
+        assert_eq!(bus.read_timeouts(), [5]);
+        assert!(registers.reads().is_empty());
+
+        // Without interfaces the handle is never responsive
+        let device = Xr2280x::new_for_test(8);
+        assert!(!device.is_responsive(3));
+        assert!(matches!(device.ping(), Err(Error::DeviceNotFound)));
+    }
+
+    #[test]
+    fn test_device_strings_read_once() {
+        // The test handle has no HID device, so reading the strings fails
+        let device = Xr2280x::new_for_test(8);
+        *device.info_strings.lock().unwrap() = None;
+        assert_eq!(device.get_device_info().serial_number, None);
+        assert!(device.info_strings.lock().unwrap().is_none());
+
+        // Once cached, the strings are returned without reading them again
+        *device.info_strings.lock().unwrap() = Some(DeviceStrings {
+            serial_number: Some("ABC123".to_string()),
+            ..DeviceStrings::default()
+        });
+        for _ in 0..3 {
+            let info = device.get_device_info();
+            assert_eq!(info.serial_number.as_deref(), Some("ABC123"));
+        }
+    }
+
+    #[test]
+    fn test_strict_grouping_by_usb_topology() {
+        // libusb-style paths and resolved sysfs paths
+        assert_eq!(usb_port_chain("1-2.4:1.0"), Some("1-2.4"));
+        assert_eq!(
+            usb_port_chain(
+                "/sys/devices/pci0000:00/0000:00:14.0/usb1/1-2/1-2.4/1-2.4:1.0/0003:04E2:1100.0005"
+            ),
+            Some("1-2.4")
+        );
+        assert_eq!(usb_port_chain("/dev/hidraw3"), None);
+        assert_eq!(usb_port_chain("DevSrvsID:4294969123"), None);
+        assert_eq!(
+            usb_port_chain(r"\\?\hid#vid_04e2&pid_1100#7&2a3b4c5d&0&0000#{4d1e55b2}"),
+            None
+        );
+
+        // Functions of one device, or devices on the same hub, group
+        assert!(paths_share_usb_parent("1-2.4:1.0", "1-2.4:1.1"));
+        assert!(paths_share_usb_parent("3-1.2.1:1.0", "3-1.2.2:1.0"));
+        assert!(paths_share_usb_parent("1-2:1.0", "1-2:1.1"));
+        // Neighbouring boards, root ports and unknown topology don't
+        assert!(!paths_share_usb_parent("3-1.2.1:1.0", "3-1.3.2:1.0"));
+        assert!(!paths_share_usb_parent("1-2:1.0", "1-3:1.0"));
+        assert!(!paths_share_usb_parent("1-2.1:1.0", "2-2.2:1.0"));
+        assert!(!paths_share_usb_parent("1-2.1:1.0", "DevSrvsID:4294969123"));
+
+        let with_serial = |pid, path: &str, serial: &str| InterfaceInfo {
+            serial_number: Some(serial.to_string()),
+            ..interface(pid, path)
+        };
+        let interfaces = || {
+            vec![
+                with_serial(consts::XR2280X_I2C_PID, "1-1.1:1.0", "6507DA00"),
+                // EDGE interface of a neighbouring board with a close serial number
+                with_serial(consts::XR2280X_EDGE_PID, "1-3.2:1.0", "6507DA01"),
+                with_serial(consts::XR2280X_EDGE_PID, "1-1.2:1.0", "7507DA00"),
+            ]
+        };
+        let strict = group_interfaces(interfaces().into_iter(), true);
+        assert_eq!(strict.len(), 2);
+        assert_eq!(strict[0].edge_path(), Some(c"1-1.2:1.0"));
+        assert_eq!(strict[0].i2c_path(), Some(c"1-1.1:1.0"));
+        assert_eq!(strict[1].i2c_path(), None);
+
+        // The serial heuristic alone pairs the first board with the wrong EDGE interface
+        let loose = group_interfaces(interfaces().into_iter(), false);
+        assert_eq!(loose.len(), 2);
+        assert_eq!(loose[0].edge_path(), Some(c"1-3.2:1.0"));
+    }
+
+    #[test]
+    fn test_duplicate_serials_become_separate_instances() {
+        let with_serial = |pid, path: &str, serial: &str| InterfaceInfo {
+            serial_number: Some(serial.to_string()),
+            ..interface(pid, path)
+        };
+        // Two boards with the same serial number, enumerated interleaved
+        let interfaces = vec![
+            with_serial(consts::XR2280X_I2C_PID, "1-2:1.0", "ABC12345"),
+            with_serial(consts::XR2280X_EDGE_PID, "1-1:1.1", "ABC12345"),
+            with_serial(consts::XR2280X_I2C_PID, "1-1:1.0", "ABC12345"),
+            with_serial(consts::XR2280X_EDGE_PID, "1-2:1.1", "ABC12345"),
+            with_serial(consts::XR2280X_I2C_PID, "1-3:1.0", "XYZ98765"),
+        ];
+        let devices = group_interfaces(interfaces.into_iter(), false);
+        let summary: Vec<_> = devices
+            .iter()
+            .map(|d| {
+                (
+                    d.serial_number.as_deref().unwrap(),
+                    d.instance_index,
+                    d.i2c_path(),
+                    d.edge_path(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("ABC12345", 0, Some(c"1-1:1.0"), Some(c"1-1:1.1")),
+                ("ABC12345", 1, Some(c"1-2:1.0"), Some(c"1-2:1.1")),
+                ("XYZ98765", 0, Some(c"1-3:1.0"), None),
+            ]
+        );
+
+        // Without topology every interface is still kept, in a separate device per board
+        let windows = |n| format!(r"\\?\hid#vid_04e2&pid_1100#{n}");
+        let interfaces = vec![
+            with_serial(consts::XR2280X_I2C_PID, &windows(1), "ABC12345"),
+            with_serial(consts::XR2280X_EDGE_PID, &windows(2), "ABC12345"),
+            with_serial(consts::XR2280X_I2C_PID, &windows(3), "ABC12345"),
+            with_serial(consts::XR2280X_EDGE_PID, &windows(4), "ABC12345"),
+        ];
+        let devices = group_interfaces(interfaces.into_iter(), true);
+        assert_eq!(devices.len(), 2);
+        assert!(
+            devices
+                .iter()
+                .all(|d| d.i2c_interface.is_some() && d.edge_interface.is_some())
+        );
+        assert_eq!(devices[1].instance_index, 1);
+
+        let selected = select_by_serial_and_index(devices.clone(), "ABC12345", 1).unwrap();
+        assert_eq!(selected.i2c_path(), devices[1].i2c_path());
+        assert!(matches!(
+            select_by_serial_and_index(devices, "ABC12345", 2),
+            Err(Error::DeviceNotFoundBySerial { serial, message })
+                if serial == "ABC12345" && message.contains("found 2")
+        ));
+    }
+
+    #[test]
+    fn test_path_string_with_nul_is_rejected() {
+        assert_eq!(
+            path_to_cstring("/dev/hidraw3").unwrap().as_c_str(),
+            c"/dev/hidraw3"
+        );
+        assert!(matches!(
+            path_to_cstring("/dev/hid\0raw3"),
+            Err(Error::DeviceNotFoundByPath { path, message })
+                if path == "/dev/hid\0raw3" && message.contains("position 8")
+        ));
+    }
+
+    #[test]
+    fn test_select_by_serial_prefix() {
+        let devices = || {
+            ["6507DA00", "6507DA11", "9911AB00"]
+                .into_iter()
+                .map(|serial| XrDeviceInfo {
+                    vid: consts::EXAR_VID,
+                    serial_number: Some(serial.to_string()),
+                    product_string: None,
+                    i2c_interface: None,
+                    edge_interface: None,
+                    instance_index: 0,
+                })
+                .chain(std::iter::once(XrDeviceInfo {
+                    vid: consts::EXAR_VID,
+                    serial_number: None,
+                    product_string: None,
+                    i2c_interface: None,
+                    edge_interface: None,
+                    instance_index: 0,
+                }))
+                .collect::<Vec<_>>()
+        };
+
+        let found = select_by_serial_prefix(devices(), "6507DA1").unwrap();
+        assert_eq!(found.serial_number.as_deref(), Some("6507DA11"));
+        let found = select_by_serial_prefix(devices(), "9911AB00").unwrap();
+        assert_eq!(found.serial_number.as_deref(), Some("9911AB00"));
+
+        let ambiguous = select_by_serial_prefix(devices(), "6507");
+        assert!(matches!(
+            ambiguous,
+            Err(Error::MultipleDevicesFound { count: 2, serials })
+                if serials == [Some("6507DA00".to_string()), Some("6507DA11".to_string())]
+        ));
+
+        assert!(matches!(
+            select_by_serial_prefix(devices(), "7507"),
+            Err(Error::DeviceNotFoundBySerial { serial, .. }) if serial == "7507"
+        ));
+        assert!(matches!(
+            select_by_serial_prefix(vec![], ""),
+            Err(Error::DeviceNotFoundBySerial { .. })
+        ));
+    }
+
+    #[test]
+    fn test_multiple_devices_error_lists_serials() {
+        ensure_single_match(vec![]).unwrap();
+        ensure_single_match(vec![Some("ABC123".to_string())]).unwrap();
+
+        let serials = vec![Some("ABC123".to_string()), None, Some("XYZ789".to_string())];
+        let error = ensure_single_match(serials.clone()).unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::MultipleDevicesFound { count: 3, serials: found } if *found == serials
+        ));
+        let message = error.to_string();
+        assert!(message.contains("'ABC123', <none>, 'XYZ789'"), "{message}");
+
+        // Several HID collections of one interface are a single device
+        let collections = vec![Some("ABC123".to_string()); 3];
+        ensure_single_match(distinct_serials(collections)).unwrap();
+        assert_eq!(
+            distinct_serials(serials),
+            vec![None, Some("ABC123".to_string()), Some("XYZ789".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_device_info_path_accessors() {
+        let info = XrDeviceInfo {
+            vid: consts::EXAR_VID,
+            serial_number: Some("ABC123".to_string()),
+            product_string: None,
+            i2c_interface: Some(interface(consts::XR2280X_I2C_PID, "/dev/hidraw1")),
+            edge_interface: Some(interface(consts::XR2280X_EDGE_PID, "/dev/hidraw2")),
+            instance_index: 0,
+        };
+        assert_eq!(info.i2c_path(), Some(c"/dev/hidraw1"));
+        assert_eq!(info.edge_path(), Some(c"/dev/hidraw2"));
+        assert_eq!(info.interface_numbers(), (Some(0), Some(1)));
+
+        let i2c_only = XrDeviceInfo {
+            edge_interface: None,
+            ..info
+        };
+        assert_eq!(i2c_only.i2c_path(), Some(c"/dev/hidraw1"));
+        assert_eq!(i2c_only.edge_path(), None);
+        assert_eq!(i2c_only.interface_numbers(), (Some(0), None));
+    }
+
+    #[test]
+    fn test_origin_info_retains_interface_paths() {
+        let info = XrDeviceInfo {
+            vid: consts::EXAR_VID,
+            serial_number: Some("ABC123".to_string()),
+            product_string: None,
+            i2c_interface: Some(interface(consts::XR2280X_I2C_PID, "/dev/hidraw1")),
+            edge_interface: Some(interface(consts::XR2280X_EDGE_PID, "/dev/hidraw2")),
+            instance_index: 0,
+        };
+        let device = Xr2280x::new_for_test(8);
+        assert!(device.get_origin_info().is_none());
+
+        let device = device.with_origin(info.clone());
+        let origin = device.get_origin_info().unwrap();
+        assert_eq!(origin.serial_number, info.serial_number);
+        assert_eq!(
+            origin.i2c_interface.as_ref().unwrap().path,
+            info.i2c_interface.as_ref().unwrap().path
+        );
+        assert_eq!(
+            origin.edge_interface.as_ref().unwrap().path,
+            info.edge_interface.as_ref().unwrap().path
+        );
+    }
+
+    #[test]
+    fn test_verified_write_retries_after_mismatch() {
+        // The first read-back misses the write, the second sees it
+        let registers = MockRegisterFile::default().with_read_sequence(0x0341, &[0x0000]);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        device
+            .write_hid_register_verified(0x0341, 0x00F0, 0x00F0)
+            .unwrap();
+        assert_eq!(registers.writes(), vec![(0x0341, 0x00F0), (0x0341, 0x00F0)]);
+        assert_eq!(registers.reads(), vec![0x0341, 0x0341]);
+    }
+
+    #[test]
+    fn test_read_registers_in_order() {
+        let registers = MockRegisterFile::default()
+            .with_register(0x03C9, 0xFC36)
+            .with_register(0x0341, 0xFCBE)
+            .with_register(0x1A01, 0xE5FE)
+            .with_register(0x03C0, 0xFC3F);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let order = [0x03C9, 0x0341, 0x1A01, 0x03C0];
+        let values = device.read_hid_registers(&order).unwrap();
+        assert_eq!(registers.reads(), order);
+        assert_eq!(values, vec![0xFC36, 0xFCBE, 0xE5FE, 0xFC3F]);
+        assert!(device.read_hid_registers(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sync_reads_one_register_per_interface() {
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        device.sync().unwrap();
+        assert_eq!(
+            registers.reads(),
+            vec![consts::i2c::REG_SCL_LOW, consts::edge::REG_FUNC_SEL_0]
+        );
+        assert!(matches!(
+            Xr2280x::new_for_test(8).sync(),
+            Err(Error::DeviceNotFound)
+        ));
+
+        // A failed read is returned without reading the next interface
+        let registers = MockRegisterFile::default().with_failing_register(consts::i2c::REG_SCL_LOW);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        assert!(matches!(device.sync(), Err(Error::Timeout)));
+        assert!(registers.reads().is_empty());
+    }
+
+    #[test]
+    fn test_read_registers_stops_at_first_error() {
+        let registers = MockRegisterFile::default().with_failing_register(0x03C1);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let result = device.read_hid_registers(&[0x03C0, 0x03C1, 0x03C2]);
+        assert_eq!(registers.reads(), vec![0x03C0]);
+        match result {
+            Err(Error::RegisterReadFailed {
+                register: 0x03C1,
+                index: 1,
+                source,
+            }) => assert!(matches!(*source, Error::Timeout)),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verified_write_reports_last_mismatch() {
+        let registers = MockRegisterFile::default().with_read_sequence(0x03C9, &[0, 0, 0]);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let result = device.write_hid_register_verified(0x03C9, 0x0004, 0x0004);
+        assert_eq!(registers.writes().len(), 3);
+        assert!(matches!(
+            result,
+            Err(Error::RegisterVerificationFailed {
+                register: 0x03C9,
+                expected: 0x0004,
+                actual: 0x0000
+            })
+        ));
+    }
+
+    #[test]
+    fn test_detect_capabilities_register_present() {
+        let caps = detect_gpio_capabilities(mocked_probe(vec![Ok(0x0000)])).unwrap();
+        assert_eq!(caps.gpio_count, 32);
+        assert_eq!(caps.detection_confidence, DetectionConfidence::High);
+    }
+
+    #[test]
+    fn test_detect_capabilities_register_absent() {
+        let caps =
+            detect_gpio_capabilities(mocked_probe(vec![Err(Error::InvalidReport(0))])).unwrap();
+        assert_eq!(caps.gpio_count, 8);
+        assert_eq!(caps.detection_confidence, DetectionConfidence::High);
+
+        let caps = detect_gpio_capabilities(mocked_probe(vec![Err(Error::UnsupportedFeature(
+            "no group 1".to_string(),
+        ))]))
+        .unwrap();
+        assert_eq!(caps.gpio_count, 8);
+        assert_eq!(caps.detection_confidence, DetectionConfidence::High);
+    }
+
+    #[test]
+    fn test_detect_capabilities_transient_error_then_success() {
+        let caps = detect_gpio_capabilities(mocked_probe(vec![Err(transport_error()), Ok(0x0000)]))
+            .unwrap();
+        assert_eq!(caps.gpio_count, 32);
+        assert_eq!(caps.detection_confidence, DetectionConfidence::Low);
+    }
+
+    #[test]
+    fn test_detect_capabilities_transient_error_then_absent() {
+        let caps = detect_gpio_capabilities(mocked_probe(vec![
+            Err(transport_error()),
+            Err(Error::InvalidReport(1)),
+        ]))
+        .unwrap();
+        assert_eq!(caps.gpio_count, 8);
+        assert_eq!(caps.detection_confidence, DetectionConfidence::Low);
+    }
+
+    #[test]
+    fn test_detect_capabilities_persistent_error_propagates() {
+        let result = detect_gpio_capabilities(mocked_probe(vec![
+            Err(transport_error()),
+            Err(transport_error()),
+        ]));
+        assert!(matches!(result, Err(Error::Hid(_))));
+    }
 }
 
 /// Interface information for a single USB HID interface.
@@ -361,16 +1163,384 @@ pub struct XrDeviceDetails {
     pub manufacturer_string: Option<String>,
 }
 
+/// USB string descriptors of an opened device, cached by [`Xr2280x::get_device_info`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DeviceStrings {
+    manufacturer_string: Option<String>,
+    product_string: Option<String>,
+    serial_number: Option<String>,
+}
+
 /// Detected capabilities of the connected XR2280x device.
+///
+/// Marked `#[non_exhaustive]` so detection results can grow new fields; obtain
+/// values from [`Xr2280x::get_capabilities`] or [`Capabilities::default`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Capabilities {
     /// Number of GPIO pins controllable via the EDGE HID interface (8 or 32).
     pub gpio_count: u8,
+    /// How trustworthy the capability detection result is.
+    pub detection_confidence: DetectionConfidence,
 }
 
 impl Default for Capabilities {
     fn default() -> Self {
-        Capabilities { gpio_count: 8 }
+        Capabilities {
+            gpio_count: 8,
+            detection_confidence: DetectionConfidence::Low,
+        }
+    }
+}
+
+/// Confidence level of the capability detection performed when opening a device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionConfidence {
+    /// The device gave a clean answer to the GPIO Group 1 probe.
+    High,
+    /// The result is an assumption (no EDGE interface) or the probe only
+    /// succeeded after a communication error.
+    Low,
+}
+
+/// Report sizes of a HID interface, taken from its report descriptor.
+///
+/// Sizes are in bytes and exclude the report ID byte. For an interface with several
+/// reports of a kind, the largest is given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReportSizes {
+    /// Largest input (device to host) report.
+    pub input: usize,
+    /// Largest output (host to device) report.
+    pub output: usize,
+    /// Largest feature report.
+    pub feature: usize,
+    /// Whether the reports are numbered, i.e. a report ID byte precedes each report.
+    pub numbered: bool,
+}
+
+impl ReportSizes {
+    /// Describes every way these I2C interface sizes disagree with the report buffers
+    /// the crate uses. Empty if they match.
+    ///
+    /// The I2C request is written as exactly one output report, so its size must
+    /// match; a response must fit the read buffer along with a report ID, if any.
+    pub(crate) fn i2c_mismatches(&self) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if self.output != consts::i2c::OUT_REPORT_WRITE_BUF_SIZE {
+            mismatches.push(format!(
+                "output report is {} bytes, expected {}",
+                self.output,
+                consts::i2c::OUT_REPORT_WRITE_BUF_SIZE
+            ));
+        }
+        let input = self.input + usize::from(self.numbered);
+        if input > consts::i2c::IN_REPORT_READ_BUF_SIZE {
+            mismatches.push(format!(
+                "input report is {input} bytes, larger than the {}-byte read buffer",
+                consts::i2c::IN_REPORT_READ_BUF_SIZE
+            ));
+        }
+        mismatches
+    }
+}
+
+/// Determines the GPIO count by probing the GPIO Group 1 function select register.
+///
+/// A successful read means 32 GPIOs. A clean "register not present" response
+/// (`InvalidReport` or `UnsupportedFeature`) means 8 GPIOs. Any other error is
+/// treated as a communication failure: the probe is retried once, and if it
+/// fails again the error is propagated instead of silently assuming 8 GPIOs.
+fn detect_gpio_capabilities<F>(mut probe: F) -> Result<Capabilities>
+where
+    F: FnMut() -> Result<u16>,
+{
+    let classify = |result: Result<u16>| -> std::result::Result<u8, Error> {
+        match result {
+            Ok(_) => Ok(32),
+            Err(Error::InvalidReport(_)) | Err(Error::UnsupportedFeature(_)) => Ok(8),
+            Err(e) => Err(e),
+        }
+    };
+
+    match classify(probe()) {
+        Ok(gpio_count) => {
+            debug!("Detected support for {gpio_count} GPIOs");
+            Ok(Capabilities {
+                gpio_count,
+                detection_confidence: DetectionConfidence::High,
+            })
+        }
+        Err(first_err) => {
+            debug!(
+                "GPIO Group 1 probe (register 0x{:04X}) failed with communication error, retrying: {first_err}",
+                consts::edge::REG_FUNC_SEL_1
+            );
+            match classify(probe()) {
+                Ok(gpio_count) => {
+                    debug!("Detected support for {gpio_count} GPIOs after retry");
+                    Ok(Capabilities {
+                        gpio_count,
+                        detection_confidence: DetectionConfidence::Low,
+                    })
+                }
+                Err(e) => {
+                    warn!(
+                        "Capability detection failed reading GPIO Group 1 register 0x{:04X}: {e}",
+                        consts::edge::REG_FUNC_SEL_1
+                    );
+                    Err(e)
+                }
+            }
+        }
+    }
+}
+
+/// Write attempts of [`Xr2280x::write_hid_register_verified`].
+const REGISTER_VERIFY_ATTEMPTS: u32 = 3;
+
+/// Delay before [`Xr2280x::write_hid_register_verified`] retries after a mismatched
+/// read-back.
+const REGISTER_VERIFY_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(2);
+
+/// Computes the report sizes described by a HID report descriptor.
+///
+/// Only the items that affect report sizes are interpreted: Report Size, Report Count,
+/// Report ID, Push and Pop, and the Input, Output and Feature main items. Long items
+/// are skipped and a truncated item ends the parse.
+fn parse_report_sizes(descriptor: &[u8]) -> ReportSizes {
+    // (report size in bits, report count, report ID)
+    let mut globals = (0u32, 0u32, 0u8);
+    let mut stack = Vec::new();
+    // Bits per (kind, report ID); kinds are input, output and feature
+    let mut bits: HashMap<(usize, u8), u32> = HashMap::new();
+    let mut numbered = false;
+
+    let mut rest = descriptor;
+    while let Some((&prefix, tail)) = rest.split_first() {
+        if prefix == 0xFE {
+            // Long item: data size, tag, data
+            let size = tail.first().map_or(0, |&size| usize::from(size));
+            rest = tail.get(2 + size..).unwrap_or(&[]);
+            continue;
+        }
+        let size = match prefix & 0x03 {
+            3 => 4,
+            size => usize::from(size),
+        };
+        let Some(data) = tail.get(..size) else {
+            break;
+        };
+        rest = &tail[size..];
+        let value = data
+            .iter()
+            .rev()
+            .fold(0u32, |value, &byte| (value << 8) | u32::from(byte));
+        let kind = match prefix & 0xFC {
+            0x80 => Some(0),
+            0x90 => Some(1),
+            0xB0 => Some(2),
+            0x74 => {
+                globals.0 = value;
+                None
+            }
+            0x94 => {
+                globals.1 = value;
+                None
+            }
+            0x84 => {
+                globals.2 = value as u8;
+                numbered = true;
+                None
+            }
+            0xA4 => {
+                stack.push(globals);
+                None
+            }
+            0xB4 => {
+                globals = stack.pop().unwrap_or(globals);
+                None
+            }
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            *bits.entry((kind, globals.2)).or_default() += globals.0.saturating_mul(globals.1);
+        }
+    }
+
+    let largest = |kind| {
+        bits.iter()
+            .filter(|((k, _), _)| *k == kind)
+            .map(|(_, &bits)| bits.div_ceil(8) as usize)
+            .max()
+            .unwrap_or(0)
+    };
+    ReportSizes {
+        input: largest(0),
+        output: largest(1),
+        feature: largest(2),
+        numbered,
+    }
+}
+
+/// Reads the report descriptor of `device` and computes its report sizes.
+fn read_report_sizes(device: &HidDevice) -> Result<ReportSizes> {
+    let mut descriptor = vec![0u8; hidapi::MAX_REPORT_DESCRIPTOR_SIZE];
+    let len = device
+        .get_report_descriptor(&mut descriptor)
+        .map_err(hid_error)?;
+    trace!("Report descriptor: {:02X?}", &descriptor[..len]);
+    Ok(parse_report_sizes(&descriptor[..len]))
+}
+
+/// Feature report access to the register file of an interface: register writes, and
+/// register reads as a read address write followed by a read.
+///
+/// Implemented by [`HidDevice`] for real hardware. Unit tests replace it with
+/// `MockRegisterFile` through `Xr2280x::with_register_transport`.
+pub(crate) trait RegisterTransport: std::fmt::Debug + Send {
+    /// Sends a feature report, including its report ID.
+    fn send_feature_report(&self, report: &[u8]) -> Result<()>;
+
+    /// Reads the feature report whose ID is in `buffer[0]`, returning its length.
+    fn get_feature_report(&self, buffer: &mut [u8]) -> Result<usize>;
+}
+
+impl RegisterTransport for HidDevice {
+    fn send_feature_report(&self, report: &[u8]) -> Result<()> {
+        HidDevice::send_feature_report(self, report).map_err(hid_error)
+    }
+
+    fn get_feature_report(&self, buffer: &mut [u8]) -> Result<usize> {
+        HidDevice::get_feature_report(self, buffer).map_err(hid_error)
+    }
+}
+
+/// A simulated register file for unit tests, plugged in with
+/// `Xr2280x::with_register_transport`.
+///
+/// Holds a 16-bit value per register address (0 until written) and serves the
+/// register feature reports of both interfaces. Writes to a GPIO group's SET and
+/// CLEAR registers also drive the STATE bits of the pins its DIR register makes
+/// outputs, so written levels read back. Clones share the same registers, so a test
+/// can keep one to inspect them and the reads and writes made.
+#[cfg(test)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MockRegisterFile {
+    state: std::sync::Arc<Mutex<MockRegisterState>>,
+}
+
+#[cfg(test)]
+#[derive(Debug, Default)]
+struct MockRegisterState {
+    registers: HashMap<u16, u16>,
+    read_address: u16,
+    writes: Vec<(u16, u16)>,
+    reads: Vec<u16>,
+    /// Values returned by the next reads of a register, ahead of its stored value.
+    read_sequences: HashMap<u16, std::collections::VecDeque<u16>>,
+    failing: std::collections::HashSet<u16>,
+}
+
+#[cfg(test)]
+impl MockRegisterFile {
+    /// Sets register `reg` to `value` without recording a write.
+    pub(crate) fn with_register(self, reg: u16, value: u16) -> Self {
+        self.state.lock().unwrap().registers.insert(reg, value);
+        self
+    }
+
+    /// Makes the next reads of `reg` return `values` in order; later reads return the
+    /// stored value again. Writes don't change what the sequence returns.
+    pub(crate) fn with_read_sequence(self, reg: u16, values: &[u16]) -> Self {
+        let values = values.iter().copied().collect();
+        self.state
+            .lock()
+            .unwrap()
+            .read_sequences
+            .insert(reg, values);
+        self
+    }
+
+    /// Makes every read and write of `reg` fail with [`Error::Timeout`].
+    pub(crate) fn with_failing_register(self, reg: u16) -> Self {
+        self.state.lock().unwrap().failing.insert(reg);
+        self
+    }
+
+    /// Current value of register `reg`.
+    pub(crate) fn register(&self, reg: u16) -> u16 {
+        let state = self.state.lock().unwrap();
+        state.registers.get(&reg).copied().unwrap_or(0)
+    }
+
+    /// Every `(register, value)` write so far.
+    pub(crate) fn writes(&self) -> Vec<(u16, u16)> {
+        self.state.lock().unwrap().writes.clone()
+    }
+
+    /// Every register read so far.
+    pub(crate) fn reads(&self) -> Vec<u16> {
+        self.state.lock().unwrap().reads.clone()
+    }
+}
+
+#[cfg(test)]
+impl MockRegisterState {
+    /// Applies a SET or CLEAR write to the STATE bits of its group's output pins.
+    fn drive_outputs(&mut self, reg: u16, value: u16) {
+        use consts::edge::*;
+        let (state_reg, dir_reg, high) = match reg {
+            REG_SET_0 => (REG_STATE_0, REG_DIR_0, true),
+            REG_CLEAR_0 => (REG_STATE_0, REG_DIR_0, false),
+            REG_SET_1 => (REG_STATE_1, REG_DIR_1, true),
+            REG_CLEAR_1 => (REG_STATE_1, REG_DIR_1, false),
+            _ => return,
+        };
+        let outputs = value & self.registers.get(&dir_reg).copied().unwrap_or(0);
+        let state = self.registers.entry(state_reg).or_default();
+        if high {
+            *state |= outputs;
+        } else {
+            *state &= !outputs;
+        }
+    }
+}
+
+#[cfg(test)]
+impl RegisterTransport for MockRegisterFile {
+    fn send_feature_report(&self, report: &[u8]) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        let word = |low: usize| u16::from_le_bytes([report[low], report[low + 1]]);
+        let reg = word(write_register_offsets::ADDR_LOW);
+        if state.failing.contains(&reg) {
+            return Err(Error::Timeout);
+        }
+        match report[write_register_offsets::REPORT_ID] {
+            consts::REPORT_ID_WRITE_HID_REGISTER => {
+                let value = word(write_register_offsets::VALUE_LOW);
+                state.registers.insert(reg, value);
+                state.writes.push((reg, value));
+                state.drive_outputs(reg, value);
+            }
+            consts::REPORT_ID_SET_HID_READ_ADDRESS => state.read_address = reg,
+            id => return Err(Error::InvalidReport(usize::from(id))),
+        }
+        Ok(())
+    }
+
+    fn get_feature_report(&self, buffer: &mut [u8]) -> Result<usize> {
+        let mut state = self.state.lock().unwrap();
+        let reg = state.read_address;
+        state.reads.push(reg);
+        let queued = state
+            .read_sequences
+            .get_mut(&reg)
+            .and_then(|values| values.pop_front());
+        let value = queued.unwrap_or_else(|| state.registers.get(&reg).copied().unwrap_or(0));
+        buffer[read_register_offsets::VALUE_LOW..=read_register_offsets::VALUE_HIGH]
+            .copy_from_slice(&value.to_le_bytes());
+        Ok(3)
     }
 }
 
@@ -382,8 +1552,30 @@ pub struct Xr2280x {
     pub(crate) i2c_device: Option<HidDevice>,
     pub(crate) edge_device: Option<HidDevice>,
     pub(crate) info: XrDeviceDetails,
+    pub(crate) info_strings: Mutex<Option<DeviceStrings>>,
     pub(crate) capabilities: Capabilities,
     pub(crate) gpio_write_config: Mutex<GpioWriteConfig>,
+    pub(crate) gpio_drop_behavior: Mutex<DropBehavior>,
+    pub(crate) gpio_output_cache: Mutex<GpioOutputCache>,
+    pub(crate) gpio_strict: Mutex<bool>,
+    pub(crate) gpio_active_low: Mutex<u32>,
+    pub(crate) i2c_timeout_scale: Mutex<f64>,
+    pub(crate) i2c_timeouts: Mutex<Timeouts>,
+    pub(crate) i2c_register_cache: Mutex<I2cRegisterCache>,
+    pub(crate) pwm_pins: Mutex<PwmPinAssignments>,
+    pub(crate) origin: Option<XrDeviceInfo>,
+    /// Replaces `i2c_device` for I2C transfers in unit tests.
+    #[cfg(test)]
+    pub(crate) i2c_transport: Option<Box<dyn I2cTransport>>,
+    /// Replaces both interfaces for register access in unit tests.
+    #[cfg(test)]
+    pub(crate) register_transport: Option<Box<dyn RegisterTransport>>,
+}
+
+impl Drop for Xr2280x {
+    fn drop(&mut self) {
+        self.gpio_apply_drop_behavior();
+    }
 }
 
 impl Xr2280x {
@@ -426,17 +1618,92 @@ impl Xr2280x {
             return Err(Error::DeviceNotFound);
         }
 
-        Self::from_hid_devices(i2c_device, edge_device)
+        Ok(Self::from_hid_devices(i2c_device, edge_device)?.with_origin(info.clone()))
+    }
+
+    /// Reopens the same physical device, e.g. after a transient USB error.
+    ///
+    /// The interfaces are reopened by the paths stored when this handle was opened with
+    /// [`device_open`](Self::device_open). If that fails (the paths can change after a
+    /// re-plug), the device is looked up again by serial number and instance index. Call
+    /// [`HidApi::refresh_devices`] first so the serial number lookup sees the current bus.
+    /// The GPIO write configuration, GPIO strict mode, GPIO polarities, I2C timeouts and
+    /// timeout scale and PWM pin assignments of this handle are carried over,
+    /// and the GPIO drop behavior is moved to the new handle so dropping this one no longer applies it.
+    /// The I2C register cache is not carried over, since the targets may have been reset.
+    pub fn reopen(&self, hid_api: &HidApi) -> Result<Self> {
+        let serial = self
+            .origin
+            .as_ref()
+            .and_then(|origin| origin.serial_number.clone())
+            .or_else(|| self.get_device_info().serial_number);
+
+        let reopened = match &self.origin {
+            Some(origin) => Self::device_open(hid_api, origin).or_else(|e| match &serial {
+                Some(serial) => {
+                    debug!("Reopen by path failed ({e}), retrying by serial {serial}");
+                    Self::open_by_serial_and_index(hid_api, serial, origin.instance_index)
+                }
+                None => Err(e),
+            }),
+            None => match &serial {
+                Some(serial) => Self::open_by_serial(hid_api, serial),
+                None => Err(Error::DeviceNotFound),
+            },
+        }?;
+
+        reopened.gpio_set_write_config(self.gpio_get_write_config())?;
+        reopened.gpio_strict_mode(*self.gpio_strict.lock().unwrap());
+        *reopened.gpio_active_low.lock().unwrap() = *self.gpio_active_low.lock().unwrap();
+        *reopened.i2c_timeout_scale.lock().unwrap() = self.i2c_timeout_scale();
+        *reopened.i2c_timeouts.lock().unwrap() = self.timeouts();
+        *reopened.pwm_pins.lock().unwrap() = *self.pwm_pins.lock().unwrap();
+        *reopened.gpio_drop_behavior.lock().unwrap() =
+            std::mem::take(&mut *self.gpio_drop_behavior.lock().unwrap());
+        Ok(reopened)
+    }
+
+    /// Lists the serial number of every connected XR2280x device, in enumeration order.
+    ///
+    /// Useful for choosing a device after [`Error::MultipleDevicesFound`]; devices
+    /// without a readable serial number are listed as `None`.
+    pub fn list_serials(hid_api: &HidApi) -> Result<Vec<Option<String>>> {
+        Ok(Self::device_enumerate(hid_api)?
+            .into_iter()
+            .map(|info| info.serial_number)
+            .collect())
     }
 
     /// Opens the first device found. Convenient but ambiguous if multiple devices exist.
+    ///
+    /// If several devices are connected, a warning listing their serial numbers is
+    /// logged; use [`open_by_serial`](Self::open_by_serial) to pick one.
     pub fn device_open_first(hid_api: &HidApi) -> Result<Self> {
-        let info = device_find_first(hid_api)?;
-        Self::device_open(hid_api, &info)
+        let devices = device_find_all(hid_api)?;
+        let info = devices.first().ok_or(Error::DeviceNotFound)?;
+        if let Err(e) =
+            ensure_single_match(devices.iter().map(|d| d.serial_number.clone()).collect())
+        {
+            warn!("Opening the first device: {e}");
+        }
+        Self::device_open(hid_api, info)
     }
 
-    /// Opens a device by its Vendor ID and Product ID. **Warning:** Ambiguous if multiple devices match.
+    /// Opens a device by its Vendor ID and Product ID.
+    ///
+    /// **Warning:** Ambiguous if multiple devices match. If interfaces with different
+    /// serial numbers match, a warning listing them is logged and the first one is
+    /// opened; use [`open_by_serial`](Self::open_by_serial) to pick one.
     pub fn open_by_vid_pid(hid_api: &HidApi, vid: u16, pid: u16) -> Result<Self> {
+        let serials = distinct_serials(
+            hid_api
+                .device_list()
+                .filter(|info| info.vendor_id() == vid && info.product_id() == pid)
+                .map(|info| info.serial_number().map(str::to_string)),
+        );
+        if let Err(e) = ensure_single_match(serials) {
+            warn!("Opening the first matching interface: {e}");
+        }
         let device = hid_api.open(vid, pid)?;
 
         // Determine which interface this is and assign appropriately
@@ -468,16 +1735,27 @@ impl Xr2280x {
         }
     }
 
+    /// Opens a device by its platform-specific path given as a string, e.g.
+    /// `/dev/hidraw3` or a path copied from a log or from [`XrDeviceInfo::i2c_path`].
+    ///
+    /// Same as [`Xr2280x::open_by_path`]. A path containing a nul byte can't name a
+    /// device and gives [`Error::DeviceNotFoundByPath`] without touching hidapi.
+    pub fn open_by_path_str(hid_api: &HidApi, path: &str) -> Result<Self> {
+        Self::open_by_path(hid_api, &path_to_cstring(path)?)
+    }
+
     /// Opens a device by its serial number.
     /// Searches through all XR2280x devices to find one with the matching serial number.
+    /// If several boards share the serial number, the first instance is opened; use
+    /// [`Xr2280x::open_by_serial_and_index`] to pick another one.
     pub fn open_by_serial(hid_api: &HidApi, serial: &str) -> Result<Self> {
         let devices = Self::device_enumerate(hid_api)?;
 
         for device_info in devices {
-            if let Some(device_serial) = &device_info.serial_number {
-                if device_serial == serial {
-                    return Self::device_open(hid_api, &device_info);
-                }
+            if let Some(device_serial) = &device_info.serial_number
+                && device_serial == serial
+            {
+                return Self::device_open(hid_api, &device_info);
             }
         }
 
@@ -487,6 +1765,30 @@ impl Xr2280x {
         })
     }
 
+    /// Opens the `index`-th device with the serial number `serial`, for boards that
+    /// share a serial number.
+    ///
+    /// Instances are numbered by [`XrDeviceInfo::instance_index`], which follows the
+    /// interface paths, so the same board keeps its index as long as it stays on the
+    /// same USB port. Returns [`Error::DeviceNotFoundBySerial`] if there is no such
+    /// instance.
+    pub fn open_by_serial_and_index(hid_api: &HidApi, serial: &str, index: usize) -> Result<Self> {
+        let device_info =
+            select_by_serial_and_index(Self::device_enumerate(hid_api)?, serial, index)?;
+        Self::device_open(hid_api, &device_info)
+    }
+
+    /// Opens the device whose serial number starts with `prefix`.
+    ///
+    /// Handy when only the end of otherwise identical serial numbers differs: any
+    /// prefix that is unique among the connected devices selects one. Returns
+    /// [`Error::MultipleDevicesFound`] listing the candidates if several devices match,
+    /// and [`Error::DeviceNotFoundBySerial`] if none does.
+    pub fn open_by_serial_prefix(hid_api: &HidApi, prefix: &str) -> Result<Self> {
+        let device_info = select_by_serial_prefix(Self::device_enumerate(hid_api)?, prefix)?;
+        Self::device_open(hid_api, &device_info)
+    }
+
     /// Opens a device by its index in the enumeration order.
     /// Index is 0-based and corresponds to the order returned by device_enumerate().
     pub fn open_by_index(hid_api: &HidApi, index: usize) -> Result<Self> {
@@ -526,61 +1828,318 @@ impl Xr2280x {
 
         debug!("Creating XR2280x from HidDevices: VID={vid:04X}");
 
-        let manufacturer_string = info_device
-            .get_manufacturer_string()?
-            .map(|s| s.to_string());
-        let product_string = info_device.get_product_string()?.map(|s| s.to_string());
-        let serial_number = info_device
-            .get_serial_number_string()?
-            .map(|s| s.to_string());
+        // The strings are read on first use by `get_device_info`
         let info = XrDeviceDetails {
             vendor_id: vid,
             product_id: 0, // Not meaningful for hardware device
-            serial_number,
-            product_string,
-            manufacturer_string,
+            serial_number: None,
+            product_string: None,
+            manufacturer_string: None,
         };
         trace!("Hardware Device Info: {info:?}");
 
         // --- Capability Detection ---
-        let temp_handle = Self {
+        let mut handle = Self {
             i2c_device,
             edge_device,
-            info: info.clone(),
+            info,
+            info_strings: Mutex::new(None),
             capabilities: Capabilities::default(),
             gpio_write_config: Mutex::new(GpioWriteConfig::default()),
+            gpio_drop_behavior: Mutex::new(DropBehavior::default()),
+            gpio_output_cache: Mutex::new(GpioOutputCache::default()),
+            gpio_strict: Mutex::new(false),
+            gpio_active_low: Mutex::new(0),
+            i2c_timeout_scale: Mutex::new(1.0),
+            i2c_timeouts: Mutex::new(Timeouts::default()),
+            i2c_register_cache: Mutex::new(I2cRegisterCache::default()),
+            pwm_pins: Mutex::new(PwmPinAssignments::default()),
+            origin: None,
+            #[cfg(test)]
+            i2c_transport: None,
+            #[cfg(test)]
+            register_transport: None,
         };
 
-        let capabilities = if temp_handle.edge_device.is_some() {
-            match temp_handle.read_hid_register(consts::edge::REG_FUNC_SEL_1) {
-                Ok(_) => {
-                    debug!("Detected support for 32 GPIOs");
-                    Capabilities { gpio_count: 32 }
-                }
-                Err(e) => {
-                    debug!(
-                        "Detected support for 8 GPIOs (failed to read GPIO Group 1 register): {e}"
-                    );
-                    Capabilities { gpio_count: 8 }
+        if let Some(i2c_device) = &handle.i2c_device {
+            match read_report_sizes(i2c_device) {
+                Ok(sizes) => {
+                    for mismatch in sizes.i2c_mismatches() {
+                        warn!("Unexpected I2C interface report size: {mismatch}");
+                    }
                 }
+                Err(e) => debug!("Could not read the I2C report descriptor: {e}"),
             }
+        }
+
+        handle.capabilities = if handle.edge_device.is_some() {
+            detect_gpio_capabilities(|| handle.read_hid_register(consts::edge::REG_FUNC_SEL_1))?
         } else {
             debug!("No EDGE interface available, assuming 8 GPIOs");
-            Capabilities { gpio_count: 8 }
+            Capabilities::default()
         };
 
-        Ok(Self {
-            i2c_device: temp_handle.i2c_device,
-            edge_device: temp_handle.edge_device,
-            info,
-            capabilities,
+        Ok(handle)
+    }
+
+    /// Creates an Xr2280x instance from a single opened HidDevice handle.
+    ///
+    /// The handle's product ID decides whether it is the I2C or the EDGE interface.
+    /// A handle for the EDGE interface gives a GPIO/PWM-capable device without I2C;
+    /// the other interface can be attached later with [`Self::with_edge_device`] or
+    /// [`Self::with_i2c_device`]. Useful when handles are opened through a custom
+    /// permission flow.
+    pub fn from_single_device(device: HidDevice) -> Result<Self> {
+        let pid = device.get_device_info().map_err(Error::Hid)?.product_id();
+        let (i2c_device, edge_device) = split_by_pid(pid, device)?;
+        Self::from_hid_devices(i2c_device, edge_device)
+    }
+
+    /// Attaches (or replaces) the EDGE interface handle and re-detects GPIO capabilities.
+    ///
+    /// Returns an error if the handle is not an XR2280x EDGE interface.
+    pub fn with_edge_device(mut self, edge_device: HidDevice) -> Result<Self> {
+        let pid = edge_device
+            .get_device_info()
+            .map_err(Error::Hid)?
+            .product_id();
+        let (_, edge_device) = split_by_pid(pid, edge_device)?;
+        let edge_device = edge_device.ok_or_else(|| {
+            Error::ArgumentOutOfRange(format!(
+                "HID device with PID 0x{pid:04X} is not an XR2280x EDGE interface"
+            ))
+        })?;
+        self.edge_device = Some(edge_device);
+        self.capabilities =
+            detect_gpio_capabilities(|| self.read_hid_register(consts::edge::REG_FUNC_SEL_1))?;
+        debug!(
+            "Attached EDGE interface, {} GPIOs",
+            self.capabilities.gpio_count
+        );
+        Ok(self)
+    }
+
+    /// Attaches (or replaces) the I2C interface handle.
+    ///
+    /// Returns an error if the handle is not an XR2280x I2C interface.
+    pub fn with_i2c_device(mut self, i2c_device: HidDevice) -> Result<Self> {
+        let pid = i2c_device
+            .get_device_info()
+            .map_err(Error::Hid)?
+            .product_id();
+        let (i2c_device, _) = split_by_pid(pid, i2c_device)?;
+        self.i2c_device = Some(i2c_device.ok_or_else(|| {
+            Error::ArgumentOutOfRange(format!(
+                "HID device with PID 0x{pid:04X} is not an XR2280x I2C interface"
+            ))
+        })?);
+        debug!("Attached I2C interface");
+        Ok(self)
+    }
+
+    /// Records the enumeration info this handle was opened from.
+    fn with_origin(mut self, origin: XrDeviceInfo) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Creates a handle without any HID interfaces for unit tests.
+    #[cfg(test)]
+    pub(crate) fn new_for_test(gpio_count: u8) -> Self {
+        Self {
+            i2c_device: None,
+            edge_device: None,
+            info: XrDeviceDetails {
+                vendor_id: consts::EXAR_VID,
+                product_id: 0,
+                serial_number: None,
+                product_string: None,
+                manufacturer_string: None,
+            },
+            info_strings: Mutex::new(Some(DeviceStrings::default())),
+            capabilities: Capabilities {
+                gpio_count,
+                detection_confidence: DetectionConfidence::High,
+            },
             gpio_write_config: Mutex::new(GpioWriteConfig::default()),
-        })
+            gpio_drop_behavior: Mutex::new(DropBehavior::default()),
+            gpio_output_cache: Mutex::new(GpioOutputCache::default()),
+            gpio_strict: Mutex::new(false),
+            gpio_active_low: Mutex::new(0),
+            i2c_timeout_scale: Mutex::new(1.0),
+            i2c_timeouts: Mutex::new(Timeouts::default()),
+            i2c_register_cache: Mutex::new(I2cRegisterCache::default()),
+            pwm_pins: Mutex::new(PwmPinAssignments::default()),
+            origin: None,
+            i2c_transport: None,
+            register_transport: None,
+        }
+    }
+
+    /// Routes I2C transfers of a test handle through `transport`.
+    #[cfg(test)]
+    pub(crate) fn with_i2c_transport(mut self, transport: impl I2cTransport + 'static) -> Self {
+        self.i2c_transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Routes register access of a test handle through `transport`.
+    #[cfg(test)]
+    pub(crate) fn with_register_transport(
+        mut self,
+        transport: impl RegisterTransport + 'static,
+    ) -> Self {
+        self.register_transport = Some(Box::new(transport));
+        self
+    }
+
+    /// The transport I2C transfers go through.
+    pub(crate) fn i2c_transport(&self) -> Result<&dyn I2cTransport> {
+        #[cfg(test)]
+        if let Some(transport) = &self.i2c_transport {
+            return Ok(transport.as_ref());
+        }
+        Ok(self.i2c_device.as_ref().ok_or(Error::DeviceNotFound)?)
     }
 
     /// Gets basic information about the opened device.
+    ///
+    /// The manufacturer, product and serial number strings are read from the device on
+    /// the first call and cached, so opening a device doesn't wait for them. If reading
+    /// them fails they are `None` and the next call tries again.
     pub fn get_device_info(&self) -> XrDeviceDetails {
-        self.info.clone()
+        let strings = {
+            let mut cache = self.info_strings.lock().unwrap();
+            match cache.as_ref() {
+                Some(strings) => strings.clone(),
+                None => match self.read_device_strings() {
+                    Ok(strings) => cache.insert(strings).clone(),
+                    Err(e) => {
+                        warn!("Failed to read device strings: {e}");
+                        DeviceStrings::default()
+                    }
+                },
+            }
+        };
+        XrDeviceDetails {
+            serial_number: strings.serial_number,
+            product_string: strings.product_string,
+            manufacturer_string: strings.manufacturer_string,
+            ..self.info.clone()
+        }
+    }
+
+    fn read_device_strings(&self) -> Result<DeviceStrings> {
+        let device = self
+            .edge_device
+            .as_ref()
+            .or(self.i2c_device.as_ref())
+            .ok_or(Error::DeviceNotFound)?;
+        trace!("Reading device strings");
+        Ok(DeviceStrings {
+            manufacturer_string: device.get_manufacturer_string()?,
+            product_string: device.get_product_string()?,
+            serial_number: device.get_serial_number_string()?,
+        })
+    }
+
+    /// Checks that the firmware answers on every opened interface, for watchdogs that
+    /// reconnect a hung device (see [`Self::reopen`]).
+    ///
+    /// The I2C interface is probed with a transfer to the reserved address 0x00 that
+    /// must complete within `timeout_ms`; the EDGE interface must answer
+    /// [`Self::ping`] within `timeout_ms`. The I2C probe runs first, so a hung I2C
+    /// engine is reported after `timeout_ms`. Feature report reads can't be cancelled,
+    /// though: a hung EDGE interface only returns `false` once the operating system's
+    /// control transfer timeout (typically a few seconds) has passed.
+    ///
+    /// A handle without any interface is never responsive.
+    pub fn is_responsive(&self, timeout_ms: i32) -> bool {
+        let has_i2c = self.i2c_transport().is_ok();
+        let has_edge = self
+            .register_transport(consts::edge::REG_FUNC_SEL_0)
+            .is_ok();
+        if !has_i2c && !has_edge {
+            return false;
+        }
+        if has_i2c && let Err(e) = self.i2c_probe_firmware(timeout_ms) {
+            warn!("I2C interface is not responsive: {e}");
+            return false;
+        }
+        if has_edge {
+            let limit = std::time::Duration::from_millis(u64::try_from(timeout_ms).unwrap_or(0));
+            let start = std::time::Instant::now();
+            let result = self.read_hid_register(consts::edge::REG_FUNC_SEL_0);
+            let elapsed = start.elapsed();
+            match result {
+                Err(e) => {
+                    warn!("EDGE interface is not responsive: {e}");
+                    return false;
+                }
+                Ok(_) if elapsed > limit => {
+                    warn!("EDGE interface answered after {elapsed:?}, limit is {limit:?}");
+                    return false;
+                }
+                Ok(_) => {}
+            }
+        }
+        true
+    }
+
+    /// Reads a harmless register to check that the device answers.
+    ///
+    /// Reads the EDGE function select register, or the I2C SCL timing register on an
+    /// I2C-only handle. Nothing is changed on the device.
+    ///
+    /// **Performance**: Uses 2 HID transactions.
+    pub fn ping(&self) -> Result<()> {
+        let register = if self.edge_device.is_some() {
+            consts::edge::REG_FUNC_SEL_0
+        } else {
+            consts::i2c::REG_SCL_LOW
+        };
+        self.read_hid_register(register).map(|_| ())
+    }
+
+    /// Waits until the device has processed every HID operation issued before it.
+    ///
+    /// Reads a harmless register on each opened interface, as [`Self::ping`] does: the
+    /// EDGE function select register and the I2C SCL timing register. Feature reports
+    /// of one interface are handled in order, so once the read returns, every earlier
+    /// register write on that interface has landed. Nothing is changed on the device.
+    ///
+    /// The crate doesn't buffer writes today: each method returns after its HID
+    /// transfers completed, and I2C transfers wait for their response report. `sync` is
+    /// an explicit barrier before timing-critical reads that keeps working if batching
+    /// is added, and doubles as a liveness check. Returns [`Error::DeviceNotFound`] for
+    /// a handle without any interface.
+    ///
+    /// **Performance**: Uses 2 HID transactions per opened interface.
+    pub fn sync(&self) -> Result<()> {
+        let registers: Vec<u16> = [consts::i2c::REG_SCL_LOW, consts::edge::REG_FUNC_SEL_0]
+            .into_iter()
+            .filter(|&reg| self.register_transport(reg).is_ok())
+            .collect();
+        if registers.is_empty() {
+            return Err(Error::DeviceNotFound);
+        }
+        for reg in registers {
+            self.read_hid_register(reg)?;
+        }
+        trace!("Synchronized with device");
+        Ok(())
+    }
+
+    /// Reads the report sizes of the I2C interface from its HID report descriptor.
+    ///
+    /// Useful when debugging protocol issues: the I2C functions assume a 36-byte
+    /// output report and at most 36 bytes per input report. Opening a device already
+    /// logs a warning if the descriptor disagrees. Returns [`Error::DeviceNotFound`]
+    /// without an I2C interface, and an error if the platform's hidapi backend can't
+    /// provide the descriptor.
+    pub fn report_sizes(&self) -> Result<ReportSizes> {
+        let i2c_device = self.i2c_device.as_ref().ok_or(Error::DeviceNotFound)?;
+        read_report_sizes(i2c_device)
     }
 
     /// Gets the detected capabilities (e.g., GPIO count) of the connected device.
@@ -588,17 +2147,53 @@ impl Xr2280x {
         self.capabilities
     }
 
+    /// Would store the current GPIO and I2C configuration as the device's power-up
+    /// defaults. Not supported by any XR2280x model.
+    ///
+    /// | Model | Persistent GPIO/I2C defaults |
+    /// |-------|------------------------------|
+    /// | XR22800/XR22801 | No |
+    /// | XR22802/XR22804 | No |
+    ///
+    /// The GPIO, PWM and I2C registers reset to their hardware defaults on power-up and
+    /// the HID interfaces have no command to store them. Always returns
+    /// [`Error::UnsupportedFeature`] without accessing the device; apply the
+    /// configuration after opening instead, e.g. with [`Xr2280x::setup`].
+    pub fn device_save_config(&self) -> Result<()> {
+        Err(unsupported_config_persistence())
+    }
+
+    /// Would restore the configuration stored by [`Xr2280x::device_save_config`].
+    /// Not supported by any XR2280x model; always returns [`Error::UnsupportedFeature`].
+    pub fn device_load_config(&self) -> Result<()> {
+        Err(unsupported_config_persistence())
+    }
+
+    /// Gets the enumeration info (serial number and interface paths) this handle was opened from.
+    /// Returns `None` if the handle was not opened via [`device_open`](Self::device_open).
+    pub fn get_origin_info(&self) -> Option<&XrDeviceInfo> {
+        self.origin.as_ref()
+    }
+
     // --- Register Access ---
-    // Wrap HID errors with register context
-    pub(crate) fn write_hid_register(&self, reg_addr: u16, value: u16) -> Result<()> {
-        // Determine which device to use based on register address
+    /// The transport reaching `reg_addr`: the I2C interface for the I2C registers
+    /// (0x0340-0x0342), the EDGE interface (GPIO/PWM/Interrupt) for the rest.
+    pub(crate) fn register_transport(&self, reg_addr: u16) -> Result<&dyn RegisterTransport> {
+        #[cfg(test)]
+        if let Some(transport) = &self.register_transport {
+            return Ok(transport.as_ref());
+        }
         let device = if (0x0340..=0x0342).contains(&reg_addr) {
-            // I2C registers
-            self.i2c_device.as_ref().ok_or(Error::DeviceNotFound)?
+            self.i2c_device.as_ref()
         } else {
-            // EDGE registers (GPIO/PWM/Interrupt)
-            self.edge_device.as_ref().ok_or(Error::DeviceNotFound)?
+            self.edge_device.as_ref()
         };
+        Ok(device.ok_or(Error::DeviceNotFound)?)
+    }
+
+    // Wrap HID errors with register context
+    pub(crate) fn write_hid_register(&self, reg_addr: u16, value: u16) -> Result<()> {
+        let device = self.register_transport(reg_addr)?;
 
         let mut buf = [0u8; 5];
         buf[write_register_offsets::REPORT_ID] = consts::REPORT_ID_WRITE_HID_REGISTER;
@@ -616,20 +2211,68 @@ impl Xr2280x {
             Ok(_) => Ok(()), // Treat any Ok as success
             Err(e) => {
                 trace!("send_feature_report error for register 0x{reg_addr:04X}: {e}");
-                Err(Error::Hid(e))
+                Err(e)
             }
         }
     }
 
+    /// Writes a register and reads it back, retrying until the bits in `mask` match.
+    /// Used for configuration writes where a silent failure would be costly.
+    pub(crate) fn write_hid_register_verified(
+        &self,
+        reg_addr: u16,
+        value: u16,
+        mask: u16,
+    ) -> Result<()> {
+        let expected = value & mask;
+        let mut actual = 0;
+        for attempt in 1..=REGISTER_VERIFY_ATTEMPTS {
+            self.write_hid_register(reg_addr, value)?;
+            actual = self.read_hid_register(reg_addr)? & mask;
+            if actual == expected {
+                return Ok(());
+            }
+            warn!(
+                "Register 0x{reg_addr:04X} verification mismatch on attempt {attempt}/{REGISTER_VERIFY_ATTEMPTS}: expected 0x{expected:04X}, read 0x{actual:04X}"
+            );
+            if attempt < REGISTER_VERIFY_ATTEMPTS {
+                std::thread::sleep(REGISTER_VERIFY_RETRY_DELAY);
+            }
+        }
+        Err(Error::RegisterVerificationFailed {
+            register: reg_addr,
+            expected,
+            actual,
+        })
+    }
+
+    /// Reads a list of raw registers, returning their values in the same order.
+    ///
+    /// Each register is routed to the I2C or EDGE interface like a single read. Reading
+    /// stops at the first failure, which is returned as [`Error::RegisterReadFailed`]
+    /// naming the register and its position in `registers`. Intended for diagnostic
+    /// dumps; there is no batching on the wire.
+    ///
+    /// **Performance**: Uses 2 HID transactions per register.
+    pub fn read_hid_registers(&self, registers: &[u16]) -> Result<Vec<u16>> {
+        let values = registers
+            .iter()
+            .enumerate()
+            .map(|(index, &register)| {
+                self.read_hid_register(register)
+                    .map_err(|e| Error::RegisterReadFailed {
+                        register,
+                        index,
+                        source: Box::new(e),
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        debug!("Read {} register(s)", values.len());
+        Ok(values)
+    }
+
     pub(crate) fn set_hid_read_address(&self, reg_addr: u16) -> Result<()> {
-        // Determine which device to use based on register address
-        let device = if (0x0340..=0x0342).contains(&reg_addr) {
-            // I2C registers
-            self.i2c_device.as_ref().ok_or(Error::DeviceNotFound)?
-        } else {
-            // EDGE registers (GPIO/PWM/Interrupt)
-            self.edge_device.as_ref().ok_or(Error::DeviceNotFound)?
-        };
+        let device = self.register_transport(reg_addr)?;
 
         let buf: [u8; 3] = [
             consts::REPORT_ID_SET_HID_READ_ADDRESS,
@@ -645,7 +2288,7 @@ impl Xr2280x {
             Ok(_) => Ok(()), // Treat any Ok as success
             Err(e) => {
                 trace!("send_feature_report error while setting read address: {e}");
-                Err(Error::Hid(e))
+                Err(e)
             }
         }
     }
@@ -653,14 +2296,7 @@ impl Xr2280x {
     pub(crate) fn read_hid_register(&self, reg_addr: u16) -> Result<u16> {
         self.set_hid_read_address(reg_addr)?;
 
-        // Determine which device to use based on register address
-        let device = if (0x0340..=0x0342).contains(&reg_addr) {
-            // I2C registers
-            self.i2c_device.as_ref().ok_or(Error::DeviceNotFound)?
-        } else {
-            // EDGE registers (GPIO/PWM/Interrupt)
-            self.edge_device.as_ref().ok_or(Error::DeviceNotFound)?
-        };
+        let device = self.register_transport(reg_addr)?;
 
         let mut buf = [0u8; 3];
         buf[read_register_offsets::REPORT_ID] = consts::REPORT_ID_READ_HID_REGISTER;
@@ -695,7 +2331,7 @@ impl Xr2280x {
             }
             Err(e) => {
                 trace!("get_feature_report error for register 0x{reg_addr:04X}: {e}");
-                Err(Error::Hid(e))
+                Err(e)
             }
         }
     }
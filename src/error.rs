@@ -40,11 +40,26 @@ pub enum Error {
         message: String,
     },
     /// Multiple XR2280x devices were found when only one was expected.
-    #[error("Multiple devices found ({count}): {message}")]
+    ///
+    /// `serials` lists the candidates so one can be selected, e.g. with
+    /// [`Xr2280x::open_by_serial`](crate::Xr2280x::open_by_serial).
+    #[error(
+        "Multiple devices found ({count}) with serial numbers [{}]; select one by serial number",
+        format_serials(serials)
+    )]
     MultipleDevicesFound {
         /// The number of devices that were found.
         count: usize,
-        /// Additional context about the ambiguity.
+        /// Serial number of each device found, in enumeration order.
+        serials: Vec<Option<String>>,
+    },
+    /// The device was opened successfully but has since gone away (e.g. unplugged).
+    ///
+    /// Unlike [`Error::DeviceNotFound`], which means no device was ever found, this
+    /// indicates the handle is no longer usable and the device should be reopened.
+    #[error("Device disconnected: {message}")]
+    DeviceDisconnected {
+        /// The underlying transport error message.
         message: String,
     },
     /// General I/O error during device communication.
@@ -95,6 +110,30 @@ pub enum Error {
         /// Description of the configuration conflict.
         message: String,
     },
+    /// A GPIO operation was attempted in strict mode on a pin not assigned to EDGE.
+    #[error(
+        "GPIO pin {pin} is not assigned to the EDGE controller; call gpio_assign_to_edge() first"
+    )]
+    GpioPinNotAssigned {
+        /// The GPIO pin number that is not assigned.
+        pin: u8,
+    },
+    /// No pin is registered under the requested label in a [`PinMap`](crate::pin_map::PinMap).
+    #[error("No GPIO pin labeled '{label}'")]
+    UnknownPinLabel {
+        /// The label that was looked up.
+        label: String,
+    },
+    /// A pin is already serving a function that conflicts with the requested use.
+    #[error("Pin {pin} is in use as {current:?}: {message}")]
+    PinFunctionConflict {
+        /// The pin number that was requested.
+        pin: u8,
+        /// The function the pin is currently serving.
+        current: crate::gpio::PinFunction,
+        /// Description of the conflict.
+        message: String,
+    },
     /// GPIO hardware-specific error.
     #[error("GPIO hardware error on pin {pin}: {message}. Check pin connections and device power.")]
     GpioHardwareError {
@@ -103,6 +142,29 @@ pub enum Error {
         /// Description of the hardware issue.
         message: String,
     },
+    /// Register write verification failed - read-back did not match the written value.
+    #[error(
+        "Register 0x{register:04X} write verification failed: expected 0x{expected:04X}, read back 0x{actual:04X}"
+    )]
+    RegisterVerificationFailed {
+        /// The register address that was written.
+        register: u16,
+        /// The expected value of the verified bits.
+        expected: u16,
+        /// The value of the verified bits that was read back on the last attempt.
+        actual: u16,
+    },
+    /// Reading one register of a multi-register read failed.
+    #[error("Reading register 0x{register:04X} (entry {index}) failed: {source}")]
+    RegisterReadFailed {
+        /// The register address that could not be read.
+        register: u16,
+        /// Position of the register in the requested list.
+        index: usize,
+        /// The underlying error.
+        #[source]
+        source: Box<Error>,
+    },
     /// GPIO write verification failed - pin did not reach expected level.
     #[error(
         "GPIO write verification failed for pin {pin} on attempt {attempt}: expected {expected:?}, but pin reads {actual:?}. This indicates a hardware timing issue or pin conflict."
@@ -117,6 +179,20 @@ pub enum Error {
         /// The attempt number when verification failed.
         attempt: u32,
     },
+    /// Masked GPIO write verification failed - some pins did not reach the written levels.
+    #[error(
+        "GPIO masked write verification failed on {group:?}: pins 0x{failed_mask:04X} did not take (expected 0x{expected:04X}, read 0x{actual:04X})"
+    )]
+    GpioMaskedWriteVerificationFailed {
+        /// The GPIO group that was written.
+        group: crate::gpio::GpioGroup,
+        /// Bits of the write mask whose STATE readback differs from the written value.
+        failed_mask: u16,
+        /// The values written, limited to the write mask.
+        expected: u16,
+        /// The STATE readback, limited to the write mask.
+        actual: u16,
+    },
     /// GPIO operation timed out before completion.
     #[error(
         "GPIO {operation} operation on pin {pin} timed out after {timeout_ms}ms. This may indicate hardware issues or excessive retry delays."
@@ -191,11 +267,15 @@ pub enum Error {
     }, // Keep specific I2C timeout
     /// I2C transaction failed due to invalid request parameters.
     #[error(
-        "I2C request error at address {address}: Invalid parameters sent to XR2280x firmware. Check data length (max 32 bytes), address validity, and operation flags."
+        "I2C request error at address {address} (write {write_len} bytes, read {read_len} bytes): Invalid parameters sent to XR2280x firmware. Check data length (max 32 bytes), address validity, and operation flags."
     )]
     I2cRequestError {
         /// The I2C address being accessed when the error occurred.
         address: I2cAddress,
+        /// Number of bytes the rejected request tried to write.
+        write_len: usize,
+        /// Number of bytes the rejected request tried to read.
+        read_len: usize,
     },
     /// I2C transaction failed with unknown error condition.
     #[error(
@@ -217,8 +297,14 @@ pub enum Error {
         actual: usize,
     },
     /// Requested operation exceeds device or protocol limits.
-    #[error("Requested operation size is too large (max {max}, got {actual})")]
+    ///
+    /// Marked `#[non_exhaustive]` so more context can be added without breaking
+    /// patterns again; match it with `{ max, actual, .. }`.
+    #[error("Requested {operation} size is too large (max {max}, got {actual})")]
+    #[non_exhaustive]
     OperationTooLarge {
+        /// Which part of the operation exceeded the limit (e.g. "I2C write").
+        operation: String,
         /// Maximum allowed size for this operation.
         max: usize,
         /// Actual size requested.
@@ -241,8 +327,183 @@ pub enum Error {
 /// throughout the crate to reduce boilerplate.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The subsystem an [`Error`](enum@Error) originates from, as reported by [`Error::category`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// I2C bus transactions and addressing.
+    I2c,
+    /// GPIO configuration, access and interrupts.
+    Gpio,
+    /// PWM channel configuration.
+    Pwm,
+    /// Device discovery, capabilities, arguments and register access.
+    Device,
+    /// HID transport and operating system I/O.
+    Io,
+}
+
+impl std::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ErrorCategory::I2c => "I2C",
+            ErrorCategory::Gpio => "GPIO",
+            ErrorCategory::Pwm => "PWM",
+            ErrorCategory::Device => "device",
+            ErrorCategory::Io => "I/O",
+        };
+        f.write_str(name)
+    }
+}
+
+impl Error {
+    /// Returns `true` if retrying the same operation may succeed.
+    ///
+    /// This covers I2C NACKs, timeouts, lost arbitration and transient transport
+    /// failures (interrupted or incomplete HID transfers). Configuration errors,
+    /// missing or disconnected devices and exhausted retries are permanent.
+    ///
+    /// ```
+    /// use xr2280x_hid::{Error, I2cAddress};
+    ///
+    /// let nack = Error::I2cNack { address: I2cAddress::new_7bit(0x50).unwrap() };
+    /// assert!(nack.is_transient());
+    /// assert!(!Error::DeviceNotFound.is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::I2cNack { .. }
+            | Error::I2cTimeout { .. }
+            | Error::I2cArbitrationLost { .. }
+            | Error::Timeout
+            | Error::GpioOperationTimeout { .. } => true,
+            Error::Hid(error) => is_transient_hid_error(error),
+            Error::Io(error) => is_transient_io_kind(error.kind()),
+            Error::RegisterReadFailed { source, .. } => source.is_transient(),
+            _ => false,
+        }
+    }
+
+    /// Returns the subsystem this error belongs to.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Error::Hid(_) | Error::Io(_) | Error::InvalidReport(_) | Error::Timeout => {
+                ErrorCategory::Io
+            }
+            Error::DeviceNotFound
+            | Error::DeviceNotFoundBySerial { .. }
+            | Error::DeviceNotFoundByPath { .. }
+            | Error::DeviceNotFoundByIndex { .. }
+            | Error::MultipleDevicesFound { .. }
+            | Error::DeviceDisconnected { .. }
+            | Error::ArgumentOutOfRange(_)
+            | Error::RegisterVerificationFailed { .. }
+            | Error::RegisterReadFailed { .. }
+            | Error::BufferTooSmall { .. }
+            | Error::UnsupportedFeature(_) => ErrorCategory::Device,
+            Error::PinArgumentOutOfRange { .. }
+            | Error::GpioRegisterReadError { .. }
+            | Error::GpioRegisterWriteError { .. }
+            | Error::GpioConfigurationError { .. }
+            | Error::GpioPinNotAssigned { .. }
+            | Error::UnknownPinLabel { .. }
+            | Error::PinFunctionConflict { .. }
+            | Error::GpioHardwareError { .. }
+            | Error::GpioWriteVerificationFailed { .. }
+            | Error::GpioMaskedWriteVerificationFailed { .. }
+            | Error::GpioOperationTimeout { .. }
+            | Error::GpioWriteRetriesExhausted { .. }
+            | Error::InterruptParseError(_) => ErrorCategory::Gpio,
+            Error::PwmConfigurationError { .. }
+            | Error::PwmParameterError { .. }
+            | Error::PwmHardwareError { .. } => ErrorCategory::Pwm,
+            Error::I2cNack { .. }
+            | Error::I2cArbitrationLost { .. }
+            | Error::I2cTimeout { .. }
+            | Error::I2cRequestError { .. }
+            | Error::I2cUnknownError { .. }
+            | Error::OperationTooLarge { .. }
+            | Error::InvalidI2c10BitAddress(_) => ErrorCategory::I2c,
+        }
+    }
+}
+
+fn is_transient_hid_error(error: &hidapi::HidError) -> bool {
+    match error {
+        hidapi::HidError::IncompleteSendError { .. } => true,
+        hidapi::HidError::IoError { error } => is_transient_io_kind(error.kind()),
+        _ => false,
+    }
+}
+
+fn is_transient_io_kind(kind: std::io::ErrorKind) -> bool {
+    matches!(
+        kind,
+        std::io::ErrorKind::Interrupted
+            | std::io::ErrorKind::TimedOut
+            | std::io::ErrorKind::WouldBlock
+    )
+}
+
 // Removed the impl Xr2280x block containing map_feature_err
 
+// Fragments of OS/hidapi error messages that indicate the device has gone away
+const DISCONNECT_MESSAGES: &[&str] = &[
+    "no such device",
+    "device not configured",
+    "device not connected",
+    "device is not connected",
+    "disconnected",
+];
+
+// Helper for converting transport errors, separating disconnects from other HID errors
+pub(crate) fn hid_error(error: hidapi::HidError) -> Error {
+    if is_disconnect_error(&error) {
+        Error::DeviceDisconnected {
+            message: error.to_string(),
+        }
+    } else {
+        Error::Hid(error)
+    }
+}
+
+fn is_disconnect_error(error: &hidapi::HidError) -> bool {
+    if let hidapi::HidError::IoError { error } = error
+        && matches!(
+            error.kind(),
+            std::io::ErrorKind::NotFound
+                | std::io::ErrorKind::NotConnected
+                | std::io::ErrorKind::BrokenPipe
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+        )
+    {
+        return true;
+    }
+    let message = error.to_string().to_lowercase();
+    DISCONNECT_MESSAGES
+        .iter()
+        .any(|fragment| message.contains(fragment))
+}
+
+// Helper for reporting an ambiguous device selection
+pub(crate) fn multiple_devices_found(serials: Vec<Option<String>>) -> Error {
+    Error::MultipleDevicesFound {
+        count: serials.len(),
+        serials,
+    }
+}
+
+fn format_serials(serials: &[Option<String>]) -> String {
+    serials
+        .iter()
+        .map(|serial| match serial {
+            Some(serial) => format!("'{serial}'"),
+            None => "<none>".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 // Helpers for creating specific UnsupportedFeature errors
 pub(crate) fn unsupported_gpio_group1() -> Error {
     Error::UnsupportedFeature("GPIO Group 1 (pins 8-31) requires XR22802/XR22804".to_string())
@@ -252,6 +513,11 @@ pub(crate) fn unsupported_pwm_pin(pin: u8) -> Error {
         "Assigning PWM to pin {pin} requires XR22802/XR22804 (XR22800/1 only support pins 0-7)"
     ))
 }
+pub(crate) fn unsupported_config_persistence() -> Error {
+    Error::UnsupportedFeature(
+        "XR2280x devices have no non-volatile storage for GPIO/I2C defaults; apply the configuration after opening".to_string(),
+    )
+}
 
 // Helpers for creating specific GPIO errors
 pub(crate) fn gpio_register_read_error(pin: u8, register: u16, message: String) -> Error {
@@ -279,3 +545,314 @@ pub(crate) fn pwm_parameter_error(channel: u8, message: String) -> Error {
 pub(crate) fn pwm_hardware_error(channel: u8, message: String) -> Error {
     Error::PwmHardwareError { channel, message }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hidapi::HidError;
+
+    fn api_error(message: &str) -> HidError {
+        HidError::HidApiError {
+            message: message.to_string(),
+        }
+    }
+
+    fn io_error(kind: std::io::ErrorKind) -> HidError {
+        HidError::IoError {
+            error: std::io::Error::from(kind),
+        }
+    }
+
+    #[test]
+    fn test_disconnect_messages_map_to_device_disconnected() {
+        for error in [
+            api_error("hid_write/write: No such device"),
+            api_error("ioctl (GFEATURE): Device not configured"),
+            api_error("The device is not connected."),
+            io_error(std::io::ErrorKind::NotFound),
+            io_error(std::io::ErrorKind::BrokenPipe),
+        ] {
+            assert!(
+                matches!(hid_error(error), Error::DeviceDisconnected { .. }),
+                "expected DeviceDisconnected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_protocol_errors_stay_hid_errors() {
+        for error in [
+            api_error("ioctl (SFEATURE): Broken report descriptor"),
+            HidError::HidApiErrorEmpty,
+            HidError::IncompleteSendError { sent: 2, all: 5 },
+            io_error(std::io::ErrorKind::InvalidInput),
+        ] {
+            assert!(matches!(hid_error(error), Error::Hid(_)), "expected Hid");
+        }
+    }
+
+    #[test]
+    fn test_error_classification_per_variant() {
+        use crate::gpio::{GpioGroup, GpioLevel};
+        use ErrorCategory::*;
+
+        let address = I2cAddress::new_7bit(0x50).unwrap();
+        let text = || "detail".to_string();
+        let cases: Vec<(Error, ErrorCategory, bool)> = vec![
+            (Error::Hid(api_error("failure")), Io, false),
+            (
+                Error::Hid(HidError::IncompleteSendError { sent: 2, all: 5 }),
+                Io,
+                true,
+            ),
+            (
+                Error::Hid(io_error(std::io::ErrorKind::Interrupted)),
+                Io,
+                true,
+            ),
+            (Error::DeviceNotFound, Device, false),
+            (
+                Error::DeviceNotFoundBySerial {
+                    serial: text(),
+                    message: text(),
+                },
+                Device,
+                false,
+            ),
+            (
+                Error::DeviceNotFoundByPath {
+                    path: text(),
+                    message: text(),
+                },
+                Device,
+                false,
+            ),
+            (
+                Error::DeviceNotFoundByIndex {
+                    index: 1,
+                    message: text(),
+                },
+                Device,
+                false,
+            ),
+            (
+                Error::MultipleDevicesFound {
+                    count: 2,
+                    serials: vec![Some(text()), None],
+                },
+                Device,
+                false,
+            ),
+            (Error::DeviceDisconnected { message: text() }, Device, false),
+            (Error::Io(std::io::ErrorKind::TimedOut.into()), Io, true),
+            (
+                Error::Io(std::io::ErrorKind::PermissionDenied.into()),
+                Io,
+                false,
+            ),
+            (Error::InvalidReport(3), Io, false),
+            (Error::Timeout, Io, true),
+            (Error::ArgumentOutOfRange(text()), Device, false),
+            (
+                Error::PinArgumentOutOfRange {
+                    pin: 40,
+                    message: text(),
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::GpioRegisterReadError {
+                    pin: 1,
+                    register: 0,
+                    message: text(),
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::GpioRegisterWriteError {
+                    pin: 1,
+                    register: 0,
+                    message: text(),
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::GpioConfigurationError {
+                    pin: 1,
+                    message: text(),
+                },
+                Gpio,
+                false,
+            ),
+            (Error::GpioPinNotAssigned { pin: 1 }, Gpio, false),
+            (Error::UnknownPinLabel { label: text() }, Gpio, false),
+            (
+                Error::PinFunctionConflict {
+                    pin: 1,
+                    current: crate::gpio::PinFunction::Pwm0,
+                    message: text(),
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::GpioHardwareError {
+                    pin: 1,
+                    message: text(),
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::RegisterVerificationFailed {
+                    register: 0x341,
+                    expected: 1,
+                    actual: 0,
+                },
+                Device,
+                false,
+            ),
+            (
+                Error::RegisterReadFailed {
+                    register: 0x341,
+                    index: 0,
+                    source: Box::new(Error::Timeout),
+                },
+                Device,
+                true,
+            ),
+            (
+                Error::RegisterReadFailed {
+                    register: 0x341,
+                    index: 0,
+                    source: Box::new(Error::DeviceNotFound),
+                },
+                Device,
+                false,
+            ),
+            (
+                Error::GpioWriteVerificationFailed {
+                    pin: 1,
+                    expected: GpioLevel::High,
+                    actual: GpioLevel::Low,
+                    attempt: 1,
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::GpioMaskedWriteVerificationFailed {
+                    group: GpioGroup::Group0,
+                    failed_mask: 0x0004,
+                    expected: 0x0006,
+                    actual: 0x0002,
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::GpioOperationTimeout {
+                    pin: 1,
+                    operation: text(),
+                    timeout_ms: 10,
+                },
+                Gpio,
+                true,
+            ),
+            (
+                Error::GpioWriteRetriesExhausted {
+                    pin: 1,
+                    attempts: 3,
+                },
+                Gpio,
+                false,
+            ),
+            (
+                Error::PwmConfigurationError {
+                    channel: 0,
+                    message: text(),
+                },
+                Pwm,
+                false,
+            ),
+            (
+                Error::PwmParameterError {
+                    channel: 0,
+                    message: text(),
+                },
+                Pwm,
+                false,
+            ),
+            (
+                Error::PwmHardwareError {
+                    channel: 0,
+                    message: text(),
+                },
+                Pwm,
+                false,
+            ),
+            (Error::I2cNack { address }, I2c, true),
+            (Error::I2cArbitrationLost { address }, I2c, true),
+            (Error::I2cTimeout { address }, I2c, true),
+            (
+                Error::I2cRequestError {
+                    address,
+                    write_len: 32,
+                    read_len: 32,
+                },
+                I2c,
+                false,
+            ),
+            (
+                Error::I2cUnknownError {
+                    address,
+                    flags: 0x80,
+                },
+                I2c,
+                false,
+            ),
+            (
+                Error::BufferTooSmall {
+                    expected: 4,
+                    actual: 2,
+                },
+                Device,
+                false,
+            ),
+            (
+                Error::OperationTooLarge {
+                    operation: "I2C write".to_string(),
+                    max: 32,
+                    actual: 33,
+                },
+                I2c,
+                false,
+            ),
+            (Error::UnsupportedFeature(text()), Device, false),
+            (Error::InvalidI2c10BitAddress(0x400), I2c, false),
+            (Error::InterruptParseError(text()), Gpio, false),
+        ];
+
+        for (error, category, transient) in cases {
+            assert_eq!(error.category(), category, "category of {error:?}");
+            assert_eq!(error.is_transient(), transient, "is_transient of {error:?}");
+        }
+    }
+
+    #[test]
+    fn test_error_category_display() {
+        assert_eq!(ErrorCategory::I2c.to_string(), "I2C");
+        assert_eq!(ErrorCategory::Io.to_string(), "I/O");
+    }
+
+    #[test]
+    fn test_raw_os_no_such_device_is_disconnect() {
+        let error = HidError::IoError {
+            error: std::io::Error::other("No such device (os error 19)"),
+        };
+        assert!(matches!(hid_error(error), Error::DeviceDisconnected { .. }));
+    }
+}
@@ -331,7 +331,7 @@
 //! let data_byte = 0xA5u8; // 10100101
 //! for bit_pos in (0..8).rev() {
 //!     let bit_value = (data_byte >> bit_pos) & 1;
-//!     let level = if bit_value == 1 { GpioLevel::High } else { GpioLevel::Low };
+//!     let level = GpioLevel::from(bit_value == 1);
 //!
 //!     // Setup data
 //!     device.gpio_write_fast(data_pin, level)?;
@@ -392,6 +392,13 @@
 //! - **XR22800/XR22801**: Limited to 8 GPIO pins, generally more reliable
 //! - **XR22802/XR22804**: 32 GPIO pins, some pins may have higher failure rates
 //!
+//! ### Drive Strength and Slew Rate
+//! The EDGE register map (0x03C0-0x03DD) exposes function select, direction, SET/CLEAR,
+//! state, tri-state, open-drain, pull-up/pull-down, interrupt and PWM registers only.
+//! None of the XR22800/1/2/4 parts documents a drive-strength or slew-rate control, so
+//! this crate offers no API for it. Use [`Xr2280x::gpio_set_open_drain`] with an external
+//! pull-up, or external buffers, when a different output drive is needed.
+//!
 //! ### Environmental Factors
 //! - **USB Power**: Low power conditions may increase failure rates
 //! - **Cable Quality**: Poor USB cables can affect HID communication reliability
@@ -443,6 +450,7 @@
 //! | `gpio_set_direction()` | 2 | 1 read + 1 write |
 //! | `gpio_write()` | 1 | Uses SET/CLEAR registers |
 //! | `gpio_read()` | 1 | Single read |
+//! | `gpio_read_cached()` | 0-1 | 0 for output pins written while the output cache is enabled |
 //! | `gpio_set_pull()` | 4 | 2 reads + 2 writes (both pull registers) |
 //! | `gpio_set_open_drain()` | 2 | 1 read + 1 write |
 //! | `gpio_set_tri_state()` | 2 | 1 read + 1 write |
@@ -458,6 +466,7 @@
 //! - Use `gpio_setup_output()` and `gpio_setup_input()` for single pins (5 vs 8 transactions)
 //! - Use `gpio_setup_outputs()` and `gpio_setup_inputs()` for multiple pins (6 total vs 8×N)
 //! - Use `gpio_write_masked()` for updating multiple pins simultaneously
+//! - Use `gpio_write_pins()` to write a list of `(pin, level)` pairs across both groups
 //! - Batch configuration changes together
 //! - Group operations by GPIO group (0-15 vs 16-31) when possible
 //!
@@ -619,12 +628,11 @@
 //!
 //! // First batch of changes
 //! transaction.set_all_high(&[GpioPin::new(0)?, GpioPin::new(1)?])?;
-//! transaction.commit()?;
+//! transaction.commit_and_keep()?;
 //!
-//! // Create new transaction for next batch of changes
-//! let mut transaction = device.gpio_transaction();
+//! // Same transaction, ready for the next batch of changes
 //! transaction.set_all_low(&[GpioPin::new(0)?, GpioPin::new(1)?])?;
-//! transaction.commit()?;
+//! transaction.commit_and_keep()?;
 //! # Ok(())
 //! # }
 //! ```
@@ -639,7 +647,7 @@
 //! # use xr2280x_hid::{Xr2280x, gpio::*};
 //! # fn spi_send_bit(device: &Xr2280x, data_pin: GpioPin, clk_pin: GpioPin, bit: bool) -> xr2280x_hid::Result<()> {
 //! let mut transaction = device.gpio_transaction();
-//! transaction.set_pin(data_pin, if bit { GpioLevel::High } else { GpioLevel::Low })?;
+//! transaction.set_pin(data_pin, bit.into())?;
 //! transaction.set_low(clk_pin)?;  // Setup phase
 //! transaction.set_high(clk_pin)?; // Clock edge
 //! transaction.commit()?; // All changes applied atomically
@@ -655,7 +663,7 @@
 //! # fn update_led_pattern(device: &Xr2280x, led_pins: &[GpioPin], pattern: &[bool]) -> xr2280x_hid::Result<()> {
 //! let mut transaction = device.gpio_transaction();
 //! for (pin, &state) in led_pins.iter().zip(pattern.iter()) {
-//!     transaction.set_pin(*pin, if state { GpioLevel::High } else { GpioLevel::Low })?;
+//!     transaction.set_pin(*pin, state.into())?;
 //! }
 //! transaction.commit()?; // All LEDs update simultaneously
 //! # Ok(())
@@ -720,7 +728,8 @@ use crate::device::Xr2280x;
 use crate::error::{
     Error, Result, gpio_register_read_error, gpio_register_write_error, unsupported_gpio_group1,
 };
-use log::{debug, trace};
+use log::{debug, trace, warn};
+use std::ops::RangeInclusive;
 
 /// Represents a GPIO group for bulk operations.
 /// GPIO Group (0-15 or 16-31) for XR22802/4 multi-group support.
@@ -732,6 +741,69 @@ pub enum GpioGroup {
     Group1,
 }
 
+impl GpioGroup {
+    /// Returns the group the given pin belongs to.
+    pub fn from_pin(pin: GpioPin) -> Self {
+        match pin.group_index() {
+            0 => GpioGroup::Group0,
+            _ => GpioGroup::Group1,
+        }
+    }
+
+    /// Returns the range of pin numbers in this group (0-15 or 16-31).
+    pub fn pin_range(self) -> RangeInclusive<u8> {
+        match self {
+            GpioGroup::Group0 => 0..=15,
+            GpioGroup::Group1 => 16..=31,
+        }
+    }
+
+    /// Iterates over the pins of this group in ascending order.
+    ///
+    /// This does not check whether the device supports the group; see
+    /// [`Xr2280x::get_capabilities`] for the number of available pins.
+    pub fn pins(self) -> impl Iterator<Item = GpioPin> {
+        self.pin_range().map(GpioPin)
+    }
+}
+
+/// Which GPIO groups a set of pins spans, returned by [`gpio_groups_for`].
+///
+/// Bulk operations on pins within a single group need half the HID transactions of
+/// operations spanning both groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupSpan {
+    /// No pins were given.
+    Empty,
+    /// All pins are in [`GpioGroup::Group0`] (pins 0-15).
+    SingleGroup0,
+    /// All pins are in [`GpioGroup::Group1`] (pins 16-31).
+    SingleGroup1,
+    /// The pins are spread over both groups.
+    Both,
+}
+
+/// Returns which GPIO groups `pins` spans.
+pub fn gpio_groups_for(pins: &[GpioPin]) -> GroupSpan {
+    match gpio_group_masks(pins) {
+        (0, 0) => GroupSpan::Empty,
+        (_, 0) => GroupSpan::SingleGroup0,
+        (0, _) => GroupSpan::SingleGroup1,
+        _ => GroupSpan::Both,
+    }
+}
+
+/// Returns the bit masks of `pins` within each group as `(group0, group1)`, ready for
+/// the `_masked` operations.
+pub fn gpio_group_masks(pins: &[GpioPin]) -> (u16, u16) {
+    pins.iter().fold((0, 0), |(group0, group1), pin| {
+        match GpioGroup::from_pin(*pin) {
+            GpioGroup::Group0 => (group0 | pin.mask(), group1),
+            GpioGroup::Group1 => (group0, group1 | pin.mask()),
+        }
+    })
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Direction configuration for a GPIO pin.
 pub enum GpioDirection {
@@ -748,6 +820,88 @@ pub enum GpioLevel {
     /// Logic high (3.3V, VCC).
     High,
 }
+
+impl GpioLevel {
+    /// Returns the opposite logic level.
+    pub fn inverted(self) -> Self {
+        match self {
+            GpioLevel::Low => GpioLevel::High,
+            GpioLevel::High => GpioLevel::Low,
+        }
+    }
+}
+
+impl From<bool> for GpioLevel {
+    /// Converts `true` to [`GpioLevel::High`] and `false` to [`GpioLevel::Low`].
+    fn from(value: bool) -> Self {
+        if value {
+            GpioLevel::High
+        } else {
+            GpioLevel::Low
+        }
+    }
+}
+
+impl From<GpioLevel> for bool {
+    /// Converts [`GpioLevel::High`] to `true` and [`GpioLevel::Low`] to `false`.
+    fn from(level: GpioLevel) -> Self {
+        level == GpioLevel::High
+    }
+}
+
+impl std::ops::Not for GpioLevel {
+    type Output = GpioLevel;
+
+    fn not(self) -> Self::Output {
+        self.inverted()
+    }
+}
+
+/// Logical state of a signal, independent of the electrical level that represents it.
+///
+/// Converted to a [`GpioLevel`] through the pin's [`GpioPolarity`], see
+/// [`Xr2280x::gpio_write_logical`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Logical {
+    /// The signal is active (e.g. a chip select selecting its device).
+    Asserted,
+    /// The signal is inactive.
+    Deasserted,
+}
+
+/// Which electrical level asserts a signal, set per pin with
+/// [`Xr2280x::gpio_set_polarity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GpioPolarity {
+    /// Asserted is High (the default for every pin).
+    #[default]
+    ActiveHigh,
+    /// Asserted is Low, as for most chip selects and reset lines.
+    ActiveLow,
+}
+
+impl GpioPolarity {
+    /// Electrical level that represents `logical` with this polarity.
+    pub fn level(self, logical: Logical) -> GpioLevel {
+        let asserted = match self {
+            GpioPolarity::ActiveHigh => GpioLevel::High,
+            GpioPolarity::ActiveLow => GpioLevel::Low,
+        };
+        match logical {
+            Logical::Asserted => asserted,
+            Logical::Deasserted => !asserted,
+        }
+    }
+
+    /// Logical state that `level` represents with this polarity.
+    pub fn logical(self, level: GpioLevel) -> Logical {
+        if level == self.level(Logical::Asserted) {
+            Logical::Asserted
+        } else {
+            Logical::Deasserted
+        }
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Pull resistor configuration for GPIO pins.
 pub enum GpioPull {
@@ -759,6 +913,58 @@ pub enum GpioPull {
     Down,
 }
 
+/// Direction, pull and level of a GPIO pin as read back from the device, returned by
+/// [`Xr2280x::gpio_setup_output_checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpioPinConfig {
+    /// Direction from the DIR register.
+    pub direction: GpioDirection,
+    /// Pull from the PULL_UP and PULL_DOWN registers.
+    pub pull: GpioPull,
+    /// Level from the STATE register.
+    pub level: GpioLevel,
+}
+
+/// Output driver mode of GPIO pins, set with [`Xr2280x::gpio_set_output_mode_masked`].
+///
+/// Each mode is one consistent combination of the OPEN_DRAIN and TRI_STATE registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Drives both levels (open-drain and tri-state off).
+    PushPull,
+    /// Drives Low and releases the line for High, for wired-OR signals (open-drain on,
+    /// tri-state off).
+    OpenDrain,
+    /// Output driver disabled, the pin is high-impedance (tri-state on, open-drain off).
+    TriState,
+}
+
+impl OutputMode {
+    /// Builds a mode from open-drain and tri-state flags.
+    ///
+    /// Returns [`Error::ArgumentOutOfRange`] if both are set: a tri-stated pin doesn't
+    /// drive at all, so it can't also be an open-drain driver.
+    pub fn from_flags(open_drain: bool, tri_state: bool) -> Result<Self> {
+        match (open_drain, tri_state) {
+            (false, false) => Ok(OutputMode::PushPull),
+            (true, false) => Ok(OutputMode::OpenDrain),
+            (false, true) => Ok(OutputMode::TriState),
+            (true, true) => Err(Error::ArgumentOutOfRange(
+                "Output mode can't be both open-drain and tri-state".to_string(),
+            )),
+        }
+    }
+
+    /// The `(open_drain, tri_state)` register bits of this mode.
+    pub fn flags(self) -> (bool, bool) {
+        match self {
+            OutputMode::PushPull => (false, false),
+            OutputMode::OpenDrain => (true, false),
+            OutputMode::TriState => (false, true),
+        }
+    }
+}
+
 /// Configuration for GPIO write reliability features
 #[derive(Debug, Clone)]
 pub struct GpioWriteConfig {
@@ -800,6 +1006,227 @@ impl GpioWriteConfig {
     }
 }
 
+/// The function a pin is currently serving, as reported by [`Xr2280x::gpio_get_function`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinFunction {
+    /// Assigned to the EDGE controller and used as a general-purpose I/O.
+    Gpio,
+    /// Assigned to the EDGE controller and driven by enabled PWM channel 0.
+    Pwm0,
+    /// Assigned to the EDGE controller and driven by enabled PWM channel 1.
+    Pwm1,
+    /// Not assigned to the EDGE controller (FUNC_SEL bit clear).
+    Unassigned,
+}
+
+/// Blink period of [`Xr2280x::device_identify`] (2 Hz).
+const IDENTIFY_BLINK_PERIOD: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Number of blinks [`Xr2280x::device_identify`] makes for `duration`, rounded up so
+/// any non-zero duration blinks at least once.
+fn identify_blink_count(duration: std::time::Duration) -> u32 {
+    duration
+        .as_nanos()
+        .div_ceil(IDENTIFY_BLINK_PERIOD.as_nanos())
+        .try_into()
+        .unwrap_or(u32::MAX)
+}
+
+/// Converts raw pull bits to a [`GpioPull`], warning about the both-enabled state.
+fn pull_from_bits(pin: GpioPin, (has_pull_up, has_pull_down): (bool, bool)) -> GpioPull {
+    match (has_pull_up, has_pull_down) {
+        (true, false) => GpioPull::Up,
+        (false, true) => GpioPull::Down,
+        (true, true) => {
+            warn!(
+                "GPIO pin {} has both pull-up and pull-down enabled; reporting as no pull",
+                pin.number()
+            );
+            GpioPull::None
+        }
+        (false, false) => GpioPull::None,
+    }
+}
+
+/// Returns `observed` if it matches `requested`, or an error listing the differences.
+fn check_pin_config(
+    pin: GpioPin,
+    requested: GpioPinConfig,
+    observed: GpioPinConfig,
+) -> Result<GpioPinConfig> {
+    let mut mismatches = Vec::new();
+    if observed.direction != requested.direction {
+        mismatches.push(format!(
+            "direction {:?} (requested {:?})",
+            observed.direction, requested.direction
+        ));
+    }
+    if observed.pull != requested.pull {
+        mismatches.push(format!(
+            "pull {:?} (requested {:?})",
+            observed.pull, requested.pull
+        ));
+    }
+    if observed.level != requested.level {
+        mismatches.push(format!(
+            "level {:?} (requested {:?})",
+            observed.level, requested.level
+        ));
+    }
+    if mismatches.is_empty() {
+        return Ok(observed);
+    }
+    Err(Error::GpioConfigurationError {
+        pin: pin.number(),
+        message: format!("read back {}", mismatches.join(", ")),
+    })
+}
+
+/// Whether `pin`'s bit in its group's STATE value equals `expected`.
+fn state_matches(pin: GpioPin, state: u16, expected: GpioLevel) -> bool {
+    GpioLevel::from(state & pin.mask() != 0) == expected
+}
+
+/// Checks a STATE readback against the `values` written to the pins in `mask`.
+fn verify_masked_write(group: GpioGroup, mask: u16, values: u16, state: u16) -> Result<()> {
+    let failed_mask = (state ^ values) & mask;
+    if failed_mask == 0 {
+        return Ok(());
+    }
+    Err(Error::GpioMaskedWriteVerificationFailed {
+        group,
+        failed_mask,
+        expected: values & mask,
+        actual: state & mask,
+    })
+}
+
+/// Pins of `group` whose bit is set in a register value `mask`.
+fn pins_in_mask(group: GpioGroup, mask: u16) -> impl Iterator<Item = GpioPin> {
+    group.pins().filter(move |pin| mask & pin.mask() != 0)
+}
+
+/// Derives a pin's function from its group's FUNC_SEL value and the two PWM control registers.
+fn pin_function_from_registers(pin: GpioPin, func_sel: u16, pwm_ctrl: [u16; 2]) -> PinFunction {
+    if func_sel & pin.mask() == 0 {
+        return PinFunction::Unassigned;
+    }
+    let drives_pin = |ctrl: u16| {
+        ctrl & consts::edge::pwm_ctrl::ENABLE_MASK != 0
+            && (ctrl & consts::edge::pwm_ctrl::PIN_MASK) >> consts::edge::pwm_ctrl::PIN_SHIFT
+                == pin.number() as u16
+    };
+    if drives_pin(pwm_ctrl[0]) {
+        PinFunction::Pwm0
+    } else if drives_pin(pwm_ctrl[1]) {
+        PinFunction::Pwm1
+    } else {
+        PinFunction::Gpio
+    }
+}
+
+/// What to do with the GPIO pins when the [`Xr2280x`] handle is dropped.
+///
+/// Configure with [`Xr2280x::gpio_set_drop_behavior`]. The actions run before the
+/// HID handles close; failures are logged and otherwise ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DropBehavior {
+    /// Leave all pins as they are (default).
+    #[default]
+    Leave,
+    /// Switch every pin of all supported groups to input, releasing any driven outputs.
+    RestoreInputs,
+    /// Drive the listed output pins to the given levels.
+    DriveLevels(Vec<(GpioPin, GpioLevel)>),
+}
+
+/// Last written levels of output pins, used by [`Xr2280x::gpio_read_cached`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct GpioOutputCache {
+    enabled: bool,
+    /// Pins with a known written level, per group (1 bits)
+    known: [u16; 2],
+    /// Written levels of the known pins, per group (1 = High)
+    levels: [u16; 2],
+    /// Pins known to be outputs, per group (1 bits)
+    outputs: [u16; 2],
+}
+
+impl GpioOutputCache {
+    /// Records a masked write to the group with the given index.
+    fn record(&mut self, index: usize, mask: u16, values: u16) {
+        if !self.enabled {
+            return;
+        }
+        self.known[index] |= mask;
+        self.levels[index] = (self.levels[index] & !mask) | (values & mask);
+    }
+
+    /// Drops the cached levels of the masked pins of the group with the given index.
+    fn forget(&mut self, index: usize, mask: u16) {
+        self.known[index] &= !mask;
+    }
+
+    /// Records a direction change of the masked pins of the group with the given
+    /// index. Switching pins to input also drops their cached levels.
+    fn track_direction(&mut self, index: usize, mask: u16, direction: GpioDirection) {
+        match direction {
+            GpioDirection::Output if self.enabled => self.outputs[index] |= mask,
+            GpioDirection::Output => {}
+            GpioDirection::Input => {
+                self.outputs[index] &= !mask;
+                self.forget(index, mask);
+            }
+        }
+    }
+
+    /// Returns the cached level of `pin` if it is a known output with a known level.
+    fn lookup(&self, pin: GpioPin) -> Option<GpioLevel> {
+        let index = pin.group_index() as usize;
+        (self.known[index] & self.outputs[index] & pin.mask() != 0)
+            .then(|| GpioLevel::from(self.levels[index] & pin.mask() != 0))
+    }
+
+    fn invalidate(&mut self) {
+        self.known = [0; 2];
+        self.levels = [0; 2];
+        self.outputs = [0; 2];
+    }
+}
+
+/// A single register operation performed when applying a [`DropBehavior`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DropAction {
+    /// Set every pin of the group to input.
+    Inputs(GpioGroup),
+    /// Masked level write (group, mask, values).
+    Write(GpioGroup, u16, u16),
+}
+
+impl DropBehavior {
+    /// Register operations needed to apply this behavior on a device with `groups`.
+    pub(crate) fn actions(&self, groups: &[GpioGroup]) -> Vec<DropAction> {
+        match self {
+            DropBehavior::Leave => Vec::new(),
+            DropBehavior::RestoreInputs => groups.iter().map(|&g| DropAction::Inputs(g)).collect(),
+            DropBehavior::DriveLevels(levels) => {
+                let masks = group_change_masks(levels);
+                groups
+                    .iter()
+                    .filter_map(|&group| {
+                        let changes = masks[group as usize];
+                        changes.has_changes().then_some(DropAction::Write(
+                            group,
+                            changes.set_mask | changes.clear_mask,
+                            changes.set_mask,
+                        ))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Edge detection type for GPIO interrupt configuration.
 pub enum GpioEdge {
@@ -854,6 +1281,34 @@ impl GpioPin {
     }
 }
 
+/// A HID operation planned by [`GpioTransaction::plan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HidOp {
+    /// Read of a group's STATE register, needed to resolve toggles.
+    ReadState {
+        /// The group being read.
+        group: GpioGroup,
+        /// The STATE register address.
+        register: u16,
+    },
+    /// Write of `mask` to a SET or CLEAR register.
+    Write {
+        /// The group being written.
+        group: GpioGroup,
+        /// The SET or CLEAR register address.
+        register: u16,
+        /// The pins driven High (SET) or Low (CLEAR).
+        mask: u16,
+    },
+    /// Pins to invert; resolved into the group's SET/CLEAR writes from the state read.
+    Toggle {
+        /// The group of the toggled pins.
+        group: GpioGroup,
+        /// The pins to invert.
+        mask: u16,
+    },
+}
+
 /// Internal structure to track GPIO changes for a single group.
 #[derive(Debug, Clone, Copy, Default)]
 struct GpioChangeMask {
@@ -861,6 +1316,8 @@ struct GpioChangeMask {
     set_mask: u16,
     /// Mask of pins to set low (1 bits)
     clear_mask: u16,
+    /// Mask of pins to invert, resolved against the current state at commit (1 bits)
+    toggle_mask: u16,
 }
 
 impl GpioChangeMask {
@@ -869,23 +1326,45 @@ impl GpioChangeMask {
         Self {
             set_mask: 0,
             clear_mask: 0,
+            toggle_mask: 0,
         }
     }
 
     /// Check if this change mask has any pending changes
     fn has_changes(&self) -> bool {
-        self.set_mask != 0 || self.clear_mask != 0
+        self.set_mask != 0 || self.clear_mask != 0 || self.pending_toggles() != 0
     }
 
     /// Get the total number of pins affected by this change mask
     fn pin_count(&self) -> u32 {
-        (self.set_mask | self.clear_mask).count_ones()
+        (self.set_mask | self.clear_mask | self.toggle_mask).count_ones()
+    }
+
+    /// Toggled pins that are not overridden by an explicit level
+    fn pending_toggles(&self) -> u16 {
+        self.toggle_mask & !(self.set_mask | self.clear_mask)
+    }
+
+    /// Resolves pending toggles against the group's current `state` into SET/CLEAR masks
+    fn resolve(&self, state: u16) -> Self {
+        let toggles = self.pending_toggles();
+        Self {
+            set_mask: self.set_mask | (toggles & !state),
+            clear_mask: self.clear_mask | (toggles & state),
+            toggle_mask: 0,
+        }
+    }
+
+    /// Number of SET/CLEAR register writes needed to apply this change mask
+    fn write_count(&self) -> usize {
+        (self.set_mask != 0) as usize + (self.clear_mask != 0) as usize
     }
 
     /// Clear all changes in this mask
     fn clear(&mut self) {
         self.set_mask = 0;
         self.clear_mask = 0;
+        self.toggle_mask = 0;
     }
 
     /// Set a pin to high level in this change mask
@@ -899,6 +1378,25 @@ impl GpioChangeMask {
         self.clear_mask |= mask;
         self.set_mask &= !mask; // Remove from set if it was there
     }
+
+    /// Record a toggle for a pin; toggling twice cancels out
+    fn toggle(&mut self, mask: u16) {
+        self.toggle_mask ^= mask;
+    }
+}
+
+/// Splits `(pin, level)` pairs into per-group change masks (index 0 = Group 0,
+/// index 1 = Group 1). Later entries for the same pin override earlier ones.
+fn group_change_masks(pins: &[(GpioPin, GpioLevel)]) -> [GpioChangeMask; 2] {
+    let mut changes = [GpioChangeMask::new(); 2];
+    for &(pin, level) in pins {
+        let change_mask = &mut changes[pin.group_index() as usize];
+        match level {
+            GpioLevel::High => change_mask.set_high(pin.mask()),
+            GpioLevel::Low => change_mask.set_low(pin.mask()),
+        }
+    }
+    changes
 }
 
 /// A transaction for batching GPIO operations efficiently.
@@ -969,12 +1467,11 @@ impl GpioChangeMask {
 ///
 /// // First batch of changes
 /// transaction.set_all_high(&[GpioPin::new(0)?, GpioPin::new(1)?])?;
-/// transaction.commit()?;
+/// transaction.commit_and_keep()?;
 ///
-/// // Create new transaction for second batch of changes
-/// let mut transaction = device.gpio_transaction();
+/// // Second batch of changes on the same transaction
 /// transaction.set_all_low(&[GpioPin::new(2)?, GpioPin::new(3)?])?;
-/// transaction.commit()?;
+/// transaction.commit_and_keep()?;
 /// # Ok(())
 /// # }
 /// ```
@@ -987,7 +1484,7 @@ impl GpioChangeMask {
 /// // Efficient SPI-like protocol implementation
 /// for bit_pos in (0..8).rev() {
 ///     let bit_value = (byte >> bit_pos) & 1;
-///     let level = if bit_value == 1 { GpioLevel::High } else { GpioLevel::Low };
+///     let level = GpioLevel::from(bit_value == 1);
 ///
 ///     device.gpio_transaction()
 ///         .with_pin(data_pin, level)?      // Setup data
@@ -1002,7 +1499,7 @@ impl GpioChangeMask {
 /// # Best Practices
 ///
 /// - Always call [`commit()`](Self::commit) to apply changes
-/// - Reuse transaction objects for better performance
+/// - Reuse transaction objects with [`commit_and_keep()`](Self::commit_and_keep) for better performance
 /// - Use convenience methods like [`set_high()`](Self::set_high) and [`set_low()`](Self::set_low)
 /// - Check [`pending_pin_count()`](Self::pending_pin_count) for debugging
 /// - The transaction will log a warning if dropped without committing
@@ -1073,6 +1570,22 @@ impl<'a> GpioTransaction<'a> {
         self.set_pin(pin, GpioLevel::Low)
     }
 
+    /// Invert a GPIO pin's current level in this transaction.
+    ///
+    /// The current level is read once per affected group at commit time and the
+    /// toggle is resolved into SET/CLEAR writes. An explicit level set for the same
+    /// pin in this transaction takes precedence over the toggle, and toggling a pin
+    /// twice cancels out.
+    pub fn toggle(&mut self, pin: GpioPin) -> Result<()> {
+        self.device.check_gpio_pin_support(pin)?;
+        match pin.group_index() {
+            0 => self.group0_changes.toggle(pin.mask()),
+            _ => self.group1_changes.toggle(pin.mask()),
+        }
+        self.has_changes = self.group0_changes.has_changes() || self.group1_changes.has_changes();
+        Ok(())
+    }
+
     /// Set multiple GPIO pins to high level in this transaction.
     pub fn set_all_high(&mut self, pins: &[GpioPin]) -> Result<()> {
         for &pin in pins {
@@ -1138,137 +1651,592 @@ impl<'a> GpioTransaction<'a> {
         (group0_count + group1_count) as usize
     }
 
+    /// Returns the HID operations a commit would perform, without touching hardware.
+    ///
+    /// Explicit levels appear as SET/CLEAR register writes with their pin masks.
+    /// Pending toggles appear as a STATE read followed by a [`HidOp::Toggle`]: their
+    /// SET/CLEAR masks depend on the state read at commit time, when they are merged
+    /// into the group's SET/CLEAR writes. Use this to log or unit-test transactions.
+    pub fn plan(&self) -> Vec<HidOp> {
+        let mut ops = Vec::new();
+        for (group, changes) in [
+            (GpioGroup::Group0, self.group0_changes),
+            (GpioGroup::Group1, self.group1_changes),
+        ] {
+            let toggles = changes.pending_toggles();
+            if toggles != 0 {
+                ops.push(HidOp::ReadState {
+                    group,
+                    register: group_register_address(group, consts::edge::REG_STATE_0),
+                });
+            }
+            for (base, mask) in [
+                (consts::edge::REG_SET_0, changes.set_mask),
+                (consts::edge::REG_CLEAR_0, changes.clear_mask),
+            ] {
+                if mask != 0 {
+                    ops.push(HidOp::Write {
+                        group,
+                        register: group_register_address(group, base),
+                        mask,
+                    });
+                }
+            }
+            if toggles != 0 {
+                ops.push(HidOp::Toggle {
+                    group,
+                    mask: toggles,
+                });
+            }
+        }
+        ops
+    }
+
     /// Commit all pending changes to the hardware.
     ///
     /// This applies all pin changes that have been set in this transaction
     /// using efficient masked write operations. The transaction is consumed
     /// by this method, preventing further modifications after commit.
+    /// Use [`commit_and_keep()`](Self::commit_and_keep) to reuse the transaction.
     ///
     /// # Returns
     ///
     /// The number of HID transactions that were performed.
-    pub fn commit(self) -> Result<usize> {
+    pub fn commit(mut self) -> Result<usize> {
+        self.commit_and_keep()
+    }
+
+    /// Commit all pending changes to the hardware and keep the transaction for reuse.
+    ///
+    /// On success the pending changes are cleared in place, leaving the transaction
+    /// ready for the next batch. If a write fails, the pending changes are kept.
+    ///
+    /// # Returns
+    ///
+    /// The number of HID transactions that were performed.
+    pub fn commit_and_keep(&mut self) -> Result<usize> {
         if !self.has_changes {
             return Ok(0);
         }
 
         let mut transaction_count = 0;
-
-        // Apply Group 0 changes
-        if self.group0_changes.has_changes() {
-            let total_mask = self.group0_changes.set_mask | self.group0_changes.clear_mask;
-            self.device.gpio_write_masked(
-                GpioGroup::Group0,
-                total_mask,
-                self.group0_changes.set_mask,
-            )?;
-            transaction_count += if self.group0_changes.set_mask != 0 {
-                1
-            } else {
-                0
-            };
-            transaction_count += if self.group0_changes.clear_mask != 0 {
-                1
-            } else {
-                0
-            };
-        }
-
-        // Apply Group 1 changes
-        if self.group1_changes.has_changes() {
-            let total_mask = self.group1_changes.set_mask | self.group1_changes.clear_mask;
-            self.device.gpio_write_masked(
-                GpioGroup::Group1,
-                total_mask,
-                self.group1_changes.set_mask,
-            )?;
-            transaction_count += if self.group1_changes.set_mask != 0 {
-                1
+        for (group, pending) in [
+            (GpioGroup::Group0, self.group0_changes),
+            (GpioGroup::Group1, self.group1_changes),
+        ] {
+            let changes = if pending.pending_toggles() != 0 {
+                transaction_count += 1;
+                pending.resolve(self.device.gpio_read_group(group)?)
             } else {
-                0
-            };
-            transaction_count += if self.group1_changes.clear_mask != 0 {
-                1
-            } else {
-                0
+                pending
             };
+            if changes.has_changes() {
+                self.device.gpio_write_masked(
+                    group,
+                    changes.set_mask | changes.clear_mask,
+                    changes.set_mask,
+                )?;
+                transaction_count += changes.write_count();
+            }
         }
 
+        self.clear();
         debug!("GPIO transaction committed with {transaction_count} HID transactions");
         Ok(transaction_count)
     }
 }
 
-impl<'a> Drop for GpioTransaction<'a> {
-    fn drop(&mut self) {
-        if self.has_changes {
-            debug!(
-                "GPIO transaction dropped with {} pending changes - consider calling commit()",
-                self.pending_pin_count()
-            );
-        }
-    }
+/// Lists the pins whose level differs between two per-group state snapshots.
+fn level_changes(
+    pins: &[GpioPin],
+    previous: [u16; 2],
+    current: [u16; 2],
+) -> Vec<(GpioPin, GpioLevel, GpioLevel)> {
+    pins.iter()
+        .filter_map(|&pin| {
+            let group = pin.group_index() as usize;
+            let old = GpioLevel::from(previous[group] & pin.mask() != 0);
+            let new = GpioLevel::from(current[group] & pin.mask() != 0);
+            (old != new).then_some((pin, old, new))
+        })
+        .collect()
 }
 
-impl Xr2280x {
-    // --- GPIO Pin Operations ---
+/// Raw values of every GPIO register in one group, as captured by
+/// [`Xr2280x::gpio_dump_registers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpioGroupRegisters {
+    /// The group these values were read from.
+    pub group: GpioGroup,
+    /// Function select (1 = pin assigned to EDGE/GPIO).
+    pub func_sel: u16,
+    /// Direction (1 = output).
+    pub dir: u16,
+    /// SET register readback (undefined by the datasheet, see [`Xr2280x::gpio_read_set_register`]).
+    pub set: u16,
+    /// CLEAR register readback (undefined by the datasheet).
+    pub clear: u16,
+    /// Current pin state.
+    pub state: u16,
+    /// Tri-state enable.
+    pub tri_state: u16,
+    /// Open-drain enable.
+    pub open_drain: u16,
+    /// Pull-up enable.
+    pub pull_up: u16,
+    /// Pull-down enable.
+    pub pull_down: u16,
+    /// Interrupt enable mask.
+    pub intr_mask: u16,
+    /// Positive (rising) edge interrupt enable.
+    pub intr_pos_edge: u16,
+    /// Negative (falling) edge interrupt enable.
+    pub intr_neg_edge: u16,
+}
 
-    /// Creates a new GPIO transaction for efficient batch operations.
-    ///
-    /// Transactions allow multiple GPIO pin changes to be batched together
-    /// and committed as a single set of hardware operations, dramatically
-    /// reducing HID communication overhead.
-    ///
-    /// ## Operation Cycle
-    ///
-    /// Creating a transaction is lightweight and performs no device communication.
-    /// Pin changes are accumulated in memory as SET and CLEAR masks for each GPIO group.
-    /// When [`commit()`](GpioTransaction::commit) is called, the transaction uses the
-    /// hardware's atomic SET and CLEAR registers to apply all changes simultaneously,
-    /// eliminating the need for read-modify-write cycles and ensuring atomic updates.
-    ///
-    /// # Example
-    ///
-    /// ```rust,no_run
-    /// # use xr2280x_hid::{Xr2280x, gpio::*};
-    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
-    /// let mut transaction = device.gpio_transaction();
-    /// transaction.set_pin(GpioPin::new(0)?, GpioLevel::High)?;
-    /// transaction.set_pin(GpioPin::new(1)?, GpioLevel::Low)?;
-    /// let hid_transactions = transaction.commit()?;
-    /// println!("Applied changes with {} HID transactions", hid_transactions);
-    /// # Ok(())
-    /// # }
-    /// ```
-    pub fn gpio_transaction(&self) -> GpioTransaction {
-        GpioTransaction::new(self)
+impl GpioGroupRegisters {
+    /// Register labels and values in hardware address order.
+    fn rows(&self) -> [(&'static str, u16); 12] {
+        [
+            ("FUNC_SEL", self.func_sel),
+            ("DIR", self.dir),
+            ("SET", self.set),
+            ("CLEAR", self.clear),
+            ("STATE", self.state),
+            ("TRI_STATE", self.tri_state),
+            ("OPEN_DRAIN", self.open_drain),
+            ("PULL_UP", self.pull_up),
+            ("PULL_DOWN", self.pull_down),
+            ("INTR_MASK", self.intr_mask),
+            ("INTR_POS_EDGE", self.intr_pos_edge),
+            ("INTR_NEG_EDGE", self.intr_neg_edge),
+        ]
     }
+}
 
-    /// Assigns a GPIO pin to the EDGE controller (required before using GPIO functions).
-    pub fn gpio_assign_to_edge(&self, pin: GpioPin) -> Result<()> {
-        self.check_gpio_pin_support(pin)?;
-        let reg = match pin.group_index() {
-            0 => consts::edge::REG_FUNC_SEL_0,
-            _ => consts::edge::REG_FUNC_SEL_1,
+/// Snapshot of the full GPIO register map for all supported groups.
+///
+/// Intended for diagnostics and bug reports; the [`Display`](std::fmt::Display)
+/// implementation prints a labeled table with one column per group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpioRegisterDump {
+    /// Register values for each supported group, Group0 first.
+    pub groups: Vec<GpioGroupRegisters>,
+}
+
+impl std::fmt::Display for GpioRegisterDump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:<14}", "Register")?;
+        for regs in &self.groups {
+            write!(f, " {:>8}", format!("{:?}", regs.group))?;
+        }
+        writeln!(f)?;
+        let Some(first) = self.groups.first() else {
+            return Ok(());
         };
-        let current = self.read_hid_register(reg)?;
-        let new_value = current | pin.mask();
-        debug!("Assigning GPIO pin {} to EDGE controller", pin.number());
-        self.write_hid_register(reg, new_value)?;
+        for (row, (label, _)) in first.rows().iter().enumerate() {
+            write!(f, "{label:<14}")?;
+            for regs in &self.groups {
+                write!(f, "   0x{:04X}", regs.rows()[row].1)?;
+            }
+            writeln!(f)?;
+        }
         Ok(())
     }
+}
 
-    /// Checks if a GPIO pin is assigned to the EDGE controller.
-    pub fn gpio_is_assigned_to_edge(&self, pin: GpioPin) -> Result<bool> {
-        self.check_gpio_pin_support(pin)?;
-        let reg = match pin.group_index() {
-            0 => consts::edge::REG_FUNC_SEL_0,
-            _ => consts::edge::REG_FUNC_SEL_1,
-        };
-        let value = self.read_gpio_register(pin, reg)?;
+/// Access to all 32 GPIO pins of an XR22802/XR22804, created by [`Xr2280x::gpio32`].
+///
+/// The device's pin count is checked once when the handle is created, so its methods
+/// never fail with [`Error::UnsupportedFeature`]; HID errors are still returned. Whole
+/// port operations take 32-bit masks with bit N for pin N and touch only the groups
+/// with bits in the mask.
+///
+/// ```no_run
+/// # use xr2280x_hid::*;
+/// # fn example(device: &Xr2280x) -> Result<()> {
+/// let gpio = device.gpio32()?; // Fails early on XR22800/XR22801
+/// gpio.set_direction_masked(0xFFFF_0000, GpioDirection::Output)?;
+/// gpio.write_masked(0xFFFF_0000, 0x00FF_0000)?; // Pins 16-23 High, 24-31 Low
+/// println!("Port: 0x{:08X}", gpio.read_all()?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Gpio32<'a> {
+    device: &'a Xr2280x,
+}
+
+impl<'a> Gpio32<'a> {
+    /// The device this handle was created from.
+    pub fn device(&self) -> &'a Xr2280x {
+        self.device
+    }
+
+    /// Reads the level of `pin`, as [`Xr2280x::gpio_read`] does.
+    pub fn read(&self, pin: GpioPin) -> Result<GpioLevel> {
+        self.device.gpio_read(pin)
+    }
+
+    /// Writes `level` to `pin`, as [`Xr2280x::gpio_write`] does.
+    pub fn write(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
+        self.device.gpio_write(pin, level)
+    }
+
+    /// Reads the levels of all 32 pins, bit N for pin N.
+    ///
+    /// **Performance**: Uses 2 HID transactions.
+    pub fn read_all(&self) -> Result<u32> {
+        let low = self.device.gpio_read_group(GpioGroup::Group0)?;
+        let high = self.device.gpio_read_group(GpioGroup::Group1)?;
+        Ok(u32::from(low) | (u32::from(high) << 16))
+    }
+
+    /// Writes `values` to the pins in `mask`, as [`Xr2280x::gpio_write_masked`] does per
+    /// group.
+    pub fn write_masked(&self, mask: u32, values: u32) -> Result<()> {
+        for (group, group_mask, group_values) in split_port_mask(mask, values) {
+            self.device
+                .gpio_write_masked(group, group_mask, group_values)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the direction of the pins in `mask`, as [`Xr2280x::gpio_set_direction_masked`]
+    /// does per group.
+    pub fn set_direction_masked(&self, mask: u32, direction: GpioDirection) -> Result<()> {
+        for (group, group_mask, _) in split_port_mask(mask, 0) {
+            self.device
+                .gpio_set_direction_masked(group, group_mask, direction)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits a 32-pin mask and values into `(group, mask, values)` for each group with
+/// bits in `mask`.
+fn split_port_mask(mask: u32, values: u32) -> impl Iterator<Item = (GpioGroup, u16, u16)> {
+    [(GpioGroup::Group0, 0), (GpioGroup::Group1, 16)]
+        .into_iter()
+        .map(move |(group, shift)| (group, (mask >> shift) as u16, (values >> shift) as u16))
+        .filter(|&(_, group_mask, _)| group_mask != 0)
+}
+
+/// Lists the edges between two 32-pin snapshots (bit N for pin N), such as those of
+/// [`GpioSnapshotRead::bits`] or [`Gpio32::read_all`], in pin order.
+///
+/// Pins that went from 0 to 1 give [`GpioEdge::Rising`] and pins that went from 1 to
+/// 0 give [`GpioEdge::Falling`]. Pulses shorter than the time between the snapshots
+/// are not seen.
+pub fn gpio_diff(prev: u32, curr: u32) -> Vec<(GpioPin, GpioEdge)> {
+    let changed = prev ^ curr;
+    (0..32u8)
+        .filter(|&n| changed & (1 << n) != 0)
+        .map(|n| {
+            let edge = if curr & (1 << n) != 0 {
+                GpioEdge::Rising
+            } else {
+                GpioEdge::Falling
+            };
+            (GpioPin(n), edge)
+        })
+        .collect()
+}
+
+/// Synthesizes edge events by comparing successive 32-pin snapshots, as a polled
+/// alternative to GPIO interrupts.
+///
+/// Feed it each snapshot with [`update`](Self::update); the first one only sets the
+/// baseline. [`GpioSnapshotRead::bits`] gives a snapshot on every model, with the
+/// pins an 8-pin part lacks always 0. Mask a snapshot before passing it in to ignore
+/// pins. See [`gpio_diff`] for the limits of edge detection by comparison.
+///
+/// ```no_run
+/// # use xr2280x_hid::*;
+/// # fn example(device: &Xr2280x) -> Result<()> {
+/// let mut detector = GpioEdgeDetector::new();
+/// loop {
+///     let snapshot = device.gpio_read_snapshot()?;
+///     for (pin, edge) in detector.update(snapshot.bits()) {
+///         println!("Pin {}: {edge:?}", pin.number());
+///     }
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GpioEdgeDetector {
+    previous: Option<u32>,
+}
+
+impl GpioEdgeDetector {
+    /// Creates a detector without a baseline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compares `snapshot` with the previous one and returns the edges between them,
+    /// or nothing for the first snapshot.
+    pub fn update(&mut self, snapshot: u32) -> Vec<(GpioPin, GpioEdge)> {
+        let edges = match self.previous {
+            Some(previous) => gpio_diff(previous, snapshot),
+            None => Vec::new(),
+        };
+        self.previous = Some(snapshot);
+        edges
+    }
+
+    /// The last snapshot passed to [`update`](Self::update), if any.
+    pub fn previous(&self) -> Option<u32> {
+        self.previous
+    }
+
+    /// Forgets the baseline, so the next snapshot reports no edges.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Levels of all supported GPIO pins read at one point in time, as returned by
+/// [`Xr2280x::gpio_read_snapshot`].
+///
+/// Looking up pins in a snapshot costs no HID transactions: take one snapshot per
+/// polling loop iteration and inspect as many pins as needed, instead of calling
+/// [`Xr2280x::gpio_read`] for each. Use [`is_fresh`](Self::is_fresh) to decide when
+/// a kept snapshot is too old to reuse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpioSnapshotRead {
+    /// STATE of each group, masked to the device's pins; `None` for groups the device
+    /// doesn't have.
+    states: [Option<u16>; 2],
+    pin_count: u8,
+    taken_at: std::time::Instant,
+}
+
+impl GpioSnapshotRead {
+    /// Level of `pin`, or `None` if the device doesn't have the pin.
+    pub fn level(&self, pin: GpioPin) -> Option<GpioLevel> {
+        if pin.number() >= self.pin_count {
+            return None;
+        }
+        let state = self.states[pin.group_index() as usize]?;
+        Some(GpioLevel::from(state & pin.mask() != 0))
+    }
+
+    /// STATE value of `group` with the bits of pins the device doesn't have cleared,
+    /// or `None` if the group is not supported.
+    pub fn group_state(&self, group: GpioGroup) -> Option<u16> {
+        self.states[group as usize]
+    }
+
+    /// Levels of all 32 pins, bit N for pin N, with pins the device doesn't have
+    /// reading 0.
+    pub fn bits(&self) -> u32 {
+        let [low, high] = self.states.map(Option::unwrap_or_default);
+        u32::from(low) | (u32::from(high) << 16)
+    }
+
+    /// When the snapshot was read.
+    pub fn taken_at(&self) -> std::time::Instant {
+        self.taken_at
+    }
+
+    /// Whether the snapshot was read less than `ttl` ago.
+    pub fn is_fresh(&self, ttl: std::time::Duration) -> bool {
+        self.taken_at.elapsed() < ttl
+    }
+}
+
+/// Maps a Group 0 register address to the equivalent register of `group`.
+pub(crate) fn group_register_address(group: GpioGroup, base_reg: u16) -> u16 {
+    match group {
+        GpioGroup::Group0 => base_reg,
+        GpioGroup::Group1 => {
+            base_reg + (consts::edge::REG_FUNC_SEL_1 - consts::edge::REG_FUNC_SEL_0)
+        }
+    }
+}
+
+impl<'a> Drop for GpioTransaction<'a> {
+    fn drop(&mut self) {
+        if self.has_changes {
+            debug!(
+                "GPIO transaction dropped with {} pending changes - consider calling commit()",
+                self.pending_pin_count()
+            );
+        }
+    }
+}
+
+impl Xr2280x {
+    // --- GPIO Pin Operations ---
+
+    /// Creates a new GPIO transaction for efficient batch operations.
+    ///
+    /// Transactions allow multiple GPIO pin changes to be batched together
+    /// and committed as a single set of hardware operations, dramatically
+    /// reducing HID communication overhead.
+    ///
+    /// ## Operation Cycle
+    ///
+    /// Creating a transaction is lightweight and performs no device communication.
+    /// Pin changes are accumulated in memory as SET and CLEAR masks for each GPIO group.
+    /// When [`commit()`](GpioTransaction::commit) is called, the transaction uses the
+    /// hardware's atomic SET and CLEAR registers to apply all changes simultaneously,
+    /// eliminating the need for read-modify-write cycles and ensuring atomic updates.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xr2280x_hid::{Xr2280x, gpio::*};
+    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
+    /// let mut transaction = device.gpio_transaction();
+    /// transaction.set_pin(GpioPin::new(0)?, GpioLevel::High)?;
+    /// transaction.set_pin(GpioPin::new(1)?, GpioLevel::Low)?;
+    /// let hid_transactions = transaction.commit()?;
+    /// println!("Applied changes with {} HID transactions", hid_transactions);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_transaction(&self) -> GpioTransaction<'_> {
+        GpioTransaction::new(self)
+    }
+
+    /// Creates a GPIO transaction after checking that the device supports every pin
+    /// in `pins`.
+    ///
+    /// Validating up front means a chain of `set_*` calls on these pins can't stop
+    /// half-way on an unsupported pin; the first unsupported pin is reported here
+    /// instead, before any transaction exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xr2280x_hid::{Xr2280x, gpio::*};
+    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
+    /// let (data, clock) = (GpioPin::new(4)?, GpioPin::new(20)?);
+    /// let mut transaction = device.gpio_transaction_for(&[data, clock])?;
+    /// transaction.set_high(data)?;
+    /// transaction.set_low(clock)?;
+    /// transaction.commit()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_transaction_for(&self, pins: &[GpioPin]) -> Result<GpioTransaction<'_>> {
+        for &pin in pins {
+            self.check_gpio_pin_support(pin)?;
+        }
+        Ok(GpioTransaction::new(self))
+    }
+
+    /// Assigns a GPIO pin to the EDGE controller (required before using GPIO functions).
+    pub fn gpio_assign_to_edge(&self, pin: GpioPin) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        let reg = match pin.group_index() {
+            0 => consts::edge::REG_FUNC_SEL_0,
+            _ => consts::edge::REG_FUNC_SEL_1,
+        };
+        let current = self.read_hid_register(reg)?;
+        let new_value = current | pin.mask();
+        debug!("Assigning GPIO pin {} to EDGE controller", pin.number());
+        self.write_hid_register(reg, new_value)?;
+        Ok(())
+    }
+
+    /// Assigns the pins in `mask` of `group` to the EDGE controller at once.
+    ///
+    /// ORs `mask` into the group's FUNC_SEL register; pins outside the mask keep their
+    /// assignment. The write is skipped if every pin is already assigned, and nothing is
+    /// accessed for an empty mask.
+    ///
+    /// **Performance**: Uses 1 HID read and at most 1 HID write, instead of one
+    /// read-modify-write per pin with [`Xr2280x::gpio_assign_to_edge`].
+    pub fn gpio_assign_to_edge_masked(&self, group: GpioGroup, mask: u16) -> Result<()> {
+        self.check_gpio_group_support(group)?;
+        debug!("Assigning {group:?} pins (mask=0x{mask:04X}) to EDGE controller");
+        if mask == 0 {
+            return Ok(());
+        }
+        let reg = group_register_address(group, consts::edge::REG_FUNC_SEL_0);
+        let current = self.read_gpio_register_masked(group, reg)?;
+        if current & mask != mask {
+            self.write_gpio_register_masked(group, reg, current | mask)?;
+        }
+        Ok(())
+    }
+
+    /// Assigns every pin supported by this device to the EDGE controller, using
+    /// [`Xr2280x::gpio_assign_to_edge_masked`] per group.
+    ///
+    /// On 8-pin parts only pins 0-7 are assigned. Leave pins your board uses for
+    /// something else out by assigning masks instead.
+    ///
+    /// **Performance**: Uses at most 2 HID transactions per supported group.
+    pub fn gpio_assign_all_to_edge(&self) -> Result<()> {
+        for group in self.supported_gpio_groups() {
+            self.gpio_assign_to_edge_masked(group, self.supported_pin_mask(group))?;
+        }
+        Ok(())
+    }
+
+    /// Checks if a GPIO pin is assigned to the EDGE controller.
+    pub fn gpio_is_assigned_to_edge(&self, pin: GpioPin) -> Result<bool> {
+        self.check_gpio_pin_support(pin)?;
+        let reg = match pin.group_index() {
+            0 => consts::edge::REG_FUNC_SEL_0,
+            _ => consts::edge::REG_FUNC_SEL_1,
+        };
+        let value = self.read_gpio_register(pin, reg)?;
         Ok((value & pin.mask()) != 0)
     }
 
+    /// Lists every pin currently assigned to the EDGE controller (GPIO or PWM use).
+    ///
+    /// Reads FUNC_SEL of each supported group and returns the pins whose bit is set,
+    /// in ascending order. On 8-pin parts only pins 0-7 are reported.
+    ///
+    /// **Performance**: Uses 1 HID transaction per supported group.
+    pub fn gpio_list_edge_assigned(&self) -> Result<Vec<GpioPin>> {
+        let mut pins = Vec::new();
+        for group in self.supported_gpio_groups() {
+            let reg = group_register_address(group, consts::edge::REG_FUNC_SEL_0);
+            let func_sel = self.read_gpio_register_masked(group, reg)?;
+            pins.extend(
+                pins_in_mask(group, func_sel)
+                    .filter(|pin| pin.number() < self.capabilities.gpio_count),
+            );
+        }
+        debug!("{} pin(s) assigned to EDGE", pins.len());
+        Ok(pins)
+    }
+
+    /// Reports the function a pin is currently serving (GPIO, PWM channel, or unassigned).
+    ///
+    /// Derived from the pin's FUNC_SEL bit and the PWM control registers: a pin assigned
+    /// to EDGE counts as PWM output when an enabled PWM channel is routed to it.
+    /// Check this before repurposing a pin.
+    ///
+    /// **Performance**: Uses 1 HID transaction for unassigned pins, 3 otherwise.
+    pub fn gpio_get_function(&self, pin: GpioPin) -> Result<PinFunction> {
+        self.check_gpio_pin_support(pin)?;
+        let reg = match pin.group_index() {
+            0 => consts::edge::REG_FUNC_SEL_0,
+            _ => consts::edge::REG_FUNC_SEL_1,
+        };
+        let func_sel = self.read_gpio_register(pin, reg)?;
+        let pwm_ctrl = if func_sel & pin.mask() != 0 {
+            [
+                self.read_gpio_register(pin, consts::edge::REG_PWM0_CTRL)?,
+                self.read_gpio_register(pin, consts::edge::REG_PWM1_CTRL)?,
+            ]
+        } else {
+            [0, 0]
+        };
+        let function = pin_function_from_registers(pin, func_sel, pwm_ctrl);
+        trace!("GPIO pin {} function: {:?}", pin.number(), function);
+        Ok(function)
+    }
+
     /// Sets the direction of a GPIO pin (Input or Output).
     ///
     /// **Performance**: Uses 2 HID transactions (1 read + 1 write).
@@ -1292,6 +2260,11 @@ impl Xr2280x {
             direction
         );
         self.write_gpio_register(pin, reg, new_value)?;
+        self.gpio_output_cache.lock().unwrap().track_direction(
+            pin.group_index() as usize,
+            pin.mask(),
+            direction,
+        );
         Ok(())
     }
 
@@ -1315,20 +2288,49 @@ impl Xr2280x {
     /// to write several pins in the same group with just 1-2 transactions total.
     pub fn gpio_write(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
         self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
+        self.write_pin_level_configured(pin, level)
+    }
+
+    /// Writes a level to an output pin and returns the level it had before.
+    ///
+    /// The pin's STATE is read first, then the new level is written like
+    /// [`Self::gpio_write`], honoring the write configuration (see
+    /// [`Self::gpio_set_write_config`]). The handle can't be shared between threads, so
+    /// no other call on it can come in between; other handles or processes using the
+    /// same device still can.
+    ///
+    /// **Performance**: Uses 2 HID transactions, plus verification reads and retries
+    /// if configured.
+    pub fn gpio_swap(&self, pin: GpioPin, new: GpioLevel) -> Result<GpioLevel> {
+        self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
+        let previous = self.read_pin_level(pin)?;
+        self.write_pin_level_configured(pin, new)?;
+        debug!("GPIO pin {} swapped {previous:?} -> {new:?}", pin.number());
+        Ok(previous)
+    }
 
-        // Get write configuration
+    /// Writes a pin's level, verifying and retrying per the write configuration.
+    fn write_pin_level_configured(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
         let config = self.gpio_write_config.lock().unwrap().clone();
 
         if config.verify_writes || config.retry_attempts > 0 {
             self.gpio_write_with_config(pin, level, &config)
         } else {
-            self.gpio_write_fast(pin, level)
+            self.write_pin_level(pin, level)
         }
     }
 
     /// Fast GPIO write without verification or retries
     pub fn gpio_write_fast(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
         self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
+        self.write_pin_level(pin, level)
+    }
+
+    /// Writes a pin's level through SET/CLEAR without any checks.
+    fn write_pin_level(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
         let (reg_set, reg_clear) = match pin.group_index() {
             0 => (consts::edge::REG_SET_0, consts::edge::REG_CLEAR_0),
             _ => (consts::edge::REG_SET_1, consts::edge::REG_CLEAR_1),
@@ -1343,11 +2345,18 @@ impl Xr2280x {
             GpioLevel::High => self.write_gpio_register(pin, reg_set, pin.mask())?,
             GpioLevel::Low => self.write_gpio_register(pin, reg_clear, pin.mask())?,
         }
+        self.gpio_output_cache.lock().unwrap().record(
+            pin.group_index() as usize,
+            pin.mask(),
+            if bool::from(level) { pin.mask() } else { 0 },
+        );
         Ok(())
     }
 
     /// GPIO write with verification and retry logic
     pub fn gpio_write_verified(&self, pin: GpioPin, level: GpioLevel) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
         let config = GpioWriteConfig::reliable();
         self.gpio_write_with_config(pin, level, &config)
     }
@@ -1375,7 +2384,7 @@ impl Xr2280x {
             }
 
             // Perform the write
-            match self.gpio_write_fast(pin, level) {
+            match self.write_pin_level(pin, level) {
                 Ok(()) => {
                     // If verification is disabled, we're done
                     if !config.verify_writes {
@@ -1386,7 +2395,7 @@ impl Xr2280x {
                     std::thread::sleep(std::time::Duration::from_millis(1));
 
                     // Verify the write
-                    match self.gpio_read(pin) {
+                    match self.read_pin_level(pin) {
                         Ok(actual_level) if actual_level == level => {
                             if attempt > 0 {
                                 debug!(
@@ -1488,80 +2497,263 @@ impl Xr2280x {
         self.gpio_write_config.lock().unwrap().clone()
     }
 
-    pub fn gpio_read(&self, pin: GpioPin) -> Result<GpioLevel> {
-        self.check_gpio_pin_support(pin)?;
-        let reg = match pin.group_index() {
-            0 => consts::edge::REG_STATE_0,
-            _ => consts::edge::REG_STATE_1,
-        };
-        let value = self.read_gpio_register(pin, reg)?;
-        let level = match (value & pin.mask()) != 0 {
-            true => GpioLevel::High,
-            false => GpioLevel::Low,
-        };
-        trace!("GPIO pin {} read as {:?}", pin.number(), level);
-        Ok(level)
+    /// Enables or disables strict mode for single-pin GPIO operations.
+    ///
+    /// While enabled, [`Xr2280x::gpio_write`], [`Xr2280x::gpio_write_fast`],
+    /// [`Xr2280x::gpio_write_verified`] and [`Xr2280x::gpio_read`] first check
+    /// [`Xr2280x::gpio_is_assigned_to_edge`] and return [`Error::GpioPinNotAssigned`]
+    /// for pins that are not assigned to the EDGE controller, instead of silently
+    /// writing a latch that does not drive the pin or reading a meaningless level.
+    /// Masked, bulk and register-level functions are not checked.
+    ///
+    /// **Performance**: Adds 1 HID transaction to every checked operation, which is
+    /// why strict mode is disabled by default.
+    pub fn gpio_strict_mode(&self, enable: bool) {
+        *self.gpio_strict.lock().unwrap() = enable;
+        debug!(
+            "GPIO strict mode {}",
+            if enable { "enabled" } else { "disabled" }
+        );
     }
 
-    /// Sets the pull resistor configuration for a GPIO pin.
+    /// Sets the polarity used by the logical GPIO functions for `pin`.
     ///
-    /// **Performance**: Uses 4 HID transactions (2 reads + 2 writes for pull-up/pull-down registers).
-    /// This is the most expensive individual GPIO operation. For better performance, use
-    /// `gpio_set_pull_masked()` or the `gpio_setup_*()` functions.
-    pub fn gpio_set_pull(&self, pin: GpioPin, pull: GpioPull) -> Result<()> {
+    /// The polarity is kept by this handle and only affects
+    /// [`Xr2280x::gpio_write_logical`] and [`Xr2280x::gpio_read_logical`]; the device
+    /// is not accessed and electrical-level functions are unchanged. All pins start
+    /// [`GpioPolarity::ActiveHigh`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::{Xr2280x, gpio::*};
+    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
+    /// let chip_select = GpioPin::new(5)?;
+    /// device.gpio_set_polarity(chip_select, GpioPolarity::ActiveLow)?;
+    /// device.gpio_write_logical(chip_select, Logical::Asserted)?; // Drives Low
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_set_polarity(&self, pin: GpioPin, polarity: GpioPolarity) -> Result<()> {
         self.check_gpio_pin_support(pin)?;
-        let (reg_up, reg_down) = match pin.group_index() {
-            0 => (consts::edge::REG_PULL_UP_0, consts::edge::REG_PULL_DOWN_0),
-            _ => (consts::edge::REG_PULL_UP_1, consts::edge::REG_PULL_DOWN_1),
-        };
-
-        debug!("Setting GPIO pin {} pull to {:?}", pin.number(), pull);
-
-        match pull {
-            GpioPull::None => {
-                // Clear both pull-up and pull-down
-                let up_val = self.read_gpio_register(pin, reg_up)?;
-                self.write_gpio_register(pin, reg_up, up_val & !pin.mask())?;
-                let down_val = self.read_gpio_register(pin, reg_down)?;
-                self.write_gpio_register(pin, reg_down, down_val & !pin.mask())?;
-            }
-            GpioPull::Up => {
-                // Set pull-up, clear pull-down
-                let up_val = self.read_gpio_register(pin, reg_up)?;
-                self.write_gpio_register(pin, reg_up, up_val | pin.mask())?;
-                let down_val = self.read_gpio_register(pin, reg_down)?;
-                self.write_gpio_register(pin, reg_down, down_val & !pin.mask())?;
-            }
-            GpioPull::Down => {
-                // Clear pull-up, set pull-down
-                let up_val = self.read_gpio_register(pin, reg_up)?;
-                self.write_gpio_register(pin, reg_up, up_val & !pin.mask())?;
-                let down_val = self.read_gpio_register(pin, reg_down)?;
-                self.write_gpio_register(pin, reg_down, down_val | pin.mask())?;
-            }
+        let bit = 1u32 << pin.number();
+        let mut active_low = self.gpio_active_low.lock().unwrap();
+        match polarity {
+            GpioPolarity::ActiveHigh => *active_low &= !bit,
+            GpioPolarity::ActiveLow => *active_low |= bit,
         }
+        debug!("GPIO pin {} polarity set to {polarity:?}", pin.number());
         Ok(())
     }
 
-    /// Gets the pull resistor configuration for a GPIO pin.
-    pub fn gpio_get_pull(&self, pin: GpioPin) -> Result<GpioPull> {
-        self.check_gpio_pin_support(pin)?;
-        let (reg_up, reg_down) = match pin.group_index() {
+    /// Gets the polarity set for `pin` with [`Xr2280x::gpio_set_polarity`].
+    pub fn gpio_get_polarity(&self, pin: GpioPin) -> GpioPolarity {
+        if *self.gpio_active_low.lock().unwrap() & (1u32 << pin.number()) != 0 {
+            GpioPolarity::ActiveLow
+        } else {
+            GpioPolarity::ActiveHigh
+        }
+    }
+
+    /// Drives `pin` to the electrical level representing `logical` under its polarity.
+    ///
+    /// Behaves like [`Xr2280x::gpio_write`] otherwise, including write verification.
+    pub fn gpio_write_logical(&self, pin: GpioPin, logical: Logical) -> Result<()> {
+        self.gpio_write(pin, self.gpio_get_polarity(pin).level(logical))
+    }
+
+    /// Reads `pin` like [`Xr2280x::gpio_read`] and returns its logical state under its
+    /// polarity.
+    pub fn gpio_read_logical(&self, pin: GpioPin) -> Result<Logical> {
+        let level = self.gpio_read(pin)?;
+        Ok(self.gpio_get_polarity(pin).logical(level))
+    }
+
+    /// Returns [`Error::GpioPinNotAssigned`] if strict mode is on and `pin` is not
+    /// assigned to EDGE.
+    fn check_gpio_pin_assigned(&self, pin: GpioPin) -> Result<()> {
+        let strict = *self.gpio_strict.lock().unwrap();
+        if strict && !self.gpio_is_assigned_to_edge(pin)? {
+            return Err(Error::GpioPinNotAssigned { pin: pin.number() });
+        }
+        Ok(())
+    }
+
+    /// Enables or disables the output-state cache used by [`Xr2280x::gpio_read_cached`].
+    ///
+    /// While enabled, every successful GPIO write records the written level, and
+    /// direction changes made through this handle track which pins are outputs;
+    /// switching pins to input forgets their levels. Disabling the cache also clears it.
+    /// The cache is disabled by default.
+    pub fn gpio_set_output_cache(&self, enable: bool) {
+        let mut cache = self.gpio_output_cache.lock().unwrap();
+        cache.enabled = enable;
+        if !enable {
+            cache.invalidate();
+        }
+        debug!(
+            "GPIO output cache {}",
+            if enable { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// Reads a pin's level, returning the last written level for output pins without
+    /// a HID transaction.
+    ///
+    /// Only pins switched to output through this handle (or found to be outputs by
+    /// [`Xr2280x::gpio_port_write`]) since the cache was enabled or invalidated are
+    /// cached. Inputs, and outputs that have not been written since, fall back to
+    /// [`Xr2280x::gpio_read`].
+    ///
+    /// **Consistency**: the cached value is what this handle last *wrote*, not what
+    /// the pin is doing. It is stale if the pin is changed by another handle or process,
+    /// reconfigured through register-level functions, or if the line is held by
+    /// external hardware — in particular open-drain outputs written High float and can
+    /// be pulled Low externally. Call [`Xr2280x::gpio_invalidate_cache`] after such changes, or
+    /// use [`Xr2280x::gpio_read`] when the physical level matters.
+    ///
+    /// **Performance**: Uses 0 HID transactions on a cache hit, 1 otherwise.
+    pub fn gpio_read_cached(&self, pin: GpioPin) -> Result<GpioLevel> {
+        self.check_gpio_pin_support(pin)?;
+        let cached = self.gpio_output_cache.lock().unwrap().lookup(pin);
+        match cached {
+            Some(level) => {
+                trace!("GPIO pin {} cached as {:?}", pin.number(), level);
+                Ok(level)
+            }
+            None => self.gpio_read(pin),
+        }
+    }
+
+    /// Clears all levels recorded by the output-state cache; the cache stays enabled.
+    pub fn gpio_invalidate_cache(&self) {
+        self.gpio_output_cache.lock().unwrap().invalidate();
+    }
+
+    /// Sets what happens to the GPIO pins when this handle is dropped.
+    ///
+    /// Use this to avoid leaving outputs energized when a program exits.
+    /// Pins in [`DropBehavior::DriveLevels`] must be supported by this device.
+    pub fn gpio_set_drop_behavior(&self, behavior: DropBehavior) -> Result<()> {
+        if let DropBehavior::DriveLevels(levels) = &behavior {
+            for &(pin, _) in levels {
+                self.check_gpio_pin_support(pin)?;
+            }
+        }
+        debug!("GPIO drop behavior set to {behavior:?}");
+        *self.gpio_drop_behavior.lock().unwrap() = behavior;
+        Ok(())
+    }
+
+    /// Gets the configured GPIO drop behavior.
+    pub fn gpio_get_drop_behavior(&self) -> DropBehavior {
+        self.gpio_drop_behavior.lock().unwrap().clone()
+    }
+
+    /// Applies the configured [`DropBehavior`]; called from `Drop`.
+    pub(crate) fn gpio_apply_drop_behavior(&self) {
+        let behavior = std::mem::take(&mut *self.gpio_drop_behavior.lock().unwrap());
+        if behavior == DropBehavior::Leave || self.edge_device.is_none() {
+            return;
+        }
+        debug!("Applying GPIO drop behavior {behavior:?}");
+        for action in behavior.actions(&self.supported_gpio_groups()) {
+            let result = match action {
+                DropAction::Inputs(group) => {
+                    self.gpio_set_direction_masked(group, 0xFFFF, GpioDirection::Input)
+                }
+                DropAction::Write(group, mask, values) => {
+                    self.gpio_write_masked(group, mask, values)
+                }
+            };
+            if let Err(e) = result {
+                warn!("Failed to apply GPIO drop action {action:?}: {e}");
+            }
+        }
+    }
+
+    pub fn gpio_read(&self, pin: GpioPin) -> Result<GpioLevel> {
+        self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
+        self.read_pin_level(pin)
+    }
+
+    /// Reads a pin's level from STATE without any checks.
+    fn read_pin_level(&self, pin: GpioPin) -> Result<GpioLevel> {
+        let reg = match pin.group_index() {
+            0 => consts::edge::REG_STATE_0,
+            _ => consts::edge::REG_STATE_1,
+        };
+        let value = self.read_gpio_register(pin, reg)?;
+        let level = GpioLevel::from((value & pin.mask()) != 0);
+        trace!("GPIO pin {} read as {:?}", pin.number(), level);
+        Ok(level)
+    }
+
+    /// Sets the pull resistor configuration for a GPIO pin.
+    ///
+    /// **Performance**: Uses 4 HID transactions (2 reads + 2 writes for pull-up/pull-down registers).
+    /// This is the most expensive individual GPIO operation. For better performance, use
+    /// `gpio_set_pull_masked()` or the `gpio_setup_*()` functions.
+    pub fn gpio_set_pull(&self, pin: GpioPin, pull: GpioPull) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        let (reg_up, reg_down) = match pin.group_index() {
             0 => (consts::edge::REG_PULL_UP_0, consts::edge::REG_PULL_DOWN_0),
             _ => (consts::edge::REG_PULL_UP_1, consts::edge::REG_PULL_DOWN_1),
         };
 
-        let up_val = self.read_gpio_register(pin, reg_up)?;
-        let down_val = self.read_gpio_register(pin, reg_down)?;
+        debug!("Setting GPIO pin {} pull to {:?}", pin.number(), pull);
 
-        let has_pull_up = (up_val & pin.mask()) != 0;
-        let has_pull_down = (down_val & pin.mask()) != 0;
+        match pull {
+            GpioPull::None => {
+                // Clear both pull-up and pull-down
+                let up_val = self.read_gpio_register(pin, reg_up)?;
+                self.write_gpio_register(pin, reg_up, up_val & !pin.mask())?;
+                let down_val = self.read_gpio_register(pin, reg_down)?;
+                self.write_gpio_register(pin, reg_down, down_val & !pin.mask())?;
+            }
+            GpioPull::Up => {
+                // Set pull-up, clear pull-down
+                let up_val = self.read_gpio_register(pin, reg_up)?;
+                self.write_gpio_register(pin, reg_up, up_val | pin.mask())?;
+                let down_val = self.read_gpio_register(pin, reg_down)?;
+                self.write_gpio_register(pin, reg_down, down_val & !pin.mask())?;
+            }
+            GpioPull::Down => {
+                // Clear pull-up, set pull-down
+                let up_val = self.read_gpio_register(pin, reg_up)?;
+                self.write_gpio_register(pin, reg_up, up_val & !pin.mask())?;
+                let down_val = self.read_gpio_register(pin, reg_down)?;
+                self.write_gpio_register(pin, reg_down, down_val | pin.mask())?;
+            }
+        }
+        Ok(())
+    }
 
-        Ok(match (has_pull_up, has_pull_down) {
-            (true, false) => GpioPull::Up,
-            (false, true) => GpioPull::Down,
-            _ => GpioPull::None, // Both or neither
-        })
+    /// Gets the pull resistor configuration for a GPIO pin.
+    ///
+    /// Both resistors enabled at once is a misconfiguration that has no [`GpioPull`]
+    /// equivalent; it is reported as [`GpioPull::None`] and logged as a warning. Use
+    /// [`Xr2280x::gpio_get_pull_raw`] to see the individual bits.
+    pub fn gpio_get_pull(&self, pin: GpioPin) -> Result<GpioPull> {
+        let bits = self.gpio_get_pull_raw(pin)?;
+        Ok(pull_from_bits(pin, bits))
+    }
+
+    /// Reads the raw `(pull_up, pull_down)` enable bits of a GPIO pin.
+    ///
+    /// Unlike [`Xr2280x::gpio_get_pull`] this reports the invalid state with both
+    /// resistors enabled as `(true, true)`, which is useful when debugging hardware.
+    ///
+    /// **Performance**: Uses 2 HID transactions.
+    pub fn gpio_get_pull_raw(&self, pin: GpioPin) -> Result<(bool, bool)> {
+        self.check_gpio_pin_support(pin)?;
+        let (reg_up, reg_down) = match pin.group_index() {
+            0 => (consts::edge::REG_PULL_UP_0, consts::edge::REG_PULL_DOWN_0),
+            _ => (consts::edge::REG_PULL_UP_1, consts::edge::REG_PULL_DOWN_1),
+        };
+        let has_pull_up = (self.read_gpio_register(pin, reg_up)? & pin.mask()) != 0;
+        let has_pull_down = (self.read_gpio_register(pin, reg_down)? & pin.mask()) != 0;
+        Ok((has_pull_up, has_pull_down))
     }
 
     /// Sets the open-drain configuration for a GPIO pin.
@@ -1671,6 +2863,225 @@ impl Xr2280x {
         Ok(())
     }
 
+    /// Like [`Xr2280x::gpio_setup_output`], but reads the direction, pull and level
+    /// back afterwards and returns them.
+    ///
+    /// Returns [`Error::GpioConfigurationError`] naming every setting that differs from
+    /// the request. A level mismatch usually means an external driver or short holds
+    /// the pin. Use this to initialize outputs where a silently ignored configuration
+    /// would be costly.
+    ///
+    /// **Performance**: Uses 4 more HID transactions than `gpio_setup_output`.
+    pub fn gpio_setup_output_checked(
+        &self,
+        pin: GpioPin,
+        initial_level: GpioLevel,
+        pull: GpioPull,
+    ) -> Result<GpioPinConfig> {
+        self.gpio_setup_output(pin, initial_level, pull)?;
+        let observed = GpioPinConfig {
+            direction: self.gpio_get_direction(pin)?,
+            pull: self.gpio_get_pull(pin)?,
+            level: self.read_pin_level(pin)?,
+        };
+        let requested = GpioPinConfig {
+            direction: GpioDirection::Output,
+            pull,
+            level: initial_level,
+        };
+        check_pin_config(pin, requested, observed)
+    }
+
+    /// Switches a pin to output with a new level, pull and open-drain mode, ordered so
+    /// the pin never shows a transient level.
+    ///
+    /// The registers are written in this order:
+    /// 1. The level (SET or CLEAR), so the pin already drives the new level while still
+    ///    in its current mode; an input pin latches it for later.
+    /// 2. The pull resistors, clearing the unwanted one before enabling the new one, so
+    ///    a released open-drain line is already held by its new pull.
+    /// 3. The open-drain mode.
+    /// 4. The direction, so an input pin only starts driving once fully configured.
+    ///
+    /// Mode registers that already hold the requested setting are not rewritten. The
+    /// level and pull changes themselves still take effect immediately on a pin that
+    /// is already an output, and tri-state and function select are left unchanged.
+    ///
+    /// **Performance**: Uses 5-9 HID transactions.
+    pub fn gpio_reconfigure_output(
+        &self,
+        pin: GpioPin,
+        level: GpioLevel,
+        pull: GpioPull,
+        open_drain: bool,
+    ) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
+        let group = match pin.group_index() {
+            0 => GpioGroup::Group0,
+            _ => GpioGroup::Group1,
+        };
+        let reg = |base| group_register_address(group, base);
+        let mask = pin.mask();
+
+        let level_reg = match level {
+            GpioLevel::High => consts::edge::REG_SET_0,
+            GpioLevel::Low => consts::edge::REG_CLEAR_0,
+        };
+        self.write_gpio_register(pin, reg(level_reg), mask)?;
+
+        let pulls = [
+            (consts::edge::REG_PULL_UP_0, pull == GpioPull::Up),
+            (consts::edge::REG_PULL_DOWN_0, pull == GpioPull::Down),
+        ];
+        let disabled = pulls.iter().filter(|(_, enable)| !enable);
+        let enabled = pulls.iter().filter(|(_, enable)| *enable);
+        let modes = [
+            (consts::edge::REG_OPEN_DRAIN_0, open_drain),
+            (consts::edge::REG_DIR_0, true),
+        ];
+        for &(base, enable) in disabled.chain(enabled).chain(&modes) {
+            let current = self.read_gpio_register(pin, reg(base))?;
+            let new_value = if enable {
+                current | mask
+            } else {
+                current & !mask
+            };
+            if new_value != current {
+                self.write_gpio_register(pin, reg(base), new_value)?;
+            }
+        }
+        let mut cache = self.gpio_output_cache.lock().unwrap();
+        cache.record(
+            pin.group_index() as usize,
+            pin.mask(),
+            if bool::from(level) { pin.mask() } else { 0 },
+        );
+        cache.track_direction(
+            pin.group_index() as usize,
+            pin.mask(),
+            GpioDirection::Output,
+        );
+        drop(cache);
+        debug!(
+            "Reconfigured GPIO pin {} as output: level={level:?}, pull={pull:?}, open_drain={open_drain}",
+            pin.number()
+        );
+        Ok(())
+    }
+
+    /// Pulses a peripheral's RESET line: asserts it, holds for `hold`, then deasserts.
+    ///
+    /// With `active_low` the line is asserted by driving it Low, otherwise High. The pin
+    /// is first driven to the deasserted level and switched to output, so a pin that was
+    /// an input does not glitch. Both transitions use verified writes (see
+    /// [`GpioWriteConfig::reliable`]). Once assertion has been attempted, deassertion is
+    /// always attempted too, so the peripheral is not left in reset; the first error is
+    /// returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// // Sensor with an active-low RESET on E4 needing at least 10ms
+    /// device.gpio_reset_peripheral(GpioPin::new(4)?, true, Duration::from_millis(10))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_reset_peripheral(
+        &self,
+        pin: GpioPin,
+        active_low: bool,
+        hold: std::time::Duration,
+    ) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        let asserted = GpioLevel::from(!active_low);
+        debug!(
+            "Resetting peripheral on GPIO pin {} (asserted={asserted:?}, hold={hold:?})",
+            pin.number()
+        );
+        self.gpio_write_fast(pin, !asserted)?;
+        self.gpio_set_direction(pin, GpioDirection::Output)?;
+        // The inverse level is written even if asserting failed
+        let assert_result = self.gpio_write_verified(pin, asserted);
+        if assert_result.is_ok() {
+            std::thread::sleep(hold);
+        } else {
+            warn!("Failed to assert reset, deasserting anyway");
+        }
+        let deassert_result = self.gpio_write_verified(pin, !asserted);
+        assert_result.and(deassert_result)
+    }
+
+    /// Blinks `pin` at about 2 Hz for `duration`, so an operator can see which of
+    /// several connected boards this handle belongs to (e.g. with an LED on that pin).
+    ///
+    /// The pin is switched to output for the blinking, with its pull and open-drain
+    /// settings left unchanged. Afterwards its direction and, if it was an output, its
+    /// level are restored; this is attempted even if blinking failed, and the first
+    /// error is returned. The output latch of an input pin cannot be read back, so it
+    /// is left Low; set it with [`Xr2280x::gpio_write`] before switching the pin to
+    /// output if that matters. The duration is rounded up to whole blinks and the call
+    /// blocks until they are done.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// let device = Xr2280x::open_by_serial(&hid_api, "1234567890")?;
+    /// // Status LED on E0
+    /// device.device_identify(GpioPin::new(0)?, Duration::from_secs(5))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Performance**: Uses 7 HID transactions plus 2 per blink.
+    pub fn device_identify(&self, pin: GpioPin, duration: std::time::Duration) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        self.check_gpio_pin_assigned(pin)?;
+        let direction = self.gpio_get_direction(pin)?;
+        let level = self.read_pin_level(pin)?;
+        let blinks = identify_blink_count(duration);
+        debug!(
+            "Identifying device: blinking GPIO pin {} {blinks} times",
+            pin.number()
+        );
+
+        let blinked = self
+            .gpio_write_fast(pin, GpioLevel::Low)
+            .and_then(|()| self.gpio_set_direction(pin, GpioDirection::Output))
+            .and_then(|()| {
+                // High for the first half of each period, Low for the second
+                let half = IDENTIFY_BLINK_PERIOD / 2;
+                for _ in 0..blinks {
+                    self.gpio_write_fast(pin, GpioLevel::High)?;
+                    std::thread::sleep(half);
+                    self.gpio_write_fast(pin, GpioLevel::Low)?;
+                    std::thread::sleep(half);
+                }
+                Ok(())
+            });
+        if blinked.is_err() {
+            warn!(
+                "Identify blink on GPIO pin {} failed, restoring it anyway",
+                pin.number()
+            );
+        }
+        let restored = match direction {
+            GpioDirection::Output => self.gpio_write_fast(pin, level),
+            // Also drops the pin from the output cache
+            GpioDirection::Input => self.gpio_set_direction(pin, GpioDirection::Input),
+        };
+        blinked.and(restored)
+    }
+
     /// Efficiently configure a GPIO pin for input with minimal HID transactions.
     /// This combines direction and pull setting into optimized operations.
     ///
@@ -1747,11 +3158,11 @@ impl Xr2280x {
         }
 
         // Set initial levels for outputs (if specified)
-        if matches!(direction, GpioDirection::Output) {
-            if let Some(levels) = initial_levels {
-                for (pin, level) in levels {
-                    self.gpio_write(*pin, *level)?;
-                }
+        if matches!(direction, GpioDirection::Output)
+            && let Some(levels) = initial_levels
+        {
+            for (pin, level) in levels {
+                self.gpio_write(*pin, *level)?;
             }
         }
 
@@ -1800,7 +3211,7 @@ impl Xr2280x {
         direction: GpioDirection,
     ) -> Result<()> {
         self.check_gpio_group_support(group)?;
-        let reg_dir = self.get_gpio_reg_for_group(group, consts::edge::REG_DIR_0);
+        let reg_dir = group_register_address(group, consts::edge::REG_DIR_0);
 
         let current = self.read_gpio_register_masked(group, reg_dir)?;
         let new_value = match direction {
@@ -1809,6 +3220,53 @@ impl Xr2280x {
         };
         debug!("Setting {group:?} pins (mask=0x{mask:04X}) direction to {direction:?}");
         self.write_gpio_register_masked(group, reg_dir, new_value)?;
+        self.gpio_output_cache
+            .lock()
+            .unwrap()
+            .track_direction(group as usize, mask, direction);
+        Ok(())
+    }
+
+    /// Sets the direction of the pins in `mask` of `group` and, with `assign_to_edge`,
+    /// assigns them to the EDGE controller in the same call.
+    ///
+    /// The direction is written first, so the pins already have it when FUNC_SEL hands
+    /// them to EDGE and never drive in the wrong direction. Each register is read once
+    /// and written only if it changes. Without `assign_to_edge` this behaves like
+    /// [`Xr2280x::gpio_set_direction_masked`], except that an unchanged DIR isn't
+    /// rewritten.
+    ///
+    /// **Performance**: Uses 2-4 HID transactions regardless of how many pins are affected.
+    pub fn gpio_configure_pins_masked(
+        &self,
+        group: GpioGroup,
+        mask: u16,
+        direction: GpioDirection,
+        assign_to_edge: bool,
+    ) -> Result<()> {
+        self.check_gpio_group_support(group)?;
+        debug!(
+            "Configuring {group:?} pins (mask=0x{mask:04X}): direction {direction:?}, assign to EDGE: {assign_to_edge}"
+        );
+        if mask == 0 {
+            return Ok(());
+        }
+        let reg_dir = group_register_address(group, consts::edge::REG_DIR_0);
+        let current = self.read_gpio_register_masked(group, reg_dir)?;
+        let new_value = match direction {
+            GpioDirection::Input => current & !mask,
+            GpioDirection::Output => current | mask,
+        };
+        if new_value != current {
+            self.write_gpio_register_masked(group, reg_dir, new_value)?;
+        }
+        if assign_to_edge {
+            self.gpio_assign_to_edge_masked(group, mask)?;
+        }
+        self.gpio_output_cache
+            .lock()
+            .unwrap()
+            .track_direction(group as usize, mask, direction);
         Ok(())
     }
 
@@ -1831,9 +3289,58 @@ impl Xr2280x {
 
         if set_mask != 0 {
             self.write_gpio_register_masked(group, reg_set, set_mask)?;
+            self.gpio_output_cache
+                .lock()
+                .unwrap()
+                .record(group as usize, set_mask, set_mask);
         }
         if clear_mask != 0 {
             self.write_gpio_register_masked(group, reg_clear, clear_mask)?;
+            self.gpio_output_cache
+                .lock()
+                .unwrap()
+                .record(group as usize, clear_mask, 0);
+        }
+        Ok(())
+    }
+
+    /// Like [`Xr2280x::gpio_write_masked`], but reads the group's STATE register back
+    /// after the SET/CLEAR writes and checks that every pin in `mask` took its value.
+    ///
+    /// Returns [`Error::GpioMaskedWriteVerificationFailed`] with the mask of pins that
+    /// read back differently. Pins configured as inputs, or held by an external driver,
+    /// will fail verification.
+    ///
+    /// **Performance**: Uses one more HID transaction than `gpio_write_masked`.
+    pub fn gpio_write_masked_verified(
+        &self,
+        group: GpioGroup,
+        mask: u16,
+        values: u16,
+    ) -> Result<()> {
+        self.gpio_write_masked(group, mask, values)?;
+        let state = self.gpio_read_group(group)?;
+        verify_masked_write(group, mask, values, state)
+    }
+
+    /// Writes levels to an arbitrary set of GPIO pins, which may span both groups.
+    /// If the same pin appears more than once, the last entry wins.
+    ///
+    /// **Performance**: Uses 1-2 HID transactions per affected group (SET and/or CLEAR),
+    /// regardless of how many pins are written.
+    pub fn gpio_write_pins(&self, pins: &[(GpioPin, GpioLevel)]) -> Result<()> {
+        for &(pin, _) in pins {
+            self.check_gpio_pin_support(pin)?;
+        }
+        let [group0, group1] = group_change_masks(pins);
+        for (group, changes) in [(GpioGroup::Group0, group0), (GpioGroup::Group1, group1)] {
+            if changes.has_changes() {
+                self.gpio_write_masked(
+                    group,
+                    changes.set_mask | changes.clear_mask,
+                    changes.set_mask,
+                )?;
+            }
         }
         Ok(())
     }
@@ -1842,67 +3349,321 @@ impl Xr2280x {
     /// Returns a 16-bit value where each bit represents a pin's state (1 = High, 0 = Low).
     pub fn gpio_read_group(&self, group: GpioGroup) -> Result<u16> {
         self.check_gpio_group_support(group)?;
-        let reg_state = self.get_gpio_reg_for_group(group, consts::edge::REG_STATE_0);
+        let reg_state = group_register_address(group, consts::edge::REG_STATE_0);
         let value = self.read_gpio_register_masked(group, reg_state)?;
         trace!("Read {group:?} state: 0x{value:04X}");
         Ok(value)
     }
 
-    /// Sets the pull resistor configuration for multiple GPIO pins in a group.
+    /// Writes `value` to a group like a microcontroller port register: every output pin
+    /// is driven to its bit of `value` (1 = High, 0 = Low).
     ///
-    /// **Performance**: Uses 4 HID transactions (2 reads + 2 writes for pull-up/pull-down registers).
-    /// Still much more efficient than multiple `gpio_set_pull()` calls.
-    pub fn gpio_set_pull_masked(&self, group: GpioGroup, mask: u16, pull: GpioPull) -> Result<()> {
+    /// The group's DIR register is read to find the output pins, then one SET and one
+    /// CLEAR write cover all of them. Bits for input pins are ignored, so inputs keep
+    /// their latched output value. Use [`Xr2280x::gpio_write_masked`] to skip the DIR
+    /// read when the output pins are known.
+    ///
+    /// **Performance**: Uses 2-3 HID transactions.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::{Xr2280x, gpio::*};
+    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
+    /// device.gpio_configure_pins_masked(GpioGroup::Group0, 0x00FF, GpioDirection::Output, true)?;
+    /// device.gpio_port_write(GpioGroup::Group0, 0x00A5)?;
+    /// let port = device.gpio_port_read(GpioGroup::Group0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_port_write(&self, group: GpioGroup, value: u16) -> Result<()> {
         self.check_gpio_group_support(group)?;
-        let reg_up = self.get_gpio_reg_for_group(group, consts::edge::REG_PULL_UP_0);
-        let reg_down = self.get_gpio_reg_for_group(group, consts::edge::REG_PULL_DOWN_0);
-
-        debug!("Setting {group:?} pins (mask=0x{mask:04X}) pull to {pull:?}");
-
-        match pull {
-            GpioPull::None => {
-                // Clear both pull-up and pull-down for masked pins
-                let up_val = self.read_hid_register(reg_up)?;
-                self.write_hid_register(reg_up, up_val & !mask)?;
-                let down_val = self.read_hid_register(reg_down)?;
-                self.write_hid_register(reg_down, down_val & !mask)?;
-            }
-            GpioPull::Up => {
-                // Set pull-up, clear pull-down for masked pins
-                let up_val = self.read_hid_register(reg_up)?;
-                self.write_hid_register(reg_up, up_val | mask)?;
-                let down_val = self.read_hid_register(reg_down)?;
-                self.write_hid_register(reg_down, down_val & !mask)?;
-            }
-            GpioPull::Down => {
-                // Clear pull-up, set pull-down for masked pins
-                let up_val = self.read_hid_register(reg_up)?;
-                self.write_hid_register(reg_up, up_val & !mask)?;
-                let down_val = self.read_hid_register(reg_down)?;
-                self.write_hid_register(reg_down, down_val | mask)?;
+        debug!("Writing {group:?} port value 0x{value:04X}");
+        let result = self.drive_port_outputs(group, value);
+        let mut cache = self.gpio_output_cache.lock().unwrap();
+        match result {
+            Ok(outputs) => {
+                cache.track_direction(group as usize, outputs, GpioDirection::Output);
+                cache.track_direction(group as usize, !outputs, GpioDirection::Input);
+                cache.record(group as usize, outputs, value);
             }
+            // The SET write may have landed without the CLEAR write
+            Err(_) => cache.forget(group as usize, 0xFFFF),
         }
-        Ok(())
+        result.map(|_| ())
     }
 
-    /// Sets the open-drain configuration for multiple GPIO pins in a group.
-    pub fn gpio_set_open_drain_masked(
-        &self,
-        group: GpioGroup,
-        mask: u16,
-        enable: bool,
-    ) -> Result<()> {
-        self.check_gpio_group_support(group)?;
-        let reg_od = self.get_gpio_reg_for_group(group, consts::edge::REG_OPEN_DRAIN_0);
+    /// Drives the output pins of `group` to `value`: reads DIR, then writes SET with the
+    /// outputs that go High and CLEAR with those that go Low. Returns the output mask.
+    fn drive_port_outputs(&self, group: GpioGroup, value: u16) -> Result<u16> {
+        let outputs = self.read_gpio_register_masked(
+            group,
+            group_register_address(group, consts::edge::REG_DIR_0),
+        )?;
+        let set_mask = outputs & value;
+        let clear_mask = outputs & !value;
+        if set_mask != 0 {
+            self.write_gpio_register_masked(
+                group,
+                group_register_address(group, consts::edge::REG_SET_0),
+                set_mask,
+            )?;
+        }
+        if clear_mask != 0 {
+            self.write_gpio_register_masked(
+                group,
+                group_register_address(group, consts::edge::REG_CLEAR_0),
+                clear_mask,
+            )?;
+        }
+        Ok(outputs)
+    }
 
-        let current = self.read_gpio_register_masked(group, reg_od)?;
-        let new_value = if enable {
-            current | mask
-        } else {
-            current & !mask
-        };
-        debug!("Setting {group:?} pins (mask=0x{mask:04X}) open-drain to {enable}");
-        self.write_gpio_register_masked(group, reg_od, new_value)?;
+    /// Reads the levels of all 16 pins of a group, inputs and outputs alike, as the
+    /// counterpart of [`Xr2280x::gpio_port_write`].
+    ///
+    /// Same as [`Xr2280x::gpio_read_group`].
+    ///
+    /// **Performance**: Uses 1 HID transaction.
+    pub fn gpio_port_read(&self, group: GpioGroup) -> Result<u16> {
+        self.gpio_read_group(group)
+    }
+
+    /// Reads the levels of all supported pins into a [`GpioSnapshotRead`].
+    ///
+    /// Pin levels are then looked up without further device access, so inspecting N
+    /// pins costs 2 reads instead of N. Unlike [`Xr2280x::gpio_read`], pins are not
+    /// checked for EDGE assignment.
+    ///
+    /// **Performance**: Uses 1 HID transaction per supported group.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::{Xr2280x, gpio::*};
+    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
+    /// let snapshot = device.gpio_read_snapshot()?;
+    /// for n in 0..4 {
+    ///     println!("Pin {n}: {:?}", snapshot.level(GpioPin::new(n)?));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_read_snapshot(&self) -> Result<GpioSnapshotRead> {
+        let mut states = [None; 2];
+        for group in self.supported_gpio_groups() {
+            let state = self.gpio_read_group(group)?;
+            states[group as usize] = Some(state & self.supported_pin_mask(group));
+        }
+        let snapshot = GpioSnapshotRead {
+            states,
+            pin_count: self.capabilities.gpio_count,
+            taken_at: std::time::Instant::now(),
+        };
+        trace!("Read GPIO snapshot: {:?}", snapshot.states);
+        Ok(snapshot)
+    }
+
+    /// Returns a [`Gpio32`] handle for all 32 pins, or [`Error::UnsupportedFeature`] on
+    /// 8-pin parts (XR22800/XR22801).
+    ///
+    /// Check once at startup when an application needs pins 8-31, instead of getting
+    /// the error from the first group 1 operation. Doesn't access the device.
+    pub fn gpio32(&self) -> Result<Gpio32<'_>> {
+        self.check_gpio_group_support(GpioGroup::Group1)?;
+        Ok(Gpio32 { device: self })
+    }
+
+    /// Reads the raw value of a group's SET register (diagnostic only).
+    ///
+    /// SET and CLEAR are action registers: writing 1 bits drives those pins High/Low.
+    /// The datasheet does not define what reading them returns, so the value must not
+    /// be taken as the output state. Use [`Xr2280x::gpio_read_group`] or
+    /// [`Xr2280x::gpio_verify_output`] to check what the pins are doing.
+    ///
+    /// **Performance**: Uses 1 HID transaction.
+    pub fn gpio_read_set_register(&self, group: GpioGroup) -> Result<u16> {
+        self.check_gpio_group_support(group)?;
+        let reg = group_register_address(group, consts::edge::REG_SET_0);
+        let value = self.read_gpio_register_masked(group, reg)?;
+        trace!("Read {group:?} SET register: 0x{value:04X}");
+        Ok(value)
+    }
+
+    /// Reads the raw value of a group's CLEAR register (diagnostic only).
+    ///
+    /// See [`Xr2280x::gpio_read_set_register`]; the returned value is not the output state.
+    ///
+    /// **Performance**: Uses 1 HID transaction.
+    pub fn gpio_read_clear_register(&self, group: GpioGroup) -> Result<u16> {
+        self.check_gpio_group_support(group)?;
+        let reg = group_register_address(group, consts::edge::REG_CLEAR_0);
+        let value = self.read_gpio_register_masked(group, reg)?;
+        trace!("Read {group:?} CLEAR register: 0x{value:04X}");
+        Ok(value)
+    }
+
+    /// Checks whether a pin's actual level (STATE register) matches `expected`.
+    ///
+    /// Use this to confirm that a write landed without the retry machinery of
+    /// [`Xr2280x::gpio_write_verified`]. A mismatch on an output usually means the
+    /// line is loaded or held externally (e.g. an open-drain pin pulled Low).
+    ///
+    /// **Performance**: Uses 1 HID transaction.
+    pub fn gpio_verify_output(&self, pin: GpioPin, expected: GpioLevel) -> Result<bool> {
+        self.check_gpio_pin_support(pin)?;
+        let state = self.gpio_read_group(GpioGroup::from_pin(pin))?;
+        let matches = state_matches(pin, state, expected);
+        if !matches {
+            debug!(
+                "GPIO pin {} expected {expected:?}, STATE=0x{state:04X}",
+                pin.number()
+            );
+        }
+        Ok(matches)
+    }
+
+    /// Watches input pins for level changes by polling, without using hardware interrupts.
+    ///
+    /// Takes an initial snapshot of the pins' levels, then every `poll_interval` reads
+    /// them again and calls `callback(pin, old_level, new_level)` for each pin whose
+    /// level changed. Polling continues until the callback returns
+    /// [`ControlFlow::Break`](std::ops::ControlFlow::Break).
+    ///
+    /// This is a fallback for hosts where interrupt reports are unreliable. Changes
+    /// shorter than the poll interval can be missed.
+    ///
+    /// **Performance**: Uses 1 HID transaction per polled group on each iteration.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::{Xr2280x, gpio::*};
+    /// # use std::ops::ControlFlow;
+    /// # use std::time::Duration;
+    /// # fn example(device: &Xr2280x) -> xr2280x_hid::Result<()> {
+    /// let button = GpioPin::new(3)?;
+    /// device.gpio_poll_changes(&[button], Duration::from_millis(10), |pin, old, new| {
+    ///     println!("Pin {} changed {old:?} -> {new:?}", pin.number());
+    ///     if new == GpioLevel::Low { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gpio_poll_changes<F>(
+        &self,
+        pins: &[GpioPin],
+        poll_interval: std::time::Duration,
+        mut callback: F,
+    ) -> Result<()>
+    where
+        F: FnMut(GpioPin, GpioLevel, GpioLevel) -> std::ops::ControlFlow<()>,
+    {
+        for &pin in pins {
+            self.check_gpio_pin_support(pin)?;
+        }
+        let (group0_mask, group1_mask) = gpio_group_masks(pins);
+        let group_masks = [group0_mask, group1_mask];
+        let read_groups = || -> Result<[u16; 2]> {
+            let mut states = [0u16; 2];
+            for (index, group) in [GpioGroup::Group0, GpioGroup::Group1]
+                .into_iter()
+                .enumerate()
+            {
+                if group_masks[index] != 0 {
+                    states[index] = self.gpio_read_group(group)? & group_masks[index];
+                }
+            }
+            Ok(states)
+        };
+
+        let mut previous = read_groups()?;
+        loop {
+            std::thread::sleep(poll_interval);
+            let current = read_groups()?;
+            for (pin, old, new) in level_changes(pins, previous, current) {
+                if callback(pin, old, new).is_break() {
+                    return Ok(());
+                }
+            }
+            previous = current;
+        }
+    }
+
+    /// Sets the pull resistor configuration for multiple GPIO pins in a group.
+    ///
+    /// Both pull registers are read before either is written, and the register whose
+    /// bits are cleared is written first, so no pin has both pulls enabled in between.
+    ///
+    /// **Performance**: Uses 4 HID transactions (2 reads + 2 writes for pull-up/pull-down registers).
+    /// Still much more efficient than multiple `gpio_set_pull()` calls.
+    pub fn gpio_set_pull_masked(&self, group: GpioGroup, mask: u16, pull: GpioPull) -> Result<()> {
+        self.check_gpio_group_support(group)?;
+        if mask == 0 {
+            return Ok(());
+        }
+        debug!("Setting {group:?} pins (mask=0x{mask:04X}) pull to {pull:?}");
+        let reg_up = group_register_address(group, consts::edge::REG_PULL_UP_0);
+        let reg_down = group_register_address(group, consts::edge::REG_PULL_DOWN_0);
+        let up = self.read_hid_register(reg_up)?;
+        let down = self.read_hid_register(reg_down)?;
+        match pull {
+            GpioPull::None => {
+                self.write_hid_register(reg_up, up & !mask)?;
+                self.write_hid_register(reg_down, down & !mask)?;
+            }
+            GpioPull::Up => {
+                self.write_hid_register(reg_down, down & !mask)?;
+                self.write_hid_register(reg_up, up | mask)?;
+            }
+            GpioPull::Down => {
+                self.write_hid_register(reg_up, up & !mask)?;
+                self.write_hid_register(reg_down, down | mask)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the pulls of pins in both groups in one call, e.g. `Up` for the pins in
+    /// `group0_mask` and `None` for the pins in `group1_mask`.
+    ///
+    /// Each group is updated like [`Self::gpio_set_pull_masked`]; a group with an empty
+    /// mask is skipped, so `group1_mask` must be 0 on 8-pin devices. Group 0 is written
+    /// completely before group 1, and if group 1 fails group 0 keeps its new pulls.
+    ///
+    /// **Performance**: Uses 4 HID transactions per group with a non-empty mask.
+    pub fn gpio_set_pull_all(
+        &self,
+        group0_mask: u16,
+        group0_pull: GpioPull,
+        group1_mask: u16,
+        group1_pull: GpioPull,
+    ) -> Result<()> {
+        if group1_mask != 0 {
+            self.check_gpio_group_support(GpioGroup::Group1)?;
+        }
+        self.gpio_set_pull_masked(GpioGroup::Group0, group0_mask, group0_pull)?;
+        if group1_mask != 0 {
+            self.gpio_set_pull_masked(GpioGroup::Group1, group1_mask, group1_pull)?;
+        }
+        Ok(())
+    }
+
+    /// Sets the open-drain configuration for multiple GPIO pins in a group.
+    pub fn gpio_set_open_drain_masked(
+        &self,
+        group: GpioGroup,
+        mask: u16,
+        enable: bool,
+    ) -> Result<()> {
+        self.check_gpio_group_support(group)?;
+        let reg_od = group_register_address(group, consts::edge::REG_OPEN_DRAIN_0);
+
+        let current = self.read_gpio_register_masked(group, reg_od)?;
+        let new_value = if enable {
+            current | mask
+        } else {
+            current & !mask
+        };
+        debug!("Setting {group:?} pins (mask=0x{mask:04X}) open-drain to {enable}");
+        self.write_gpio_register_masked(group, reg_od, new_value)?;
         Ok(())
     }
 
@@ -1914,7 +3675,7 @@ impl Xr2280x {
         enable: bool,
     ) -> Result<()> {
         self.check_gpio_group_support(group)?;
-        let reg_ts = self.get_gpio_reg_for_group(group, consts::edge::REG_TRI_STATE_0);
+        let reg_ts = group_register_address(group, consts::edge::REG_TRI_STATE_0);
 
         let current = self.read_gpio_register_masked(group, reg_ts)?;
         let new_value = if enable {
@@ -1927,6 +3688,141 @@ impl Xr2280x {
         Ok(())
     }
 
+    /// Sets the output driver mode of multiple GPIO pins in a group.
+    ///
+    /// Configures the open-drain and tri-state registers together so each pin ends
+    /// up in exactly one [`OutputMode`]; use [`OutputMode::from_flags`] to build the
+    /// mode from separate flags. Both registers are read before either is written,
+    /// and a register that already holds the requested bits is not rewritten.
+    ///
+    /// **Performance**: Uses 2-4 HID transactions, vs 4 for separate
+    /// [`Xr2280x::gpio_set_open_drain_masked`] and
+    /// [`Xr2280x::gpio_set_tri_state_masked`] calls.
+    pub fn gpio_set_output_mode_masked(
+        &self,
+        group: GpioGroup,
+        mask: u16,
+        mode: OutputMode,
+    ) -> Result<()> {
+        self.check_gpio_group_support(group)?;
+        debug!("Setting {group:?} pins (mask=0x{mask:04X}) output mode to {mode:?}");
+        // Entering tri-state writes TRI_STATE first, so the driver is off before
+        // open-drain changes; otherwise OPEN_DRAIN is written first, so the driver comes
+        // back in its new mode
+        let (open_drain, tri_state) = mode.flags();
+        let reg_od = group_register_address(group, consts::edge::REG_OPEN_DRAIN_0);
+        let reg_ts = group_register_address(group, consts::edge::REG_TRI_STATE_0);
+        let mut registers = [(reg_od, open_drain), (reg_ts, tri_state)];
+        if tri_state {
+            registers.reverse();
+        }
+        let mut updates = Vec::with_capacity(2);
+        for (reg, enable) in registers {
+            let current = self.read_gpio_register_masked(group, reg)?;
+            let new_value = if enable {
+                current | mask
+            } else {
+                current & !mask
+            };
+            updates.push((reg, current, new_value));
+        }
+        for (reg, current, new_value) in updates {
+            if new_value != current {
+                self.write_gpio_register_masked(group, reg, new_value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads every GPIO register of all supported groups for diagnostics.
+    ///
+    /// Captures function select, direction, state, SET/CLEAR readback, pull-up/down,
+    /// open-drain, tri-state and interrupt configuration. Print the result with
+    /// `{}` to get a labeled table suitable for bug reports.
+    ///
+    /// **Performance**: Uses 12 HID transactions per supported group.
+    pub fn gpio_dump_registers(&self) -> Result<GpioRegisterDump> {
+        let groups = self
+            .supported_gpio_groups()
+            .into_iter()
+            .map(|group| self.read_group_registers(group))
+            .collect::<Result<Vec<_>>>()?;
+        debug!("Dumped GPIO registers for {} group(s)", groups.len());
+        Ok(GpioRegisterDump { groups })
+    }
+
+    /// Reads all twelve registers of `group`, one HID transaction per register.
+    fn read_group_registers(&self, group: GpioGroup) -> Result<GpioGroupRegisters> {
+        let reg =
+            |base: u16| self.read_gpio_register_masked(group, group_register_address(group, base));
+        Ok(GpioGroupRegisters {
+            group,
+            func_sel: reg(consts::edge::REG_FUNC_SEL_0)?,
+            dir: reg(consts::edge::REG_DIR_0)?,
+            set: reg(consts::edge::REG_SET_0)?,
+            clear: reg(consts::edge::REG_CLEAR_0)?,
+            state: reg(consts::edge::REG_STATE_0)?,
+            tri_state: reg(consts::edge::REG_TRI_STATE_0)?,
+            open_drain: reg(consts::edge::REG_OPEN_DRAIN_0)?,
+            pull_up: reg(consts::edge::REG_PULL_UP_0)?,
+            pull_down: reg(consts::edge::REG_PULL_DOWN_0)?,
+            intr_mask: reg(consts::edge::REG_INTR_MASK_0)?,
+            intr_pos_edge: reg(consts::edge::REG_INTR_POS_EDGE_0)?,
+            intr_neg_edge: reg(consts::edge::REG_INTR_NEG_EDGE_0)?,
+        })
+    }
+
+    /// Returns every pin of all supported groups to a high-impedance input with pulls off.
+    ///
+    /// Per group this switches all pins to input first, so nothing is driven while the
+    /// rest is reconfigured, then disables open-drain and tri-state and clears both pull
+    /// registers. Every register is written as a whole, so no reads are needed. Use this
+    /// to leave the hardware in a safe state, e.g. at the end of a test. Function
+    /// assignment (FUNC_SEL), PWM and interrupt configuration are left unchanged.
+    ///
+    /// **Performance**: Uses 5 HID transactions per supported group.
+    pub fn gpio_reset_all_to_inputs(&self) -> Result<()> {
+        for group in self.supported_gpio_groups() {
+            debug!("Resetting all {group:?} pins to inputs with pulls off");
+            for base in [
+                consts::edge::REG_DIR_0,
+                consts::edge::REG_OPEN_DRAIN_0,
+                consts::edge::REG_TRI_STATE_0,
+                consts::edge::REG_PULL_UP_0,
+                consts::edge::REG_PULL_DOWN_0,
+            ] {
+                self.write_gpio_register_masked(
+                    group,
+                    group_register_address(group, base),
+                    0x0000,
+                )?;
+            }
+            self.gpio_output_cache.lock().unwrap().track_direction(
+                group as usize,
+                0xFFFF,
+                GpioDirection::Input,
+            );
+        }
+        Ok(())
+    }
+
+    /// GPIO groups available on this device model.
+    fn supported_gpio_groups(&self) -> Vec<GpioGroup> {
+        if self.check_gpio_group_support(GpioGroup::Group1).is_ok() {
+            vec![GpioGroup::Group0, GpioGroup::Group1]
+        } else {
+            vec![GpioGroup::Group0]
+        }
+    }
+
+    /// Mask of the pins of `group` this device has.
+    fn supported_pin_mask(&self, group: GpioGroup) -> u16 {
+        group
+            .pins()
+            .filter(|pin| pin.number() < self.capabilities.gpio_count)
+            .fold(0, |mask, pin| mask | pin.mask())
+    }
+
     // --- Helper Methods ---
     fn get_gpio_group_regs(&self, group: GpioGroup) -> (u16, u16) {
         match group {
@@ -1935,15 +3831,6 @@ impl Xr2280x {
         }
     }
 
-    fn get_gpio_reg_for_group(&self, group: GpioGroup, base_reg: u16) -> u16 {
-        match group {
-            GpioGroup::Group0 => base_reg,
-            GpioGroup::Group1 => {
-                base_reg + (consts::edge::REG_FUNC_SEL_1 - consts::edge::REG_FUNC_SEL_0)
-            }
-        }
-    }
-
     pub(crate) fn check_gpio_pin_support(&self, pin: GpioPin) -> Result<()> {
         if self.capabilities.gpio_count == 8 && pin.number() > 7 {
             Err(Error::UnsupportedFeature(format!(
@@ -2039,3 +3926,1244 @@ impl Xr2280x {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::MockRegisterFile;
+
+    fn pin(n: u8) -> GpioPin {
+        GpioPin::new(n).unwrap()
+    }
+
+    /// The SET and CLEAR writes made to either group, in order.
+    fn level_writes(registers: &MockRegisterFile) -> Vec<(u16, u16)> {
+        use consts::edge::*;
+        let level_registers = [REG_SET_0, REG_CLEAR_0, REG_SET_1, REG_CLEAR_1];
+        registers
+            .writes()
+            .into_iter()
+            .filter(|(reg, _)| level_registers.contains(reg))
+            .collect()
+    }
+
+    #[test]
+    fn test_pull_raw_reports_both_enabled() {
+        // Pin 17 is bit 1 of the group 1 registers
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_PULL_UP_1, 0x0002)
+            .with_register(consts::edge::REG_PULL_DOWN_1, 0x0002);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers);
+        assert_eq!(device.gpio_get_pull_raw(pin(17)).unwrap(), (true, true));
+        assert_eq!(device.gpio_get_pull(pin(17)).unwrap(), GpioPull::None);
+
+        assert_eq!(device.gpio_get_pull_raw(pin(16)).unwrap(), (false, false));
+        assert_eq!(pull_from_bits(pin(1), (true, false)), GpioPull::Up);
+        assert_eq!(pull_from_bits(pin(1), (false, true)), GpioPull::Down);
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_unassigned_pin() {
+        let device = Xr2280x::new_for_test(32).with_register_transport(MockRegisterFile::default());
+        device.gpio_strict_mode(true);
+        let result = device.check_gpio_pin_assigned(pin(5));
+        assert!(matches!(result, Err(Error::GpioPinNotAssigned { pin: 5 })));
+
+        // Errors from the FUNC_SEL read are passed through
+        let registers =
+            MockRegisterFile::default().with_failing_register(consts::edge::REG_FUNC_SEL_0);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers);
+        device.gpio_strict_mode(true);
+        let result = device.check_gpio_pin_assigned(pin(5));
+        assert!(matches!(result, Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn test_strict_mode_permits_assigned_pin() {
+        // Pin 20 is bit 4 of FUNC_SEL_1
+        let registers =
+            MockRegisterFile::default().with_register(consts::edge::REG_FUNC_SEL_1, 0x0010);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers);
+        device.gpio_strict_mode(true);
+        device.check_gpio_pin_assigned(pin(20)).unwrap();
+    }
+
+    #[test]
+    fn test_strict_mode_off_skips_check() {
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device.check_gpio_pin_assigned(pin(5)).unwrap();
+        assert!(registers.reads().is_empty());
+
+        // Off by default: the check passes without touching HID
+        let device = Xr2280x::new_for_test(32);
+        device.check_gpio_pin_assigned(pin(5)).unwrap();
+        device.gpio_strict_mode(true);
+        assert!(matches!(
+            device.check_gpio_pin_assigned(pin(5)),
+            Err(Error::DeviceNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_verify_output_against_state() {
+        let state = 0b1000_0000_0000_0101;
+        assert!(state_matches(pin(0), state, GpioLevel::High));
+        assert!(state_matches(pin(1), state, GpioLevel::Low));
+        assert!(!state_matches(pin(2), state, GpioLevel::Low));
+        assert!(state_matches(pin(15), state, GpioLevel::High));
+        // Group 1 pins use the same bit positions in their own STATE register
+        assert!(state_matches(pin(31), state, GpioLevel::High));
+        assert!(!state_matches(pin(17), state, GpioLevel::High));
+    }
+
+    #[test]
+    fn test_reset_pulse_polarity() {
+        use consts::edge::{REG_CLEAR_0, REG_SET_0};
+        let hold = std::time::Duration::from_millis(10);
+        for (active_low, expected) in [
+            (true, [REG_SET_0, REG_CLEAR_0, REG_SET_0]),
+            (false, [REG_CLEAR_0, REG_SET_0, REG_CLEAR_0]),
+        ] {
+            let registers = MockRegisterFile::default();
+            let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+            let start = std::time::Instant::now();
+            device
+                .gpio_reset_peripheral(pin(4), active_low, hold)
+                .unwrap();
+            assert!(start.elapsed() >= hold);
+            // Idle level, then assert and deassert on the output
+            assert_eq!(level_writes(&registers), expected.map(|reg| (reg, 0x0010)));
+            assert_eq!(registers.register(consts::edge::REG_DIR_0), 0x0010);
+        }
+    }
+
+    #[test]
+    fn test_reset_pulse_deasserts_after_failed_assert() {
+        // Asserting an active-low reset writes CLEAR
+        let registers =
+            MockRegisterFile::default().with_failing_register(consts::edge::REG_CLEAR_0);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let hold = std::time::Duration::from_secs(5);
+        let start = std::time::Instant::now();
+        let result = device.gpio_reset_peripheral(pin(4), true, hold);
+        assert!(result.is_err());
+        assert!(start.elapsed() < hold, "held reset after a failed assert");
+        assert_eq!(
+            level_writes(&registers).last(),
+            Some(&(consts::edge::REG_SET_0, 0x0010))
+        );
+    }
+
+    #[test]
+    fn test_pins_in_func_sel_mask() {
+        let numbers = |group, mask| {
+            pins_in_mask(group, mask)
+                .map(|p| p.number())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(GpioGroup::Group0, 0x8025), vec![0, 2, 5, 15]);
+        assert_eq!(numbers(GpioGroup::Group1, 0x0003), vec![16, 17]);
+        assert!(numbers(GpioGroup::Group1, 0).is_empty());
+        assert_eq!(numbers(GpioGroup::Group0, 0xFFFF).len(), 16);
+    }
+
+    #[test]
+    fn test_group_pins_and_range() {
+        let group0: Vec<u8> = GpioGroup::Group0.pins().map(|p| p.number()).collect();
+        let group1: Vec<u8> = GpioGroup::Group1.pins().map(|p| p.number()).collect();
+        assert_eq!(group0, (0..=15).collect::<Vec<_>>());
+        assert_eq!(group1, (16..=31).collect::<Vec<_>>());
+        assert_eq!(GpioGroup::Group0.pin_range(), 0..=15);
+        assert_eq!(GpioGroup::Group1.pin_range(), 16..=31);
+
+        for group in [GpioGroup::Group0, GpioGroup::Group1] {
+            assert!(group.pins().all(|p| GpioGroup::from_pin(p) == group));
+        }
+        assert_eq!(GpioGroup::from_pin(pin(15)), GpioGroup::Group0);
+        assert_eq!(GpioGroup::from_pin(pin(16)), GpioGroup::Group1);
+    }
+
+    #[test]
+    fn test_cached_read_after_write_skips_hid() {
+        // Pins 3, 16 and 17 are outputs; pin 4 stays an input that reads Low
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let state_reads = || {
+            registers
+                .reads()
+                .iter()
+                .filter(|&&reg| {
+                    matches!(reg, consts::edge::REG_STATE_0 | consts::edge::REG_STATE_1)
+                })
+                .count()
+        };
+        device.gpio_set_output_cache(true);
+        device
+            .gpio_set_direction_masked(GpioGroup::Group0, 0x0008, GpioDirection::Output)
+            .unwrap();
+        device
+            .gpio_set_direction_masked(GpioGroup::Group1, 0x0003, GpioDirection::Output)
+            .unwrap();
+
+        device.gpio_write_fast(pin(3), GpioLevel::High).unwrap();
+        device.gpio_write_fast(pin(16), GpioLevel::High).unwrap();
+        device.gpio_write_fast(pin(17), GpioLevel::Low).unwrap();
+        device.gpio_write_fast(pin(4), GpioLevel::High).unwrap();
+        assert_eq!(device.gpio_read_cached(pin(3)).unwrap(), GpioLevel::High);
+        assert_eq!(device.gpio_read_cached(pin(16)).unwrap(), GpioLevel::High);
+        assert_eq!(device.gpio_read_cached(pin(17)).unwrap(), GpioLevel::Low);
+        assert_eq!(state_reads(), 0);
+
+        // The written input pin reports STATE, not its latch
+        assert_eq!(device.gpio_read_cached(pin(4)).unwrap(), GpioLevel::Low);
+        assert_eq!(state_reads(), 1);
+
+        device.gpio_invalidate_cache();
+        assert_eq!(device.gpio_read_cached(pin(3)).unwrap(), GpioLevel::High);
+        assert_eq!(state_reads(), 2);
+    }
+
+    #[test]
+    fn test_output_cache_forget_and_disable() {
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let state_reads = || {
+            registers
+                .reads()
+                .iter()
+                .filter(|&&reg| reg == consts::edge::REG_STATE_0)
+                .count()
+        };
+
+        device
+            .gpio_set_direction_masked(GpioGroup::Group0, 0x0001, GpioDirection::Output)
+            .unwrap();
+        device.gpio_write_fast(pin(0), GpioLevel::High).unwrap();
+        device.gpio_read_cached(pin(0)).unwrap();
+        assert_eq!(state_reads(), 1, "disabled cache records nothing");
+
+        device.gpio_set_output_cache(true);
+        device
+            .gpio_set_direction_masked(GpioGroup::Group0, 0x0003, GpioDirection::Output)
+            .unwrap();
+        device.gpio_write_fast(pin(0), GpioLevel::High).unwrap();
+        device.gpio_write_fast(pin(1), GpioLevel::High).unwrap();
+        device
+            .gpio_set_direction(pin(1), GpioDirection::Input)
+            .unwrap();
+        assert_eq!(device.gpio_read_cached(pin(0)).unwrap(), GpioLevel::High);
+        assert_eq!(state_reads(), 1);
+        device.gpio_read_cached(pin(1)).unwrap();
+        assert_eq!(state_reads(), 2, "switching to input forgets the pin");
+
+        device.gpio_set_output_cache(false);
+        device.gpio_set_output_cache(true);
+        device.gpio_read_cached(pin(0)).unwrap();
+        assert_eq!(state_reads(), 3, "disabling clears the cache");
+    }
+
+    #[test]
+    fn test_plan_mixed_cross_group_transaction() {
+        let device = Xr2280x::new_for_test(32);
+        let mut transaction = device.gpio_transaction();
+        transaction.set_high(pin(1)).unwrap();
+        transaction.set_low(pin(2)).unwrap();
+        transaction.set_high(pin(17)).unwrap();
+        transaction.toggle(pin(20)).unwrap();
+
+        assert_eq!(
+            transaction.plan(),
+            vec![
+                HidOp::Write {
+                    group: GpioGroup::Group0,
+                    register: consts::edge::REG_SET_0,
+                    mask: 0b0010,
+                },
+                HidOp::Write {
+                    group: GpioGroup::Group0,
+                    register: consts::edge::REG_CLEAR_0,
+                    mask: 0b0100,
+                },
+                HidOp::ReadState {
+                    group: GpioGroup::Group1,
+                    register: consts::edge::REG_STATE_1,
+                },
+                HidOp::Write {
+                    group: GpioGroup::Group1,
+                    register: consts::edge::REG_SET_1,
+                    mask: 0b0010,
+                },
+                HidOp::Toggle {
+                    group: GpioGroup::Group1,
+                    mask: 0b1_0000,
+                },
+            ]
+        );
+        // Planning does not consume or alter the pending changes
+        assert_eq!(transaction.pending_pin_count(), 4);
+        assert!(device.gpio_transaction().plan().is_empty());
+    }
+
+    #[test]
+    fn test_toggle_inverts_read_state() {
+        use consts::edge::{REG_CLEAR_0, REG_SET_0, REG_STATE_0};
+        let registers = MockRegisterFile::default().with_register(REG_STATE_0, 0b0101);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let mut transaction = device.gpio_transaction();
+        for n in 0..4 {
+            transaction.toggle(pin(n)).unwrap();
+        }
+        assert_eq!(transaction.pending_pin_count(), 4);
+
+        let count = transaction.commit_and_keep().unwrap();
+
+        assert_eq!(registers.reads(), vec![REG_STATE_0]);
+        assert_eq!(
+            registers.writes(),
+            vec![(REG_SET_0, 0b1010), (REG_CLEAR_0, 0b0101)]
+        );
+        assert_eq!(count, 3);
+        assert!(!transaction.has_pending_changes());
+    }
+
+    #[test]
+    fn test_toggle_explicit_level_wins() {
+        use consts::edge::{REG_CLEAR_0, REG_SET_0};
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let mut transaction = device.gpio_transaction();
+        transaction.toggle(pin(0)).unwrap();
+        transaction.toggle(pin(2)).unwrap();
+        transaction.set_high(pin(0)).unwrap();
+        transaction.set_low(pin(2)).unwrap();
+        transaction.toggle(pin(17)).unwrap();
+        transaction.toggle(pin(17)).unwrap();
+
+        transaction.commit().unwrap();
+        assert!(registers.reads().is_empty());
+        assert_eq!(
+            registers.writes(),
+            vec![(REG_SET_0, 0b0001), (REG_CLEAR_0, 0b0100)]
+        );
+    }
+
+    #[test]
+    fn test_pin_function_from_registers() {
+        use consts::edge::pwm_ctrl::ENABLE_MASK;
+        let pwm_on = |pin: u16| ENABLE_MASK | pin;
+
+        // FUNC_SEL bit clear: not controlled by EDGE, regardless of PWM routing
+        assert_eq!(
+            pin_function_from_registers(pin(3), 0xFFF7, [pwm_on(3), 0]),
+            PinFunction::Unassigned
+        );
+        // Assigned to EDGE and no enabled PWM routed to it
+        assert_eq!(
+            pin_function_from_registers(pin(3), 0x0008, [3, pwm_on(4)]),
+            PinFunction::Gpio
+        );
+        assert_eq!(
+            pin_function_from_registers(pin(3), 0x0008, [pwm_on(3), 0]),
+            PinFunction::Pwm0
+        );
+        assert_eq!(
+            pin_function_from_registers(pin(20), 0x0010, [pwm_on(4), pwm_on(20)]),
+            PinFunction::Pwm1
+        );
+    }
+
+    #[test]
+    fn test_drop_behavior_actions() {
+        let groups = [GpioGroup::Group0, GpioGroup::Group1];
+        assert!(DropBehavior::Leave.actions(&groups).is_empty());
+        assert_eq!(
+            DropBehavior::RestoreInputs.actions(&groups[..1]),
+            vec![DropAction::Inputs(GpioGroup::Group0)]
+        );
+        assert_eq!(
+            DropBehavior::DriveLevels(vec![
+                (pin(1), GpioLevel::High),
+                (pin(2), GpioLevel::Low),
+                (pin(20), GpioLevel::Low),
+            ])
+            .actions(&groups),
+            vec![
+                DropAction::Write(GpioGroup::Group0, 0x0006, 0x0002),
+                DropAction::Write(GpioGroup::Group1, 0x0010, 0x0000),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drop_behavior_is_recorded() {
+        let device = Xr2280x::new_for_test(8);
+        assert_eq!(device.gpio_get_drop_behavior(), DropBehavior::Leave);
+
+        let safe = DropBehavior::DriveLevels(vec![(pin(3), GpioLevel::Low)]);
+        device.gpio_set_drop_behavior(safe.clone()).unwrap();
+        assert_eq!(device.gpio_get_drop_behavior(), safe);
+
+        // Pins outside the device's GPIO range are rejected
+        let unsupported = DropBehavior::DriveLevels(vec![(pin(20), GpioLevel::Low)]);
+        assert!(device.gpio_set_drop_behavior(unsupported).is_err());
+        assert_eq!(device.gpio_get_drop_behavior(), safe);
+    }
+
+    #[test]
+    fn test_gpio_level_bool_conversions() {
+        assert_eq!(GpioLevel::from(true), GpioLevel::High);
+        assert_eq!(GpioLevel::from(false), GpioLevel::Low);
+        assert!(bool::from(GpioLevel::High));
+        assert!(!bool::from(GpioLevel::Low));
+        for value in [true, false] {
+            assert_eq!(bool::from(GpioLevel::from(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_gpio_level_inversion() {
+        assert_eq!(GpioLevel::High.inverted(), GpioLevel::Low);
+        assert_eq!(GpioLevel::Low.inverted(), GpioLevel::High);
+        for level in [GpioLevel::Low, GpioLevel::High] {
+            assert_eq!(!level, level.inverted());
+            assert_eq!(!!level, level);
+            assert_eq!(bool::from(!level), !bool::from(level));
+        }
+    }
+
+    #[test]
+    fn test_level_changes_diff() {
+        let pins = [pin(0), pin(5), pin(16)];
+        assert!(level_changes(&pins, [0x0021, 0x0001], [0x0021, 0x0001]).is_empty());
+        assert_eq!(
+            level_changes(&pins, [0x0001, 0x0000], [0x0020, 0x0001]),
+            vec![
+                (pin(0), GpioLevel::High, GpioLevel::Low),
+                (pin(5), GpioLevel::Low, GpioLevel::High),
+                (pin(16), GpioLevel::Low, GpioLevel::High),
+            ]
+        );
+        // Changes on pins that are not watched are ignored
+        assert!(level_changes(&pins, [0x0000, 0x0000], [0x0002, 0x0100]).is_empty());
+    }
+
+    #[test]
+    fn test_poll_changes_over_read_sequence() {
+        use consts::edge::{REG_STATE_0, REG_STATE_1};
+        let registers = MockRegisterFile::default()
+            .with_read_sequence(REG_STATE_0, &[0x0000, 0x0000, 0x0004, 0x0004])
+            .with_read_sequence(REG_STATE_1, &[0x0000, 0x0000, 0x0000, 0x0002]);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let mut events = Vec::new();
+
+        device
+            .gpio_poll_changes(
+                &[pin(2), pin(17)],
+                std::time::Duration::ZERO,
+                |pin, old, new| {
+                    events.push((pin.number(), old, new));
+                    if events.len() == 2 {
+                        std::ops::ControlFlow::Break(())
+                    } else {
+                        std::ops::ControlFlow::Continue(())
+                    }
+                },
+            )
+            .unwrap();
+
+        // The initial snapshot plus three polls, both groups each time
+        assert_eq!(registers.reads(), [REG_STATE_0, REG_STATE_1].repeat(4));
+        assert_eq!(
+            events,
+            vec![
+                (2, GpioLevel::Low, GpioLevel::High),
+                (17, GpioLevel::Low, GpioLevel::High),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_poll_changes_propagates_read_error() {
+        let registers =
+            MockRegisterFile::default().with_failing_register(consts::edge::REG_STATE_0);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers);
+        let result = device.gpio_poll_changes(&[pin(0)], std::time::Duration::ZERO, |_, _, _| {
+            std::ops::ControlFlow::Continue(())
+        });
+        assert!(matches!(result, Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn test_dump_registers_reads_each_register_once() {
+        // Every register holds its own address
+        let registers = (consts::edge::REG_FUNC_SEL_0..=consts::edge::REG_INTR_NEG_EDGE_1)
+            .fold(MockRegisterFile::default(), |registers, reg| {
+                registers.with_register(reg, reg)
+            });
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let dump = device.gpio_dump_registers().unwrap();
+
+        let mut reads = registers.reads();
+        assert_eq!(reads.len(), 24);
+        reads.sort_unstable();
+        reads.dedup();
+        assert_eq!(
+            reads,
+            (consts::edge::REG_FUNC_SEL_0..=consts::edge::REG_INTR_NEG_EDGE_1).collect::<Vec<_>>()
+        );
+        assert_eq!(dump.groups[0].func_sel, consts::edge::REG_FUNC_SEL_0);
+        assert_eq!(
+            dump.groups[1].intr_neg_edge,
+            consts::edge::REG_INTR_NEG_EDGE_1
+        );
+
+        let table = dump.to_string();
+        assert!(table.contains("Group1"));
+        assert!(table.contains("PULL_DOWN"));
+        assert!(table.contains("0x03D7"));
+    }
+
+    #[test]
+    fn test_reset_all_to_inputs_register_values() {
+        let registers = (consts::edge::REG_FUNC_SEL_0..=consts::edge::REG_INTR_NEG_EDGE_1)
+            .fold(MockRegisterFile::default(), |registers, reg| {
+                registers.with_register(reg, 0xA5A5)
+            });
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device.gpio_reset_all_to_inputs().unwrap();
+
+        assert_eq!(registers.writes().len(), 10);
+        assert!(registers.reads().is_empty());
+        for reg in [
+            consts::edge::REG_DIR_0,
+            consts::edge::REG_OPEN_DRAIN_0,
+            consts::edge::REG_TRI_STATE_0,
+            consts::edge::REG_PULL_UP_0,
+            consts::edge::REG_PULL_DOWN_0,
+            consts::edge::REG_DIR_1,
+            consts::edge::REG_OPEN_DRAIN_1,
+            consts::edge::REG_TRI_STATE_1,
+            consts::edge::REG_PULL_UP_1,
+            consts::edge::REG_PULL_DOWN_1,
+        ] {
+            assert_eq!(registers.register(reg), 0, "register 0x{reg:04X}");
+        }
+        assert_eq!(registers.register(consts::edge::REG_FUNC_SEL_0), 0xA5A5);
+        assert_eq!(registers.register(consts::edge::REG_SET_1), 0xA5A5);
+    }
+
+    #[test]
+    fn test_reset_all_to_inputs_direction_first_single_group() {
+        use consts::edge::*;
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        device.gpio_reset_all_to_inputs().unwrap();
+        assert_eq!(
+            registers.writes(),
+            [
+                REG_DIR_0,
+                REG_OPEN_DRAIN_0,
+                REG_TRI_STATE_0,
+                REG_PULL_UP_0,
+                REG_PULL_DOWN_0
+            ]
+            .map(|reg| (reg, 0x0000))
+        );
+    }
+
+    #[test]
+    fn test_dump_registers_skips_unsupported_group() {
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let dump = device.gpio_dump_registers().unwrap();
+        assert_eq!(registers.reads().len(), 12);
+        assert_eq!(dump.groups.len(), 1);
+    }
+
+    #[test]
+    fn test_commit_and_keep_cycles_are_independent() {
+        use consts::edge::{REG_CLEAR_0, REG_CLEAR_1, REG_SET_0};
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let mut transaction = device.gpio_transaction();
+
+        transaction.set_high(pin(0)).unwrap();
+        transaction.set_low(pin(17)).unwrap();
+        let count = transaction.commit_and_keep().unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(
+            registers.writes(),
+            vec![(REG_SET_0, 0x0001), (REG_CLEAR_1, 0x0002)]
+        );
+        assert!(!transaction.has_pending_changes());
+        assert_eq!(transaction.pending_pin_count(), 0);
+
+        transaction.set_low(pin(1)).unwrap();
+        let count = transaction.commit_and_keep().unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(registers.writes()[2..], [(REG_CLEAR_0, 0x0002)]);
+        assert!(!transaction.has_pending_changes());
+        assert_eq!(transaction.pending_pin_count(), 0);
+        assert!(registers.reads().is_empty());
+    }
+
+    #[test]
+    fn test_commit_and_keep_keeps_changes_on_error() {
+        let registers = MockRegisterFile::default().with_failing_register(consts::edge::REG_SET_0);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers);
+        let mut transaction = device.gpio_transaction();
+        transaction.set_high(pin(4)).unwrap();
+        let result = transaction.commit_and_keep();
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(transaction.has_pending_changes());
+        assert_eq!(transaction.pending_pin_count(), 1);
+    }
+
+    #[test]
+    fn test_swap_returns_previous_level() {
+        // Pin 4 reads High, pin 20 (bit 4 of group 1) reads Low
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_STATE_0, 0x0010)
+            .with_register(consts::edge::REG_STATE_1, 0x0000);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+
+        assert_eq!(
+            device.gpio_swap(pin(4), GpioLevel::Low).unwrap(),
+            GpioLevel::High
+        );
+        assert_eq!(
+            device.gpio_swap(pin(20), GpioLevel::High).unwrap(),
+            GpioLevel::Low
+        );
+        assert_eq!(
+            registers.writes(),
+            vec![
+                (consts::edge::REG_CLEAR_0, 0x0010),
+                (consts::edge::REG_SET_1, 0x0010)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pull_registers_accessed_once_per_group() {
+        use consts::edge::*;
+
+        let registers = MockRegisterFile::default()
+            .with_register(REG_PULL_UP_0, 0x00F0)
+            .with_register(REG_PULL_DOWN_0, 0x000F)
+            .with_register(REG_PULL_UP_1, 0xFFFF)
+            .with_register(REG_PULL_DOWN_1, 0x0000);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device
+            .gpio_set_pull_all(0x0003, GpioPull::Up, 0x8000, GpioPull::Down)
+            .unwrap();
+        assert_eq!(
+            registers.reads(),
+            [
+                REG_PULL_UP_0,
+                REG_PULL_DOWN_0,
+                REG_PULL_UP_1,
+                REG_PULL_DOWN_1
+            ]
+        );
+        assert_eq!(
+            registers.writes(),
+            [
+                (REG_PULL_DOWN_0, 0x000C),
+                (REG_PULL_UP_0, 0x00F3),
+                (REG_PULL_UP_1, 0x7FFF),
+                (REG_PULL_DOWN_1, 0x8000),
+            ]
+        );
+
+        // Groups without pins are not touched
+        let registers = MockRegisterFile::default().with_register(REG_PULL_UP_1, 0xFFFF);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device
+            .gpio_set_pull_all(0x0000, GpioPull::Up, 0x0001, GpioPull::None)
+            .unwrap();
+        assert_eq!(registers.reads(), [REG_PULL_UP_1, REG_PULL_DOWN_1]);
+        assert_eq!(
+            registers.writes(),
+            [(REG_PULL_UP_1, 0xFFFE), (REG_PULL_DOWN_1, 0x0000)]
+        );
+
+        let device = Xr2280x::new_for_test(8);
+        assert!(matches!(
+            device.gpio_set_pull_all(0x0001, GpioPull::Up, 0x0001, GpioPull::Up),
+            Err(Error::UnsupportedFeature(_))
+        ));
+    }
+
+    #[test]
+    fn test_group_span_of_pin_slices() {
+        let pins = |numbers: &[u8]| -> Vec<GpioPin> {
+            numbers.iter().map(|&n| GpioPin::new(n).unwrap()).collect()
+        };
+
+        assert_eq!(gpio_groups_for(&[]), GroupSpan::Empty);
+        assert_eq!(gpio_group_masks(&[]), (0, 0));
+
+        let low = pins(&[0, 3, 15]);
+        assert_eq!(gpio_groups_for(&low), GroupSpan::SingleGroup0);
+        assert_eq!(gpio_group_masks(&low), (0x8009, 0));
+
+        let high = pins(&[16, 31, 16]);
+        assert_eq!(gpio_groups_for(&high), GroupSpan::SingleGroup1);
+        assert_eq!(gpio_group_masks(&high), (0, 0x8001));
+
+        let both = pins(&[15, 16]);
+        assert_eq!(gpio_groups_for(&both), GroupSpan::Both);
+        assert_eq!(gpio_group_masks(&both), (0x8000, 0x0001));
+    }
+
+    #[test]
+    fn test_reconfigure_output_write_order() {
+        use consts::edge::*;
+
+        // Pin 17 (group 1): push-pull input with pull-down -> open-drain High with pull-up
+        let pin = GpioPin::new(17).unwrap();
+        let registers = MockRegisterFile::default()
+            .with_register(REG_PULL_DOWN_1, 0x0002)
+            .with_register(REG_DIR_1, 0x0001);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device
+            .gpio_reconfigure_output(pin, GpioLevel::High, GpioPull::Up, true)
+            .unwrap();
+        assert_eq!(
+            registers.writes(),
+            [
+                (REG_SET_1, 0x0002),
+                (REG_PULL_DOWN_1, 0x0000),
+                (REG_PULL_UP_1, 0x0002),
+                (REG_OPEN_DRAIN_1, 0x0002),
+                (REG_DIR_1, 0x0003),
+            ]
+        );
+
+        // Back to push-pull Low with no pull: only the changed registers are written
+        let written = registers.writes().len();
+        device
+            .gpio_reconfigure_output(pin, GpioLevel::Low, GpioPull::None, false)
+            .unwrap();
+        assert_eq!(
+            registers.writes()[written..],
+            [
+                (REG_CLEAR_1, 0x0002),
+                (REG_PULL_UP_1, 0x0000),
+                (REG_OPEN_DRAIN_1, 0x0000),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_change_masks_single_group() {
+        let [group0, group1] = group_change_masks(&[
+            (pin(0), GpioLevel::High),
+            (pin(3), GpioLevel::Low),
+            (pin(15), GpioLevel::High),
+        ]);
+        assert_eq!(group0.set_mask, 0x8001);
+        assert_eq!(group0.clear_mask, 0x0008);
+        assert!(!group1.has_changes());
+    }
+
+    #[test]
+    fn test_group_change_masks_cross_group() {
+        let [group0, group1] = group_change_masks(&[
+            (pin(1), GpioLevel::High),
+            (pin(16), GpioLevel::Low),
+            (pin(31), GpioLevel::High),
+        ]);
+        assert_eq!(group0.set_mask, 0x0002);
+        assert_eq!(group0.clear_mask, 0x0000);
+        assert_eq!(group1.set_mask, 0x8000);
+        assert_eq!(group1.clear_mask, 0x0001);
+    }
+
+    #[test]
+    fn test_group_change_masks_duplicate_pin_last_wins() {
+        let [group0, _] =
+            group_change_masks(&[(pin(2), GpioLevel::High), (pin(2), GpioLevel::Low)]);
+        assert_eq!(group0.set_mask, 0x0000);
+        assert_eq!(group0.clear_mask, 0x0004);
+
+        let [_, group1] =
+            group_change_masks(&[(pin(20), GpioLevel::Low), (pin(20), GpioLevel::High)]);
+        assert_eq!(group1.set_mask, 0x0010);
+        assert_eq!(group1.clear_mask, 0x0000);
+    }
+
+    #[test]
+    fn test_snapshot_levels_match_group_reads() {
+        use consts::edge::{REG_STATE_0, REG_STATE_1};
+        let registers = MockRegisterFile::default()
+            .with_register(REG_STATE_0, 0x8005)
+            .with_register(REG_STATE_1, 0x0102);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let snapshot = device.gpio_read_snapshot().unwrap();
+        assert_eq!(registers.reads(), vec![REG_STATE_0, REG_STATE_1]);
+        for (n, level) in [
+            (0, GpioLevel::High),
+            (1, GpioLevel::Low),
+            (2, GpioLevel::High),
+            (15, GpioLevel::High),
+            (16, GpioLevel::Low),
+            (17, GpioLevel::High),
+            (24, GpioLevel::High),
+            (31, GpioLevel::Low),
+        ] {
+            assert_eq!(snapshot.level(pin(n)), Some(level), "pin {n}");
+        }
+        assert_eq!(snapshot.group_state(GpioGroup::Group1), Some(0x0102));
+        assert_eq!(snapshot.bits(), 0x0102_8005);
+        assert!(snapshot.is_fresh(std::time::Duration::from_secs(60)));
+        assert!(!snapshot.is_fresh(std::time::Duration::ZERO));
+
+        // Groups the device doesn't have have no levels
+        let registers = MockRegisterFile::default().with_register(REG_STATE_0, 0x0001);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers);
+        let snapshot = device.gpio_read_snapshot().unwrap();
+        assert_eq!(snapshot.level(pin(0)), Some(GpioLevel::High));
+        assert_eq!(snapshot.level(pin(16)), None);
+        assert_eq!(snapshot.group_state(GpioGroup::Group1), None);
+    }
+
+    #[test]
+    fn test_snapshot_masks_missing_pins() {
+        // Pins 8-15 don't exist on 8-pin parts, whatever STATE_0 says about them
+        let registers =
+            MockRegisterFile::default().with_register(consts::edge::REG_STATE_0, 0xFF81);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers);
+        let snapshot = device.gpio_read_snapshot().unwrap();
+        assert_eq!(snapshot.group_state(GpioGroup::Group0), Some(0x0081));
+        assert_eq!(snapshot.level(pin(7)), Some(GpioLevel::High));
+        assert_eq!(snapshot.level(pin(8)), None);
+        assert_eq!(snapshot.level(pin(15)), None);
+        assert_eq!(snapshot.bits(), 0x0000_0081);
+    }
+
+    #[test]
+    fn test_active_low_pin_drives_low_when_asserted() {
+        assert_eq!(
+            GpioPolarity::ActiveLow.level(Logical::Asserted),
+            GpioLevel::Low
+        );
+        assert_eq!(
+            GpioPolarity::ActiveLow.level(Logical::Deasserted),
+            GpioLevel::High
+        );
+        assert_eq!(
+            GpioPolarity::ActiveHigh.level(Logical::Asserted),
+            GpioLevel::High
+        );
+        assert_eq!(
+            GpioPolarity::ActiveLow.logical(GpioLevel::Low),
+            Logical::Asserted
+        );
+        assert_eq!(
+            GpioPolarity::ActiveHigh.logical(GpioLevel::Low),
+            Logical::Deasserted
+        );
+
+        let device = Xr2280x::new_for_test(32);
+        device
+            .gpio_set_polarity(pin(20), GpioPolarity::ActiveLow)
+            .unwrap();
+        assert_eq!(device.gpio_get_polarity(pin(20)), GpioPolarity::ActiveLow);
+        assert_eq!(device.gpio_get_polarity(pin(4)), GpioPolarity::ActiveHigh);
+        device
+            .gpio_set_polarity(pin(20), GpioPolarity::ActiveHigh)
+            .unwrap();
+        assert_eq!(device.gpio_get_polarity(pin(20)), GpioPolarity::ActiveHigh);
+
+        let device = Xr2280x::new_for_test(8);
+        assert!(
+            device
+                .gpio_set_polarity(pin(8), GpioPolarity::ActiveLow)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_output_mode_register_bits() {
+        use consts::edge::*;
+        let (od, ts) = (REG_OPEN_DRAIN_1, REG_TRI_STATE_1);
+        let run = |mode, start: (u16, u16)| {
+            let registers = MockRegisterFile::default()
+                .with_register(od, start.0)
+                .with_register(ts, start.1);
+            let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+            device
+                .gpio_set_output_mode_masked(GpioGroup::Group1, 0x0030, mode)
+                .unwrap();
+            (
+                (registers.register(od), registers.register(ts)),
+                registers.writes(),
+            )
+        };
+
+        // Other pins' bits are preserved
+        let (bits, _) = run(OutputMode::PushPull, (0x8030, 0x0130));
+        assert_eq!(bits, (0x8000, 0x0100));
+        let (bits, _) = run(OutputMode::OpenDrain, (0x8000, 0x0130));
+        assert_eq!(bits, (0x8030, 0x0100));
+        let (bits, _) = run(OutputMode::TriState, (0x8030, 0x0100));
+        assert_eq!(bits, (0x8000, 0x0130));
+
+        // Tri-state is entered before open-drain changes and left after it
+        let (_, writes) = run(OutputMode::TriState, (0x0030, 0x0000));
+        assert_eq!(writes, vec![(ts, 0x0030), (od, 0x0000)]);
+        let (_, writes) = run(OutputMode::OpenDrain, (0x0000, 0x0030));
+        assert_eq!(writes, vec![(od, 0x0030), (ts, 0x0000)]);
+
+        // Registers already holding the mode are not rewritten
+        let (_, writes) = run(OutputMode::PushPull, (0x8000, 0x0000));
+        assert!(writes.is_empty());
+
+        assert_eq!(
+            OutputMode::from_flags(true, false).unwrap(),
+            OutputMode::OpenDrain
+        );
+        assert_eq!(
+            OutputMode::from_flags(false, true).unwrap(),
+            OutputMode::TriState
+        );
+        assert!(matches!(
+            OutputMode::from_flags(true, true),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_transaction_for_validates_pins_up_front() {
+        let device = Xr2280x::new_for_test(8);
+        let result = device.gpio_transaction_for(&[pin(0), pin(7), pin(8)]);
+        assert!(matches!(result, Err(Error::UnsupportedFeature(_))));
+
+        let mut transaction = device.gpio_transaction_for(&[pin(0), pin(7)]).unwrap();
+        transaction.set_pin(pin(7), GpioLevel::High).unwrap();
+        assert_eq!(transaction.pending_pin_count(), 1);
+        transaction.clear();
+
+        let device = Xr2280x::new_for_test(32);
+        assert!(device.gpio_transaction_for(&[pin(8), pin(31)]).is_ok());
+    }
+
+    #[test]
+    fn test_masked_write_verification() {
+        // Pin 2 of the mask stays low, pin 9 outside the mask is ignored
+        let result = verify_masked_write(GpioGroup::Group1, 0x0006, 0x0006, 0x0202);
+        match result {
+            Err(Error::GpioMaskedWriteVerificationFailed {
+                group,
+                failed_mask,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(group, GpioGroup::Group1);
+                assert_eq!(failed_mask, 0x0004);
+                assert_eq!(expected, 0x0006);
+                assert_eq!(actual, 0x0002);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        // A pin written low that reads high also fails
+        let result = verify_masked_write(GpioGroup::Group0, 0x0003, 0x0001, 0x0003);
+        assert!(matches!(
+            result,
+            Err(Error::GpioMaskedWriteVerificationFailed {
+                failed_mask: 0x0002,
+                ..
+            })
+        ));
+
+        assert!(verify_masked_write(GpioGroup::Group0, 0x00FF, 0x0055, 0xFF55).is_ok());
+    }
+
+    #[test]
+    fn test_assign_to_edge_masked_touches_func_sel_once() {
+        use consts::edge::{REG_FUNC_SEL_0, REG_FUNC_SEL_1};
+        let registers = MockRegisterFile::default()
+            .with_register(REG_FUNC_SEL_0, 0x0003)
+            .with_register(REG_FUNC_SEL_1, 0x8000);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+
+        for group in [GpioGroup::Group0, GpioGroup::Group1] {
+            device.gpio_assign_to_edge_masked(group, 0x00F0).unwrap();
+        }
+        assert_eq!(registers.reads(), [REG_FUNC_SEL_0, REG_FUNC_SEL_1]);
+        assert_eq!(
+            registers.writes(),
+            [(REG_FUNC_SEL_0, 0x00F3), (REG_FUNC_SEL_1, 0x80F0)]
+        );
+
+        // Already assigned: read only; empty mask: nothing
+        device
+            .gpio_assign_to_edge_masked(GpioGroup::Group0, 0x0030)
+            .unwrap();
+        device
+            .gpio_assign_to_edge_masked(GpioGroup::Group1, 0)
+            .unwrap();
+        assert_eq!(registers.reads().len(), 3);
+        assert_eq!(registers.writes().len(), 2);
+    }
+
+    #[test]
+    fn test_gpio32_requires_32_pins() {
+        assert!(matches!(
+            Xr2280x::new_for_test(8).gpio32(),
+            Err(Error::UnsupportedFeature(_))
+        ));
+        let device = Xr2280x::new_for_test(32);
+        assert!(std::ptr::eq(device.gpio32().unwrap().device(), &device));
+
+        let split: Vec<_> = split_port_mask(0x8001_0000, 0xFFFF_FFFF).collect();
+        assert_eq!(split, vec![(GpioGroup::Group1, 0x8001, 0xFFFF)]);
+        let split: Vec<_> = split_port_mask(0x0003_00F0, 0x0001_0010).collect();
+        assert_eq!(
+            split,
+            vec![
+                (GpioGroup::Group0, 0x00F0, 0x0010),
+                (GpioGroup::Group1, 0x0003, 0x0001),
+            ]
+        );
+        assert_eq!(split_port_mask(0, u32::MAX).count(), 0);
+    }
+
+    #[test]
+    fn test_configure_pins_sets_direction_and_function() {
+        let (reg_func, reg_dir) = (consts::edge::REG_FUNC_SEL_1, consts::edge::REG_DIR_1);
+        let registers = MockRegisterFile::default()
+            .with_register(reg_func, 0x0100)
+            .with_register(reg_dir, 0x0001);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let written_registers =
+            || -> Vec<u16> { registers.writes().iter().map(|&(reg, _)| reg).collect() };
+
+        device
+            .gpio_configure_pins_masked(GpioGroup::Group1, 0x00F0, GpioDirection::Output, true)
+            .unwrap();
+        assert_eq!(registers.register(reg_func), 0x01F0);
+        assert_eq!(registers.register(reg_dir), 0x00F1);
+        // Direction first, so pins are handed to EDGE already configured
+        assert_eq!(written_registers(), vec![reg_dir, reg_func]);
+
+        // Without assignment FUNC_SEL is left alone
+        device
+            .gpio_configure_pins_masked(GpioGroup::Group1, 0x0030, GpioDirection::Input, false)
+            .unwrap();
+        assert_eq!(registers.register(reg_dir), 0x00C1);
+        assert_eq!(written_registers(), vec![reg_dir, reg_func, reg_dir]);
+
+        // Nothing changes: nothing is written
+        device
+            .gpio_configure_pins_masked(GpioGroup::Group1, 0x00C0, GpioDirection::Output, true)
+            .unwrap();
+        assert_eq!(registers.writes().len(), 3);
+    }
+
+    #[test]
+    fn test_port_write_drives_outputs_with_set_and_clear() {
+        let write_port = |dir: u16, value: u16| {
+            let registers = MockRegisterFile::default().with_register(consts::edge::REG_DIR_0, dir);
+            let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+            device.gpio_port_write(GpioGroup::Group0, value).unwrap();
+            assert_eq!(registers.reads(), [consts::edge::REG_DIR_0]);
+            registers.writes()
+        };
+
+        assert_eq!(
+            write_port(0xFFFF, 0xA5A5),
+            vec![
+                (consts::edge::REG_SET_0, 0xA5A5),
+                (consts::edge::REG_CLEAR_0, 0x5A5A)
+            ]
+        );
+        // Input pins are left out of both masks
+        assert_eq!(
+            write_port(0x00FF, 0xA5A5),
+            vec![
+                (consts::edge::REG_SET_0, 0x00A5),
+                (consts::edge::REG_CLEAR_0, 0x005A)
+            ]
+        );
+        // All outputs High: no CLEAR write
+        assert_eq!(
+            write_port(0x000F, 0xFFFF),
+            vec![(consts::edge::REG_SET_0, 0x000F)]
+        );
+        assert!(write_port(0x0000, 0xA5A5).is_empty());
+    }
+
+    #[test]
+    fn test_gpio_diff_synthesizes_edges() {
+        use GpioEdge::{Falling, Rising};
+        assert!(gpio_diff(0xDEAD_BEEF, 0xDEAD_BEEF).is_empty());
+        assert_eq!(gpio_diff(0x0000_0000, 0x0000_0001), vec![(pin(0), Rising)]);
+        assert_eq!(
+            gpio_diff(0x8000_0001, 0x0001_0002),
+            vec![
+                (pin(0), Falling),
+                (pin(1), Rising),
+                (pin(16), Rising),
+                (pin(31), Falling)
+            ]
+        );
+        assert_eq!(gpio_diff(0, u32::MAX).len(), 32);
+
+        let mut detector = GpioEdgeDetector::new();
+        assert!(detector.update(0x0000_00F0).is_empty());
+        assert_eq!(
+            detector.update(0x0000_0078),
+            vec![(pin(3), Rising), (pin(7), Falling)]
+        );
+        assert!(detector.update(0x0000_0078).is_empty());
+        assert_eq!(detector.previous(), Some(0x0000_0078));
+
+        detector.reset();
+        assert!(detector.update(0).is_empty());
+    }
+
+    #[test]
+    fn test_set_direction_against_mock_registers() {
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_DIR_0, 0x0001)
+            .with_register(consts::edge::REG_DIR_1, 0x8000);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+
+        device
+            .gpio_set_direction(pin(4), GpioDirection::Output)
+            .unwrap();
+        device
+            .gpio_set_direction(pin(31), GpioDirection::Input)
+            .unwrap();
+        assert_eq!(registers.register(consts::edge::REG_DIR_0), 0x0011);
+        assert_eq!(registers.register(consts::edge::REG_DIR_1), 0x0000);
+        assert_eq!(
+            registers.writes(),
+            vec![
+                (consts::edge::REG_DIR_0, 0x0011),
+                (consts::edge::REG_DIR_1, 0x0000)
+            ]
+        );
+        assert_eq!(
+            device.gpio_get_direction(pin(4)).unwrap(),
+            GpioDirection::Output
+        );
+        assert_eq!(
+            device.gpio_get_direction(pin(0)).unwrap(),
+            GpioDirection::Output
+        );
+        assert_eq!(
+            device.gpio_get_direction(pin(5)).unwrap(),
+            GpioDirection::Input
+        );
+    }
+
+    #[test]
+    fn test_setup_output_checked_reports_mismatch() {
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+
+        let config = device
+            .gpio_setup_output_checked(pin(2), GpioLevel::High, GpioPull::Up)
+            .unwrap();
+        assert_eq!(
+            config,
+            GpioPinConfig {
+                direction: GpioDirection::Output,
+                pull: GpioPull::Up,
+                level: GpioLevel::High,
+            }
+        );
+        assert_eq!(registers.register(consts::edge::REG_DIR_0), 0x0004);
+
+        // Pin 3 stays Low although High was requested, e.g. shorted to ground
+        let registers =
+            MockRegisterFile::default().with_read_sequence(consts::edge::REG_STATE_0, &[0x0000]);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers);
+        let result = device.gpio_setup_output_checked(pin(3), GpioLevel::High, GpioPull::None);
+        assert!(matches!(
+            result,
+            Err(Error::GpioConfigurationError { pin: 3, ref message })
+                if message == "read back level Low (requested High)"
+        ));
+    }
+
+    #[test]
+    fn test_identify_blink_count() {
+        let ms = std::time::Duration::from_millis;
+        assert_eq!(identify_blink_count(std::time::Duration::ZERO), 0);
+        assert_eq!(identify_blink_count(ms(1)), 1);
+        assert_eq!(identify_blink_count(ms(500)), 1);
+        assert_eq!(identify_blink_count(ms(501)), 2);
+        assert_eq!(identify_blink_count(ms(1200)), 3);
+        assert_eq!(identify_blink_count(std::time::Duration::from_secs(5)), 10);
+    }
+
+    #[test]
+    fn test_identify_blinks_output_pin() {
+        use consts::edge::{REG_CLEAR_0, REG_SET_0};
+        // Pin 3 is an output driving High
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_DIR_0, 0x0008)
+            .with_register(consts::edge::REG_STATE_0, 0x0008);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+
+        let start = std::time::Instant::now();
+        device
+            .device_identify(pin(3), std::time::Duration::from_millis(1))
+            .unwrap();
+        assert!(start.elapsed() >= IDENTIFY_BLINK_PERIOD);
+        // Low, one High/Low blink, then the saved level again
+        assert_eq!(
+            level_writes(&registers),
+            [REG_CLEAR_0, REG_SET_0, REG_CLEAR_0, REG_SET_0].map(|reg| (reg, 0x0008))
+        );
+
+        // A failed write ends the blinking, and the pin is restored anyway
+        let registers = MockRegisterFile::default().with_failing_register(REG_SET_0);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let result = device.device_identify(pin(2), std::time::Duration::from_secs(5));
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert_eq!(level_writes(&registers), vec![(REG_CLEAR_0, 0x0004)]);
+        assert_eq!(registers.register(consts::edge::REG_DIR_0), 0x0000);
+    }
+
+    #[test]
+    fn test_identify_restores_pin_config() {
+        // Pin 3 is an output driving High, pin 2 an input with a pull-up
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_DIR_0, 0x0008)
+            .with_register(consts::edge::REG_STATE_0, 0x0008)
+            .with_register(consts::edge::REG_PULL_UP_0, 0x0004);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        device.gpio_set_output_cache(true);
+
+        device
+            .device_identify(pin(2), std::time::Duration::ZERO)
+            .unwrap();
+        assert_eq!(registers.register(consts::edge::REG_DIR_0), 0x0008);
+        assert_eq!(registers.register(consts::edge::REG_PULL_UP_0), 0x0004);
+        let reads = registers.reads().len();
+        device.gpio_read_cached(pin(2)).unwrap();
+        assert_eq!(
+            registers.reads()[reads..],
+            [consts::edge::REG_STATE_0],
+            "the blink's Low level is not cached for the input pin"
+        );
+
+        device
+            .device_identify(pin(3), std::time::Duration::ZERO)
+            .unwrap();
+        assert_eq!(registers.register(consts::edge::REG_DIR_0), 0x0008);
+        assert_eq!(
+            registers.writes().last(),
+            Some(&(consts::edge::REG_SET_0, 0x0008))
+        );
+        assert_eq!(device.gpio_read_cached(pin(3)).unwrap(), GpioLevel::High);
+    }
+}
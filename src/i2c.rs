@@ -112,8 +112,9 @@
 
 use crate::consts;
 use crate::device::Xr2280x;
-use crate::error::{Error, Result};
+use crate::error::{Error, ErrorCategory, Result, hid_error};
 use crate::flags;
+use hidapi::HidDevice;
 use log::{debug, trace, warn};
 
 // HID Report Structure Constants - I2C Communication
@@ -138,6 +139,12 @@ mod request_offsets {
     pub const WRITE_DATA_10BIT_START: usize = 5;
 }
 
+/// Flags for a complete transfer: START, address, data, STOP.
+const START_STOP: u8 = flags::i2c::START_BIT | flags::i2c::STOP_BIT;
+
+/// Flags for an address-only probe: START, address, STOP.
+const PING_FLAGS: u8 = flags::i2c::START_BIT | flags::i2c::STOP_BIT;
+
 /// I2C Response Report Structure (Incoming)
 #[allow(dead_code)]
 mod response_offsets {
@@ -150,12 +157,14 @@ mod response_offsets {
     /// Offset where read data begins in incoming HID report
     pub const READ_DATA_START: usize = 4;
 }
+use std::collections::HashMap;
 use std::fmt;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Default timeouts for different I2C operations (in milliseconds).
 ///
 /// These constants provide operation-specific defaults that balance performance with reliability.
+/// They initialize each handle's [`Timeouts`], which can be changed at runtime.
 /// Unlike traditional I2C where ACK/NACK responses are instantaneous, HID-based I2C communication
 /// involves multiple layers (USB, HID, firmware) that introduce latency and potential delays.
 ///
@@ -237,8 +246,86 @@ pub mod timeouts {
     pub const EEPROM_WRITE: i32 = 5000;
 }
 
+/// Default I2C timeouts of one device handle, in milliseconds.
+///
+/// Every I2C method that doesn't take a timeout uses the matching field, scaled by
+/// [`Xr2280x::i2c_set_timeout_scale`]. Each handle starts with the [`timeouts`]
+/// constants and can be tuned at runtime, e.g. on slow VMs:
+///
+/// ```no_run
+/// # use xr2280x_hid::*;
+/// # use hidapi::HidApi;
+/// # fn main() -> Result<()> {
+/// # let hid_api = HidApi::new()?;
+/// let mut device = Xr2280x::device_open_first(&hid_api)?;
+/// device.timeouts_mut().read = 300;
+/// device.timeouts_mut().write_read = 500;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Firmware responsiveness probes (default [`timeouts::PROBE`]).
+    pub probe: i32,
+    /// Per-address scan transfers (default [`timeouts::SCAN`]).
+    pub scan: i32,
+    /// Reads (default [`timeouts::READ`]).
+    pub read: i32,
+    /// Writes (default [`timeouts::WRITE`]).
+    pub write: i32,
+    /// Write-then-read transfers (default [`timeouts::WRITE_READ`]).
+    pub write_read: i32,
+    /// EEPROM writes (default [`timeouts::EEPROM_WRITE`]).
+    pub eeprom_write: i32,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Timeouts {
+            probe: timeouts::PROBE,
+            scan: timeouts::SCAN,
+            read: timeouts::READ,
+            write: timeouts::WRITE,
+            write_read: timeouts::WRITE_READ,
+            eeprom_write: timeouts::EEPROM_WRITE,
+        }
+    }
+}
+
+impl Timeouts {
+    /// Every timeout scaled by `scale`, see [`scale_timeout`].
+    fn scaled(self, scale: f64) -> Self {
+        Timeouts {
+            probe: scale_timeout(self.probe, scale),
+            scan: scale_timeout(self.scan, scale),
+            read: scale_timeout(self.read, scale),
+            write: scale_timeout(self.write, scale),
+            write_read: scale_timeout(self.write_read, scale),
+            eeprom_write: scale_timeout(self.eeprom_write, scale),
+        }
+    }
+}
+
 /// Represents a 7-bit or 10-bit I2C slave address.
 /// Use `I2cAddress::new_7bit(addr)` or `I2cAddress::new_10bit(addr)`.
+///
+/// ## 7-bit addresses vs. 8-bit wire bytes
+///
+/// This crate always takes the **7-bit** address (0x00-0x7F), e.g. `0x50` for a
+/// 24C02 EEPROM. On the bus, the address is sent shifted left by one with the R/W
+/// bit in bit 0, so the same EEPROM shows up as `0xA0` (write) and `0xA1` (read).
+/// Datasheets and logic analyzers often quote these "8-bit addresses"; convert them
+/// with [`I2cAddress::from_wire_byte`] instead of passing them directly, which
+/// would address a different device (or fail, for values above 0x7F).
+///
+/// ```
+/// use xr2280x_hid::I2cAddress;
+///
+/// let eeprom = I2cAddress::from_wire_byte(0xA0); // Datasheet says "0xA0/0xA1"
+/// assert_eq!(eeprom, I2cAddress::Bit7(0x50));
+/// assert_eq!(eeprom.wire_byte_write(), 0xA0);
+/// assert_eq!(eeprom.wire_byte_read(), 0xA1);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum I2cAddress {
     /// Standard 7-bit address (0x00 - 0x7F).
@@ -267,6 +354,72 @@ impl I2cAddress {
             Err(Error::InvalidI2c10BitAddress(addr))
         }
     }
+
+    /// The address value without its width, e.g. `0x50` for both `Bit7(0x50)` and
+    /// `Bit10(0x050)`.
+    pub fn bits(&self) -> u16 {
+        match *self {
+            I2cAddress::Bit7(addr) => u16::from(addr),
+            I2cAddress::Bit10(addr) => addr,
+        }
+    }
+
+    /// Interprets an 8-bit wire byte (address and R/W bit) as a 7-bit address.
+    ///
+    /// The R/W bit is ignored, so `0xA0` and `0xA1` both give `Bit7(0x50)`.
+    pub fn from_wire_byte(byte: u8) -> Self {
+        I2cAddress::Bit7(byte >> 1)
+    }
+
+    /// The first byte sent on the wire to write to this address (R/W bit clear).
+    ///
+    /// For a 7-bit address this is the address shifted left by one. For a 10-bit
+    /// address it is the `11110xx0` prefix byte carrying the two high address bits;
+    /// the low eight bits follow in a second byte.
+    pub fn wire_byte_write(&self) -> u8 {
+        match *self {
+            I2cAddress::Bit7(addr) => addr << 1,
+            I2cAddress::Bit10(addr) => 0xF0 | ((addr >> 7) as u8 & 0x06),
+        }
+    }
+
+    /// The first byte sent on the wire to read from this address (R/W bit set).
+    ///
+    /// See [`I2cAddress::wire_byte_write`].
+    pub fn wire_byte_read(&self) -> u8 {
+        self.wire_byte_write() | 0x01
+    }
+}
+
+impl std::str::FromStr for I2cAddress {
+    type Err = Error;
+
+    /// Parses `"0x50"` or `"7:0x50"` as a 7-bit address and `"10:0x123"` as a 10-bit
+    /// address. Values are hexadecimal with a `0x` prefix or decimal without one.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            Error::ArgumentOutOfRange(format!(
+                "invalid I2C address '{s}' (expected e.g. '0x50', '7:0x50' or '10:0x123')"
+            ))
+        };
+        let (width, value) = match s.trim().split_once(':') {
+            Some((width, value)) => (width.trim(), value.trim()),
+            None => ("7", s.trim()),
+        };
+        let value = match value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            Some(hex) => u16::from_str_radix(hex, 16),
+            None => value.parse(),
+        }
+        .map_err(|_| invalid())?;
+        match width {
+            "7" => I2cAddress::new_7bit(u8::try_from(value).map_err(|_| invalid())?),
+            "10" => I2cAddress::new_10bit(value),
+            _ => Err(invalid()),
+        }
+    }
 }
 
 impl fmt::Display for I2cAddress {
@@ -278,6 +431,335 @@ impl fmt::Display for I2cAddress {
     }
 }
 
+/// Options for [`Xr2280x::i2c_scan_with_config`].
+///
+/// The default matches [`Xr2280x::i2c_scan_with_progress`]: a single fast pass with
+/// [`timeouts::SCAN`] per address. Devices that need time after power-up benefit
+/// from an inter-address delay, and a confirmation pass filters out addresses that
+/// only acknowledged once because of a bus glitch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScanConfig {
+    /// Timeout per address in milliseconds for the main pass.
+    pub timeout_ms: i32,
+    /// Delay between probing consecutive addresses (None = no delay).
+    pub inter_address_delay: Option<std::time::Duration>,
+    /// If set, every address that acknowledged is probed again with this timeout
+    /// (in milliseconds) and dropped if it does not acknowledge a second time.
+    pub confirm_timeout_ms: Option<i32>,
+}
+
+impl Default for ScanConfig {
+    fn default() -> Self {
+        Self {
+            timeout_ms: timeouts::SCAN,
+            inter_address_delay: None,
+            confirm_timeout_ms: None,
+        }
+    }
+}
+
+impl ScanConfig {
+    /// A slower, more robust scan: 1ms between addresses and a confirmation pass
+    /// with [`timeouts::READ`].
+    pub fn thorough() -> Self {
+        Self {
+            timeout_ms: timeouts::SCAN,
+            inter_address_delay: Some(std::time::Duration::from_millis(1)),
+            confirm_timeout_ms: Some(timeouts::READ),
+        }
+    }
+}
+
+/// Summary of an I2C bus health check, returned by [`Xr2280x::i2c_health_check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusHealth {
+    /// Number of devices that acknowledged their address.
+    pub device_count: usize,
+    /// 7-bit addresses of the devices that were found.
+    pub addresses: Vec<u8>,
+    /// True if the scan detected a stuck bus (e.g. SDA/SCL held low).
+    pub bus_stuck: bool,
+    /// How long the check took.
+    pub scan_duration: std::time::Duration,
+}
+
+impl BusHealth {
+    /// Builds a health summary from a scan result, turning a stuck-bus timeout into `bus_stuck`.
+    fn from_scan(scan: Result<Vec<u8>>, scan_duration: std::time::Duration) -> Result<Self> {
+        let (addresses, bus_stuck) = match scan {
+            Ok(addresses) => (addresses, false),
+            Err(Error::I2cTimeout { address }) => {
+                warn!("I2C health check: bus appears stuck (timeout at {address})");
+                (Vec::new(), true)
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(Self {
+            device_count: addresses.len(),
+            addresses,
+            bus_stuck,
+            scan_duration,
+        })
+    }
+}
+
+/// Raw outcome of an I2C transfer, returned by [`Xr2280x::i2c_transfer_raw_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct I2cTransferStatus {
+    /// Status byte of the I2C_SLAVE_IN report, unchanged. Bits 0-3 are the request
+    /// error, NAK, arbitration lost and timeout flags (see [`crate::flags::i2c_status`]);
+    /// bits 4-7 are the firmware's sequence number.
+    pub status_flags: u8,
+    /// Number of bytes copied into the read buffer.
+    pub bytes_read: usize,
+}
+
+/// An I2C target device bound to a fixed address, created by
+/// [`Xr2280x::i2c_device_7bit`] or [`Xr2280x::i2c_device_10bit`].
+///
+/// Like a Linux `i2c-dev` handle after `I2C_SLAVE`, every call goes to the stored
+/// address with the same default timeouts as the `Xr2280x` methods. The handle
+/// borrows the device, so several handles for different targets can coexist.
+///
+/// # Register cache
+///
+/// Registers enabled with [`I2cDeviceHandle::cache_reg8`] can be read with
+/// [`I2cDeviceHandle::read_reg8_cached`], which returns the last value read within the
+/// register's TTL instead of issuing a transfer. The cache lives in the `Xr2280x`, so
+/// handles bound to the same address share it.
+///
+/// A cached value can be stale for up to its TTL:
+/// - Only [`I2cDeviceHandle::write_reg8`] and [`I2cDeviceHandle::update_reg8`] clear
+///   the entry they write. Writes through [`I2cDeviceHandle::write`], the `Xr2280x`
+///   methods or another bus master are not seen; call [`I2cDeviceHandle::invalidate`]
+///   after them.
+/// - The device may change the register on its own (status flags, measurement results,
+///   clear-on-read bits) or lose it on a reset. Only cache configuration and
+///   identification registers, or use a TTL shorter than the update interval.
+///
+/// ```no_run
+/// # use xr2280x_hid::*;
+/// # use hidapi::HidApi;
+/// # fn main() -> Result<()> {
+/// # let hid_api = HidApi::new()?;
+/// # let device = Xr2280x::device_open_first(&hid_api)?;
+/// let sensor = device.i2c_device_7bit(0x48)?;
+/// sensor.write_reg8(0x01, 0x60)?; // Configuration register
+/// let mut temperature = [0u8; 2];
+/// sensor.read_regs(0x00, &mut temperature)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct I2cDeviceHandle<'a> {
+    device: &'a Xr2280x,
+    address: I2cAddress,
+}
+
+impl I2cDeviceHandle<'_> {
+    /// The address this handle is bound to.
+    pub fn address(&self) -> I2cAddress {
+        self.address
+    }
+
+    /// Writes `data` (max 32 bytes) to the device.
+    pub fn write(&self, data: &[u8]) -> Result<()> {
+        self.transfer(data, None, self.device.default_timeouts().write)
+    }
+
+    /// Reads `buffer.len()` bytes (max 32) from the device.
+    pub fn read(&self, buffer: &mut [u8]) -> Result<()> {
+        self.transfer(&[], Some(buffer), self.device.default_timeouts().read)
+    }
+
+    /// Writes `write_data`, then reads into `read_buffer` in one transaction.
+    pub fn write_read(&self, write_data: &[u8], read_buffer: &mut [u8]) -> Result<()> {
+        self.transfer(
+            write_data,
+            Some(read_buffer),
+            self.device.default_timeouts().write_read,
+        )
+    }
+
+    /// Reads the 8-bit register `reg` (register pointer write, then a 1-byte read).
+    pub fn read_reg8(&self, reg: u8) -> Result<u8> {
+        let mut value = [0u8];
+        self.write_read(&[reg], &mut value)?;
+        Ok(value[0])
+    }
+
+    /// Writes `value` to the 8-bit register `reg`, clearing its cached value.
+    pub fn write_reg8(&self, reg: u8, value: u8) -> Result<()> {
+        self.invalidate(reg);
+        self.write(&[reg, value])
+    }
+
+    /// Updates the bits in `mask` of the 8-bit register `reg` to those of `value`, as
+    /// [`Xr2280x::i2c_update_reg8`] does. The register's cached value is cleared.
+    pub fn update_reg8(&self, reg: u8, mask: u8, value: u8) -> Result<bool> {
+        self.invalidate(reg);
+        self.update_reg(&[reg], mask, value)
+    }
+
+    /// Enables caching of the 8-bit register `reg` for [`Self::read_reg8_cached`],
+    /// keeping each value read for `ttl`.
+    ///
+    /// Calling it again replaces the TTL and clears the cached value. See the
+    /// [register cache](Self#register-cache) notes on staleness.
+    pub fn cache_reg8(&self, reg: u8, ttl: Duration) {
+        self.device
+            .i2c_register_cache
+            .lock()
+            .unwrap()
+            .set_ttl((self.address, reg), ttl);
+    }
+
+    /// Reads the 8-bit register `reg`, returning the cached value if it was read less
+    /// than its TTL ago.
+    ///
+    /// Registers not enabled with [`Self::cache_reg8`] are always read from the device,
+    /// like [`Self::read_reg8`].
+    pub fn read_reg8_cached(&self, reg: u8) -> Result<u8> {
+        let mut cache = self.device.i2c_register_cache.lock().unwrap();
+        let key = (self.address, reg);
+        let now = Instant::now();
+        if let Some(value) = cache.get(key, now) {
+            trace!("Register 0x{reg:02X} of {} read from cache", self.address);
+            return Ok(value);
+        }
+        let value = self.read_reg8(reg)?;
+        cache.store(key, value, now);
+        Ok(value)
+    }
+
+    /// Clears the cached value of `reg`, so the next [`Self::read_reg8_cached`] reads
+    /// the device. The register stays enabled for caching.
+    pub fn invalidate(&self, reg: u8) {
+        self.device
+            .i2c_register_cache
+            .lock()
+            .unwrap()
+            .invalidate((self.address, reg));
+    }
+
+    /// Reads consecutive registers starting at `reg` into `buffer` (max 32 bytes).
+    ///
+    /// Use [`Xr2280x::i2c_read_registers`] for longer blocks.
+    pub fn read_regs(&self, reg: u8, buffer: &mut [u8]) -> Result<()> {
+        self.write_read(&[reg], buffer)
+    }
+
+    /// Checks whether the device acknowledges its address (see [`Xr2280x::i2c_ping_7bit`]).
+    pub fn ping(&self) -> Result<()> {
+        self.transfer(&[], None, self.device.default_timeouts().probe)
+    }
+
+    /// Reads two bytes starting at the 8-bit register `reg`, in bus order.
+    fn read_reg16(&self, reg: u8) -> Result<[u8; 2]> {
+        let mut value = [0u8; 2];
+        self.write_read(&[reg], &mut value)?;
+        Ok(value)
+    }
+
+    /// Read-modify-writes the register at `pointer`, skipping the write if nothing
+    /// changes. Returns whether the register was written.
+    fn update_reg(&self, pointer: &[u8], mask: u8, value: u8) -> Result<bool> {
+        let mut current = [0u8];
+        self.write_read(pointer, &mut current)?;
+        let updated = updated_bits(current[0], mask, value);
+        if updated == current[0] {
+            return Ok(false);
+        }
+        self.write(&[pointer, &[updated]].concat())?;
+        Ok(true)
+    }
+
+    /// Performs one transfer with START and STOP.
+    fn transfer(
+        &self,
+        write_data: &[u8],
+        read_buffer: Option<&mut [u8]>,
+        timeout_ms: i32,
+    ) -> Result<()> {
+        self.device
+            .i2c_transfer(
+                self.address,
+                write_data,
+                read_buffer,
+                START_STOP,
+                Some(timeout_ms),
+            )
+            .map(|_| ())
+    }
+}
+
+/// A cached 8-bit register of one target, keyed by address and register.
+type CacheKey = (I2cAddress, u8);
+
+/// Register values cached for [`I2cDeviceHandle::read_reg8_cached`].
+#[derive(Debug, Default)]
+pub(crate) struct I2cRegisterCache {
+    entries: HashMap<CacheKey, CachedRegister>,
+}
+
+#[derive(Debug)]
+struct CachedRegister {
+    ttl: Duration,
+    /// The last value read and when the read started.
+    value: Option<(u8, Instant)>,
+}
+
+impl I2cRegisterCache {
+    fn set_ttl(&mut self, key: CacheKey, ttl: Duration) {
+        self.entries
+            .insert(key, CachedRegister { ttl, value: None });
+    }
+
+    /// The cached value of `key`, if it is younger than its TTL at `now`.
+    fn get(&self, key: CacheKey, now: Instant) -> Option<u8> {
+        let entry = self.entries.get(&key)?;
+        let (value, read_at) = entry.value?;
+        (now.saturating_duration_since(read_at) < entry.ttl).then_some(value)
+    }
+
+    /// Records a value read at `read_at`; ignored unless `key` is enabled for caching.
+    fn store(&mut self, key: CacheKey, value: u8, read_at: Instant) {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.value = Some((value, read_at));
+        }
+    }
+
+    fn invalidate(&mut self, key: CacheKey) {
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.value = None;
+        }
+    }
+}
+
+/// Exchanges raw reports with the I2C interface: one I2C_SLAVE_OUT report per
+/// transfer, answered by one I2C_SLAVE_IN report.
+///
+/// Implemented by [`HidDevice`] for real hardware. Unit tests replace it with
+/// `MockI2cTransport` through `Xr2280x::with_i2c_transport`.
+pub(crate) trait I2cTransport: fmt::Debug + Send {
+    /// Sends an OUT report, returning the number of bytes written.
+    fn write_report(&self, report: &[u8]) -> Result<usize>;
+
+    /// Reads an IN report into `buffer`, returning its length or 0 if none arrived
+    /// within `timeout_ms`.
+    fn read_report(&self, buffer: &mut [u8], timeout_ms: i32) -> Result<usize>;
+}
+
+impl I2cTransport for HidDevice {
+    fn write_report(&self, report: &[u8]) -> Result<usize> {
+        self.write(report).map_err(hid_error)
+    }
+
+    fn read_report(&self, buffer: &mut [u8], timeout_ms: i32) -> Result<usize> {
+        self.read_timeout(buffer, timeout_ms).map_err(hid_error)
+    }
+}
+
 impl Xr2280x {
     // --- I2C Methods ---
     //
@@ -305,33 +787,143 @@ impl Xr2280x {
     // - Protocol errors (malformed responses)
 
     /// Sets the I2C bus speed (approximated). Max supported is 400 kHz.
+    ///
+    /// Equivalent to [`Self::i2c_set_speed_hz`] with `speed_khz * 1000`, including the
+    /// read-back of both timing registers.
     pub fn i2c_set_speed_khz(&self, speed_khz: u32) -> Result<()> {
-        if speed_khz == 0 || speed_khz > 400 {
+        self.i2c_set_speed_hz(speed_khz_to_hz(speed_khz)?)
+    }
+
+    /// Like [`Self::i2c_set_speed_khz`], but writes the timing registers without
+    /// reading them back.
+    pub fn i2c_set_speed_khz_fast(&self, speed_khz: u32) -> Result<()> {
+        self.i2c_set_speed_hz_fast(speed_khz_to_hz(speed_khz)?)
+    }
+
+    /// Returns the supported I2C bus speed range as `(min_hz, max_hz)`.
+    ///
+    /// All XR2280x parts implement Standard and Fast mode only, so the maximum is
+    /// 400 kHz; Fast-mode Plus (1 MHz) is not supported by any model. The minimum is
+    /// the slowest speed whose SCL timing fits the 16-bit timing registers.
+    pub fn i2c_speed_limits(&self) -> (u32, u32) {
+        (consts::i2c::MIN_SPEED_HZ, consts::i2c::MAX_SPEED_HZ)
+    }
+
+    /// Sets the I2C bus speed in Hz (approximated). Supported range is 458 Hz to 400 kHz
+    /// (see [`Self::i2c_speed_limits`]).
+    ///
+    /// The SCL low/high times are computed from the exact frequency, so speeds below
+    /// 1 kHz (e.g. for long cables) and between whole kHz steps can be used. The lower
+    /// bound is where each half of the SCL period still fits the 16-bit timing registers.
+    ///
+    /// Both timing registers are read back after writing and rewritten up to two more
+    /// times on a mismatch; if they still don't match,
+    /// [`Error::RegisterVerificationFailed`] is returned and the bus may be left at an
+    /// unexpected speed. Use [`Self::i2c_set_speed_hz_fast`] to skip the read-back.
+    ///
+    /// **Performance**: Uses 4-12 HID transactions (each register write is verified).
+    pub fn i2c_set_speed_hz(&self, speed_hz: u32) -> Result<()> {
+        let (low, high) = scl_timing(speed_hz)?;
+        debug!("Setting I2C speed ~{speed_hz}Hz: SCL_LOW=0x{low:04X}, SCL_HIGH=0x{high:04X}");
+        self.write_scl_timing((low, high), true)
+    }
+
+    /// Like [`Self::i2c_set_speed_hz`], but writes the timing registers without
+    /// reading them back.
+    ///
+    /// **Performance**: Uses 2 HID transactions.
+    pub fn i2c_set_speed_hz_fast(&self, speed_hz: u32) -> Result<()> {
+        let (low, high) = scl_timing(speed_hz)?;
+        debug!("Setting I2C speed ~{speed_hz}Hz without verification");
+        self.write_scl_timing((low, high), false)
+    }
+
+    /// Writes the SCL low and high times, reading each back if `verify`.
+    fn write_scl_timing(&self, (low, high): (u16, u16), verify: bool) -> Result<()> {
+        for (register, value) in [
+            (consts::i2c::REG_SCL_LOW, low),
+            (consts::i2c::REG_SCL_HIGH, high),
+        ] {
+            if verify {
+                self.write_hid_register_verified(register, value, 0xFFFF)?;
+            } else {
+                self.write_hid_register(register, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Scales every default I2C timeout of this handle by `scale`, e.g. `2.0` behind
+    /// slow USB hubs.
+    ///
+    /// Applies to all methods that don't take a timeout, including
+    /// [`I2cDeviceHandle`] calls, default scans and `None` passed to
+    /// [`Self::i2c_transfer_raw`]. Explicit timeouts of the `_with_timeout` variants
+    /// and [`ScanConfig`] are used as given. Scaled timeouts are rounded to whole
+    /// milliseconds and at least 1 ms. The default scale is `1.0`.
+    pub fn i2c_set_timeout_scale(&self, scale: f64) -> Result<()> {
+        if !(scale.is_finite() && scale > 0.0) {
             return Err(Error::ArgumentOutOfRange(format!(
-                "I2C speed {speed_khz} kHz out of range (1-400)"
+                "I2C timeout scale must be positive (got {scale})"
             )));
         }
-        let target_total_cycles = 60_000 / speed_khz;
-        let low_cycles = target_total_cycles / 2;
-        let high_cycles = target_total_cycles - low_cycles;
-        let (min_low, min_high) = if speed_khz <= 100 {
-            (252, 240)
-        } else {
-            (78, 36)
-        };
-        let final_low = low_cycles.max(min_low);
-        let final_high = high_cycles.max(min_high);
-        debug!(
-            "Setting I2C speed ~{speed_khz}kHz: SCL_LOW=0x{final_low:04X}, SCL_HIGH=0x{final_high:04X}"
-        );
-        self.write_hid_register(consts::i2c::REG_SCL_LOW, final_low as u16)?;
-        self.write_hid_register(consts::i2c::REG_SCL_HIGH, final_high as u16)?;
+        debug!("I2C default timeout scale set to {scale}");
+        *self.i2c_timeout_scale.lock().unwrap() = scale;
+        Ok(())
+    }
+
+    /// Returns the scale applied to default I2C timeouts (see [`Self::i2c_set_timeout_scale`]).
+    pub fn i2c_timeout_scale(&self) -> f64 {
+        *self.i2c_timeout_scale.lock().unwrap()
+    }
+
+    /// Returns the default I2C timeouts of this handle, before scaling.
+    pub fn timeouts(&self) -> Timeouts {
+        *self.i2c_timeouts.lock().unwrap()
+    }
+
+    /// Gives mutable access to the default I2C timeouts of this handle.
+    ///
+    /// Changes apply to every later call that doesn't take a timeout, including
+    /// [`I2cDeviceHandle`] calls. Values aren't checked here, so keep them positive;
+    /// [`Self::set_timeouts`] validates them and works through a shared reference.
+    pub fn timeouts_mut(&mut self) -> &mut Timeouts {
+        self.i2c_timeouts.get_mut().unwrap()
+    }
+
+    /// Replaces the default I2C timeouts of this handle (see [`Self::timeouts_mut`]).
+    ///
+    /// Every timeout must be positive.
+    pub fn set_timeouts(&self, timeouts: Timeouts) -> Result<()> {
+        let Timeouts {
+            probe,
+            scan,
+            read,
+            write,
+            write_read,
+            eeprom_write,
+        } = timeouts;
+        if [probe, scan, read, write, write_read, eeprom_write]
+            .iter()
+            .any(|&ms| ms <= 0)
+        {
+            return Err(Error::ArgumentOutOfRange(format!(
+                "I2C timeouts must be positive (got {timeouts:?})"
+            )));
+        }
+        debug!("I2C default timeouts set to {timeouts:?}");
+        *self.i2c_timeouts.lock().unwrap() = timeouts;
         Ok(())
     }
 
+    /// This handle's default timeouts with its scale applied.
+    fn default_timeouts(&self) -> Timeouts {
+        self.timeouts().scaled(self.i2c_timeout_scale())
+    }
+
     /// Performs a 7-bit I2C write operation with default timeout.
     ///
-    /// Uses a [`timeouts::WRITE`] (200ms) timeout, suitable for most device register writes.
+    /// Uses the [`Timeouts::write`] timeout (200ms by default), suitable for most device register writes.
     /// For EEPROM operations, consider using [`Self::i2c_eeprom_write_7bit`] instead.
     ///
     /// # Arguments
@@ -357,11 +949,14 @@ impl Xr2280x {
             Some(data),
             None,
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::WRITE),
+            Some(self.default_timeouts().write),
         )
     }
 
     /// Performs a 10-bit I2C write operation with default timeout.
+    ///
+    /// At most 31 data bytes can be written, because the low address byte
+    /// occupies the first byte of the report's data area.
     pub fn i2c_write_10bit(&self, slave_addr: u16, data: &[u8]) -> Result<()> {
         let addr = I2cAddress::new_10bit(slave_addr)?;
         self.i2c_transfer_raw(
@@ -369,13 +964,13 @@ impl Xr2280x {
             Some(data),
             None,
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::WRITE),
+            Some(self.default_timeouts().write),
         )
     }
 
     /// Performs a 7-bit I2C read operation with default timeout.
     ///
-    /// Uses a [`timeouts::READ`] (100ms) timeout, optimized for sensor readings and register access.
+    /// Uses the [`Timeouts::read`] timeout (100ms by default), optimized for sensor readings and register access.
     ///
     /// # Arguments
     /// * `slave_addr` - 7-bit I2C address (0x00-0x7F)
@@ -402,11 +997,33 @@ impl Xr2280x {
             None,
             Some(buffer),
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::READ),
+            Some(self.default_timeouts().read),
+        )
+    }
+
+    /// Performs a 7-bit I2C read and returns the number of bytes the device actually returned.
+    ///
+    /// Unlike [`i2c_read_7bit`](Self::i2c_read_7bit), a short read is reported to the
+    /// caller instead of only being logged, which is useful for variable-length protocols.
+    /// The count is clamped to the buffer length; bytes beyond it are left untouched.
+    pub fn i2c_read_7bit_count(&self, slave_addr: u8, buffer: &mut [u8]) -> Result<usize> {
+        let addr = I2cAddress::new_7bit(slave_addr)?;
+        self.i2c_transfer(
+            addr,
+            &[],
+            Some(buffer),
+            flags::i2c::START_BIT | flags::i2c::STOP_BIT,
+            Some(self.default_timeouts().read),
         )
     }
 
     /// Performs a 10-bit I2C read operation with default timeout.
+    ///
+    /// The request carries the full 10-bit address: the `11110xx0` prefix byte in the
+    /// address field and the low address byte as a one-byte write phase. The firmware
+    /// is expected to follow that with a repeated START and the `11110xx1` read prefix,
+    /// as the I2C specification requires for 10-bit reads. The datasheet does not spell
+    /// this out; the hardware test `test_i2c_read_10bit_repeated_start` checks it.
     pub fn i2c_read_10bit(&self, slave_addr: u16, buffer: &mut [u8]) -> Result<()> {
         let addr = I2cAddress::new_10bit(slave_addr)?;
         self.i2c_transfer_raw(
@@ -414,7 +1031,7 @@ impl Xr2280x {
             None,
             Some(buffer),
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::READ),
+            Some(self.default_timeouts().read),
         )
     }
 
@@ -431,7 +1048,26 @@ impl Xr2280x {
             Some(write_data),
             Some(read_buffer),
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::WRITE_READ),
+            Some(self.default_timeouts().write_read),
+        )
+    }
+
+    /// Performs a 7-bit I2C write-then-read and returns the number of bytes actually read.
+    ///
+    /// See [`i2c_read_7bit_count`](Self::i2c_read_7bit_count) for how the count is determined.
+    pub fn i2c_write_read_7bit_count(
+        &self,
+        slave_addr: u8,
+        write_data: &[u8],
+        read_buffer: &mut [u8],
+    ) -> Result<usize> {
+        let addr = I2cAddress::new_7bit(slave_addr)?;
+        self.i2c_transfer(
+            addr,
+            write_data,
+            Some(read_buffer),
+            flags::i2c::START_BIT | flags::i2c::STOP_BIT,
+            Some(self.default_timeouts().write_read),
         )
     }
 
@@ -448,7 +1084,7 @@ impl Xr2280x {
             Some(write_data),
             Some(read_buffer),
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::WRITE_READ),
+            Some(self.default_timeouts().write_read),
         )
     }
 
@@ -543,6 +1179,25 @@ impl Xr2280x {
         )
     }
 
+    /// Performs a 7-bit I2C write-then-read operation with separate budgets for the
+    /// write and the read phase.
+    ///
+    /// The firmware runs both phases as one transaction and answers only after the read,
+    /// so the host waits for a single response. The HID timeout is therefore the sum of
+    /// both budgets (saturating at `i32::MAX`); the split only documents where the time
+    /// is expected to go. Negative budgets are rejected.
+    pub fn i2c_write_read_7bit_split_timeout(
+        &self,
+        slave_addr: u8,
+        write_data: &[u8],
+        read_buffer: &mut [u8],
+        write_timeout_ms: i32,
+        read_timeout_ms: i32,
+    ) -> Result<()> {
+        let timeout_ms = combined_timeout(write_timeout_ms, read_timeout_ms)?;
+        self.i2c_write_read_7bit_with_timeout(slave_addr, write_data, read_buffer, timeout_ms)
+    }
+
     /// Performs a 10-bit I2C write-then-read operation with custom timeout.
     /// Use this for slow devices or when you need faster response times.
     pub fn i2c_write_read_10bit_with_timeout(
@@ -562,6 +1217,88 @@ impl Xr2280x {
         )
     }
 
+    /// Writes then reads a 7-bit device without releasing the bus in between.
+    ///
+    /// Some devices (e.g. certain ADCs) abort a conversion if they see a STOP after the
+    /// command write. This issues two HID transfers that form one I2C transaction:
+    ///
+    /// | Phase | Data | Flags |
+    /// |-------|------|-------|
+    /// | write | `write_data` | `START_BIT` (`0x01`), no STOP: SCL is held low afterwards |
+    /// | read  | `read_buffer` | `STOP_BIT` (`0x02`) only, ending the transaction |
+    ///
+    /// Both phases are limited to 32 bytes. If the write fails the read is not issued;
+    /// the bus may then stay held until the next transfer with a STOP.
+    pub fn i2c_write_then_read_no_stop(
+        &self,
+        slave_addr: u8,
+        write_data: &[u8],
+        read_buffer: &mut [u8],
+    ) -> Result<()> {
+        let addr = I2cAddress::new_7bit(slave_addr)?;
+        debug!("I2C write-then-read without STOP to {addr}");
+        let timeouts = self.default_timeouts();
+        self.i2c_transfer(
+            addr,
+            write_data,
+            Some(&mut []),
+            flags::i2c::START_BIT,
+            Some(timeouts.write),
+        )?;
+        self.i2c_transfer(
+            addr,
+            &[],
+            Some(read_buffer),
+            flags::i2c::STOP_BIT,
+            Some(timeouts.read),
+        )
+        .map(|_| ())
+    }
+
+    /// Reads a length-prefixed response, e.g. an SMBus block read: writes `command`,
+    /// then after a repeated START reads a count byte followed by that many data bytes.
+    ///
+    /// The count byte is read with an ACK and the data is read in the same read phase
+    /// by a second HID transfer ending with STOP, so the device sees a single block
+    /// read. Returns the data bytes without the count.
+    ///
+    /// A count of 0 returns an empty vector and a count above 32 fails with
+    /// [`Error::OperationTooLarge`]; in both cases one byte is read and discarded to
+    /// end the transaction with a NACK and STOP.
+    ///
+    /// **Performance**: Uses 2 HID transfers.
+    pub fn i2c_read_length_prefixed(&self, slave_addr: u8, command: u8) -> Result<Vec<u8>> {
+        let addr = I2cAddress::new_7bit(slave_addr)?;
+        debug!("Length-prefixed read from {addr} with command 0x{command:02X}");
+        use consts::i2c::out_flags::{ACK_LAST_READ, START_BIT, STOP_BIT};
+        let timeouts = self.default_timeouts();
+        let mut count = [0u8];
+        self.i2c_transfer(
+            addr,
+            &[command],
+            Some(&mut count),
+            START_BIT | ACK_LAST_READ,
+            Some(timeouts.write_read),
+        )?;
+        let count = usize::from(count[0]);
+        // Out-of-range counts still end the read with a discarded byte, NACK and STOP
+        let read_len = match count {
+            1..=consts::i2c::REPORT_MAX_DATA_SIZE => count,
+            _ => 1,
+        };
+        let mut data = vec![0u8; read_len];
+        self.i2c_transfer(addr, &[], Some(&mut data), STOP_BIT, Some(timeouts.read))?;
+        match count {
+            0 => Ok(Vec::new()),
+            1..=consts::i2c::REPORT_MAX_DATA_SIZE => Ok(data),
+            _ => Err(Error::OperationTooLarge {
+                operation: "I2C length-prefixed read".to_string(),
+                max: consts::i2c::REPORT_MAX_DATA_SIZE,
+                actual: count,
+            }),
+        }
+    }
+
     /// Performs a 7-bit I2C EEPROM write operation with extended timeout.
     /// EEPROMs can take several seconds to complete internal write cycles.
     /// This method uses a 5-second default timeout suitable for most EEPROMs.
@@ -572,7 +1309,7 @@ impl Xr2280x {
             Some(data),
             None,
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::EEPROM_WRITE),
+            Some(self.default_timeouts().eeprom_write),
         )
     }
 
@@ -586,7 +1323,7 @@ impl Xr2280x {
             Some(data),
             None,
             flags::i2c::START_BIT | flags::i2c::STOP_BIT,
-            Some(timeouts::EEPROM_WRITE),
+            Some(self.default_timeouts().eeprom_write),
         )
     }
 
@@ -626,6 +1363,143 @@ impl Xr2280x {
         )
     }
 
+    /// Reads a block of consecutive registers from a 7-bit device with an 8-bit register pointer.
+    ///
+    /// Writes `start_reg` once, then reads `count` bytes after a repeated start, relying on
+    /// the device auto-incrementing its register pointer. Blocks longer than the 32-byte
+    /// HID report limit are read in chunks within the same I2C transaction: intermediate
+    /// chunks ACK their last byte and omit STOP so the device keeps sending.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// // Read accelerometer, temperature and gyro output block (14 bytes from 0x3B)
+    /// let block = device.i2c_read_registers(0x68, 0x3B, 14)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_read_registers(
+        &self,
+        slave_addr: u8,
+        start_reg: u8,
+        count: usize,
+    ) -> Result<Vec<u8>> {
+        self.i2c_read_register_block(slave_addr, &[start_reg], count)
+    }
+
+    /// Reads a block of consecutive registers from a 7-bit device with a 16-bit register pointer.
+    ///
+    /// The register pointer is sent big-endian (high byte first). See
+    /// [`i2c_read_registers`](Self::i2c_read_registers) for the chunking behavior.
+    pub fn i2c_read_registers_16bit(
+        &self,
+        slave_addr: u8,
+        start_reg: u16,
+        count: usize,
+    ) -> Result<Vec<u8>> {
+        self.i2c_read_register_block(slave_addr, &start_reg.to_be_bytes(), count)
+    }
+
+    fn i2c_read_register_block(
+        &self,
+        slave_addr: u8,
+        pointer: &[u8],
+        count: usize,
+    ) -> Result<Vec<u8>> {
+        let addr = I2cAddress::new_7bit(slave_addr)?;
+        debug!("Reading {count} registers from {addr} starting at {pointer:02X?}");
+        let mut data = Vec::with_capacity(count);
+        self.i2c_read_chunks(addr, pointer, count, |chunk| data.extend_from_slice(chunk))?;
+        Ok(data)
+    }
+
+    /// Reads `count` bytes after writing `pointer`, split into report-sized chunks that
+    /// are handed to `handler` as soon as they are read. The pointer is written only
+    /// with the first chunk; later chunks continue the same I2C transaction. An empty
+    /// `pointer` makes it a plain read.
+    ///
+    /// This assumes that when a report ACKs its last read byte and sends no STOP, the
+    /// firmware continues that read on the next report without START: no START condition
+    /// or address byte goes out, and the target keeps sending from its auto-incremented
+    /// pointer. The datasheet does not spell this out; the hardware test
+    /// `test_i2c_read_registers_across_reports` checks it.
+    fn i2c_read_chunks<H>(
+        &self,
+        addr: I2cAddress,
+        pointer: &[u8],
+        count: usize,
+        mut handler: H,
+    ) -> Result<()>
+    where
+        H: FnMut(&[u8]),
+    {
+        let timeouts = self.default_timeouts();
+        let mut buffer = [0u8; consts::i2c::REPORT_MAX_DATA_SIZE];
+        let chunk_count = count.div_ceil(consts::i2c::REPORT_MAX_DATA_SIZE);
+        for index in 0..chunk_count {
+            let first = index == 0;
+            let last = index + 1 == chunk_count;
+            let offset = index * consts::i2c::REPORT_MAX_DATA_SIZE;
+            let len = (count - offset).min(consts::i2c::REPORT_MAX_DATA_SIZE);
+            let mut flags = 0;
+            if first {
+                flags |= consts::i2c::out_flags::START_BIT;
+            }
+            if last {
+                flags |= consts::i2c::out_flags::STOP_BIT;
+            } else {
+                flags |= consts::i2c::out_flags::ACK_LAST_READ;
+            }
+            let (write_data, timeout) = match first && !pointer.is_empty() {
+                true => (pointer, timeouts.write_read),
+                false => (&[][..], timeouts.read),
+            };
+            let chunk = &mut buffer[..len];
+            self.i2c_transfer(addr, write_data, Some(chunk), flags, Some(timeout))?;
+            handler(chunk);
+        }
+        Ok(())
+    }
+
+    /// Reads `total_len` bytes from a 7-bit device as one I2C read, handing them to
+    /// `chunk_handler` in chunks of up to 32 bytes (one HID report) as they arrive.
+    ///
+    /// The device is addressed only once: every chunk but the last acknowledges its
+    /// final byte so the device keeps sending, and only the last chunk ends with STOP.
+    /// The data is never collected into one buffer, so this suits draining large
+    /// FIFOs. If a chunk fails, its error is returned and the handler has seen only
+    /// the chunks before it.
+    ///
+    /// **Performance**: Uses 1 HID transaction per 32 bytes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # fn example(device: &Xr2280x) -> Result<()> {
+    /// let mut samples = 0;
+    /// device.i2c_read_7bit_stream(0x68, 1024, |chunk| samples += chunk.len() / 6)?;
+    /// println!("Read {samples} samples");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_read_7bit_stream<F>(
+        &self,
+        slave_addr: u8,
+        total_len: usize,
+        chunk_handler: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&[u8]),
+    {
+        let addr = I2cAddress::new_7bit(slave_addr)?;
+        debug!("Streaming {total_len} bytes from {addr}");
+        self.i2c_read_chunks(addr, &[], total_len, chunk_handler)
+    }
+
     /// Low-level I2C transfer with full control over flags and timeout.
     /// Can perform writes, reads, or write-then-read operations.
     /// See [`crate::flags::i2c`] for available flag constants.
@@ -644,6 +1518,210 @@ impl Xr2280x {
             flags,
             timeout_ms,
         )
+        .map(|_| ())
+    }
+
+    /// Sets the bits selected by `mask` in the 8-bit register `reg` of a 7-bit device to
+    /// those of `value`, leaving the other bits unchanged.
+    ///
+    /// Reads the register, computes `(current & !mask) | (value & mask)` and writes the
+    /// result back only if it differs. Returns whether a write took place. The read and
+    /// the write are separate I2C transactions.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// // Set the shutdown bit (bit 0) of a TMP102 configuration register
+    /// device.i2c_update_reg8(0x48, 0x01, 0x01, 0x01)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_update_reg8(&self, slave_addr: u8, reg: u8, mask: u8, value: u8) -> Result<bool> {
+        self.i2c_device_7bit(slave_addr)?
+            .update_reg8(reg, mask, value)
+    }
+
+    /// Like [`Self::i2c_update_reg8`] for devices with a 16-bit register pointer, which
+    /// is sent big-endian (high byte first).
+    pub fn i2c_update_reg8_16bit(
+        &self,
+        slave_addr: u8,
+        reg: u16,
+        mask: u8,
+        value: u8,
+    ) -> Result<bool> {
+        self.i2c_device_7bit(slave_addr)?
+            .update_reg(&reg.to_be_bytes(), mask, value)
+    }
+
+    /// Reads a 16-bit big-endian (high byte first) value from the 8-bit register `reg`
+    /// of a 7-bit device.
+    ///
+    /// Writes the register pointer, then reads two bytes in one transaction. This is
+    /// the layout used by most sensors, e.g. the TMP102 temperature register.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// // TMP102 temperature: 12-bit left-justified, 0.0625 °C per LSB
+    /// let raw = device.i2c_read_reg_be_i16(0x48, 0x00)?;
+    /// println!("{:.2} °C", f32::from(raw >> 4) * 0.0625);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_read_reg_be16(&self, slave_addr: u8, reg: u8) -> Result<u16> {
+        self.i2c_read_reg16(slave_addr, reg).map(u16::from_be_bytes)
+    }
+
+    /// Reads a 16-bit little-endian (low byte first) value from the 8-bit register
+    /// `reg` of a 7-bit device, as [`Self::i2c_read_reg_be16`] does.
+    pub fn i2c_read_reg_le16(&self, slave_addr: u8, reg: u8) -> Result<u16> {
+        self.i2c_read_reg16(slave_addr, reg).map(u16::from_le_bytes)
+    }
+
+    /// Like [`Self::i2c_read_reg_be16`], interpreting the value as two's complement.
+    pub fn i2c_read_reg_be_i16(&self, slave_addr: u8, reg: u8) -> Result<i16> {
+        self.i2c_read_reg16(slave_addr, reg).map(i16::from_be_bytes)
+    }
+
+    /// Like [`Self::i2c_read_reg_le16`], interpreting the value as two's complement.
+    pub fn i2c_read_reg_le_i16(&self, slave_addr: u8, reg: u8) -> Result<i16> {
+        self.i2c_read_reg16(slave_addr, reg).map(i16::from_le_bytes)
+    }
+
+    fn i2c_read_reg16(&self, slave_addr: u8, reg: u8) -> Result<[u8; 2]> {
+        self.i2c_device_7bit(slave_addr)?.read_reg16(reg)
+    }
+
+    /// Writes a 16-bit value big-endian (high byte first) to the 8-bit register `reg`
+    /// of a 7-bit device.
+    ///
+    /// Sends `[reg, high, low]` in a single transaction. The device auto-increments its
+    /// register pointer, so the cached values of `reg` and `reg + 1` are cleared (see
+    /// [`I2cDeviceHandle::cache_reg8`]).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// // TMP102 configuration: continuous conversion at 4 Hz
+    /// device.i2c_write_reg_be16(0x48, 0x01, 0x60A0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_write_reg_be16(&self, slave_addr: u8, reg: u8, value: u16) -> Result<()> {
+        self.i2c_write_reg_bytes(slave_addr, reg, &value.to_be_bytes())
+    }
+
+    /// Writes a 16-bit value little-endian (low byte first) to the 8-bit register
+    /// `reg` of a 7-bit device, as [`Self::i2c_write_reg_be16`] does.
+    pub fn i2c_write_reg_le16(&self, slave_addr: u8, reg: u8, value: u16) -> Result<()> {
+        self.i2c_write_reg_bytes(slave_addr, reg, &value.to_le_bytes())
+    }
+
+    /// Writes a 32-bit value big-endian (most significant byte first) to the 8-bit
+    /// register `reg` of a 7-bit device, as [`Self::i2c_write_reg_be16`] does, clearing
+    /// the cached values of `reg` through `reg + 3`.
+    pub fn i2c_write_reg_be32(&self, slave_addr: u8, reg: u8, value: u32) -> Result<()> {
+        self.i2c_write_reg_bytes(slave_addr, reg, &value.to_be_bytes())
+    }
+
+    /// Writes a 32-bit value little-endian (least significant byte first) to the 8-bit
+    /// register `reg` of a 7-bit device, as [`Self::i2c_write_reg_be32`] does.
+    pub fn i2c_write_reg_le32(&self, slave_addr: u8, reg: u8, value: u32) -> Result<()> {
+        self.i2c_write_reg_bytes(slave_addr, reg, &value.to_le_bytes())
+    }
+
+    fn i2c_write_reg_bytes(&self, slave_addr: u8, reg: u8, bytes: &[u8]) -> Result<()> {
+        let device = self.i2c_device_7bit(slave_addr)?;
+        for offset in 0..bytes.len() {
+            device.invalidate(reg.wrapping_add(offset as u8));
+        }
+        device.write(&[&[reg], bytes].concat())
+    }
+
+    /// Returns a handle bound to the 7-bit address `slave_addr`.
+    ///
+    /// See [`I2cDeviceHandle`]. Fails if the address is out of range.
+    pub fn i2c_device_7bit(&self, slave_addr: u8) -> Result<I2cDeviceHandle<'_>> {
+        Ok(I2cDeviceHandle {
+            device: self,
+            address: I2cAddress::new_7bit(slave_addr)?,
+        })
+    }
+
+    /// Returns a handle bound to the 10-bit address `slave_addr`.
+    ///
+    /// See [`I2cDeviceHandle`]. Fails if the address is out of range.
+    pub fn i2c_device_10bit(&self, slave_addr: u16) -> Result<I2cDeviceHandle<'_>> {
+        Ok(I2cDeviceHandle {
+            device: self,
+            address: I2cAddress::new_10bit(slave_addr)?,
+        })
+    }
+
+    /// Low-level I2C transfer that returns the raw firmware status byte instead of
+    /// converting status flags to errors.
+    ///
+    /// Takes the same arguments as [`Xr2280x::i2c_transfer_raw`]. Only hard failures
+    /// are errors: invalid arguments, HID transport errors, a missing response report
+    /// ([`Error::I2cTimeout`]) or a malformed one. NAKs, lost arbitration and other
+    /// flag combinations are left for the caller to inspect in
+    /// [`I2cTransferStatus::status_flags`]; read data is copied whenever the report
+    /// carries it.
+    pub fn i2c_transfer_raw_status(
+        &self,
+        slave_addr: I2cAddress,
+        write_data: Option<&[u8]>,
+        read_buffer: Option<&mut [u8]>,
+        flags: u8,
+        timeout_ms: Option<i32>,
+    ) -> Result<I2cTransferStatus> {
+        self.i2c_exchange(
+            slave_addr,
+            write_data.unwrap_or(&[]),
+            read_buffer,
+            flags,
+            timeout_ms,
+        )
+    }
+
+    /// Checks whether a device acknowledges a 7-bit address, without transferring data.
+    ///
+    /// Sends START, the address and STOP (a zero-length write), the same probe the bus
+    /// scan uses. Returns `Ok(())` if the device acknowledged and [`Error::I2cNack`] if
+    /// no device is present.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// match device.i2c_ping_7bit(0x50, timeouts::PROBE) {
+    ///     Ok(()) => println!("EEPROM present"),
+    ///     Err(Error::I2cNack { .. }) => println!("EEPROM absent"),
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_ping_7bit(&self, slave_addr: u8, timeout_ms: i32) -> Result<()> {
+        let address = I2cAddress::new_7bit(slave_addr)?;
+        self.i2c_transfer(address, &[], None, PING_FLAGS, Some(timeout_ms))
+            .map(|_| ())
     }
 
     /// Fast I2C bus scan for device discovery.
@@ -652,7 +1730,10 @@ impl Xr2280x {
     ///
     /// # Arguments
     /// * `start_addr` - First 7-bit address to scan (typically 0x08)
-    /// * `end_addr` - Last 7-bit address to scan (typically 0x77)
+    /// * `end_addr` - Last 7-bit address to scan (typically 0x77), inclusive
+    ///
+    /// Returns [`Error::ArgumentOutOfRange`] if `start_addr > end_addr` or `end_addr`
+    /// is above 0x7F. All scan functions check the range this way before any I/O.
     ///
     /// # Example
     /// ```no_run
@@ -672,6 +1753,61 @@ impl Xr2280x {
         self.i2c_scan_with_progress(start_addr, end_addr, |_, _, _, _| {})
     }
 
+    /// Like [`Self::i2c_scan`], but returns the found devices as [`I2cAddress`] values
+    /// ready to pass to the address-based API, e.g. [`Self::i2c_device_7bit`].
+    pub fn i2c_scan_typed(&self, start_addr: u8, end_addr: u8) -> Result<Vec<I2cAddress>> {
+        self.i2c_scan(start_addr, end_addr)?
+            .into_iter()
+            .map(I2cAddress::new_7bit)
+            .collect()
+    }
+
+    /// Scans like [`Self::i2c_scan`], then reads the 8-bit register `id_reg` (e.g. a
+    /// WHO_AM_I register) of every device found, for an inventory of the bus.
+    ///
+    /// Each entry holds the address and the register value, or `None` if reading it
+    /// failed, e.g. because the device has no such register. The reads use the short
+    /// [`Timeouts::scan`] timeout (scaled by [`Self::i2c_set_timeout_scale`]), so a
+    /// silent device doesn't hold up the inventory.
+    ///
+    /// **Performance**: Uses 1 HID transaction per scanned address plus 1 per device
+    /// found.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # fn example(device: &Xr2280x) -> Result<()> {
+    /// for (addr, id) in device.i2c_scan_and_identify(0x08, 0x77, 0x0F)? {
+    ///     match id {
+    ///         Some(id) => println!("0x{addr:02X}: WHO_AM_I = 0x{id:02X}"),
+    ///         None => println!("0x{addr:02X}: no ID register"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_scan_and_identify(
+        &self,
+        start_addr: u8,
+        end_addr: u8,
+        id_reg: u8,
+    ) -> Result<Vec<(u8, Option<u8>)>> {
+        let found = self.i2c_scan(start_addr, end_addr)?;
+        let timeout_ms = self.default_timeouts().scan;
+        Ok(found
+            .into_iter()
+            .map(|addr| {
+                let mut id = [0u8; 1];
+                let id = self
+                    .i2c_write_read_7bit_with_timeout(addr, &[id_reg], &mut id, timeout_ms)
+                    .map(|_| id[0])
+                    .inspect_err(|e| debug!("No ID from device at 0x{addr:02X}: {e}"))
+                    .ok();
+                (addr, id)
+            })
+            .collect())
+    }
+
     /// Fast I2C bus scan using the standard address range (0x08 to 0x77).
     /// This is a convenience method that scans the most commonly used I2C address space,
     /// avoiding reserved addresses at the low and high ends.
@@ -694,9 +1830,37 @@ impl Xr2280x {
         self.i2c_scan(0x08, 0x77)
     }
 
+    /// Checks the I2C bus health with a default-range scan (0x08 to 0x77).
+    ///
+    /// Unlike [`i2c_scan_default`](Self::i2c_scan_default), a stuck bus is reported as
+    /// `bus_stuck: true` instead of an error, so this gives a single "is my bus OK" answer.
+    /// Other errors (e.g. arbitration lost, disconnect) are still returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// let health = device.i2c_health_check()?;
+    /// if health.bus_stuck {
+    ///     eprintln!("I2C bus stuck - check device power and pull-ups");
+    /// } else {
+    ///     println!("{} devices in {:?}", health.device_count, health.scan_duration);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_health_check(&self) -> Result<BusHealth> {
+        let start = Instant::now();
+        let scan = self.i2c_scan_default();
+        BusHealth::from_scan(scan, start.elapsed())
+    }
+
     /// Fast I2C bus scan with progress callback for device discovery.
     ///
-    /// Scans the specified range of 7-bit addresses using optimized [`timeouts::SCAN`] (25ms) timeouts.
+    /// Scans the specified range of 7-bit addresses using the short [`Timeouts::scan`] timeout.
     /// Includes automatic stuck bus detection to prevent hanging when hardware issues occur.
     ///
     /// # Stuck Bus Detection
@@ -754,7 +1918,7 @@ impl Xr2280x {
         self.i2c_scan_with_progress_and_timeout(
             start_addr,
             end_addr,
-            timeouts::SCAN,
+            self.default_timeouts().scan,
             progress_callback,
         )
     }
@@ -773,11 +1937,13 @@ impl Xr2280x {
         start_addr: u8,
         end_addr: u8,
         scan_timeout_ms: i32,
-        mut progress_callback: F,
+        progress_callback: F,
     ) -> Result<Vec<u8>>
     where
         F: FnMut(u8, bool, usize, usize),
     {
+        scan_range_len(start_addr, end_addr)?;
+
         // Step 1: Quick firmware responsiveness test to catch stuck bus immediately
         debug!("Testing firmware responsiveness with ultra-short timeout...");
         let probe_start = Instant::now();
@@ -795,20 +1961,74 @@ impl Xr2280x {
         debug!("Responsiveness test passed in {:?}", probe_start.elapsed());
 
         // Step 2: Perform actual scan with fast failure detection
+        let config = ScanConfig {
+            timeout_ms: scan_timeout_ms,
+            ..ScanConfig::default()
+        };
+        self.i2c_scan_pass(start_addr, end_addr, &config, progress_callback)
+    }
+
+    /// I2C bus scan with a [`ScanConfig`] and progress callback.
+    ///
+    /// Works like [`Self::i2c_scan_with_progress_and_timeout`], additionally waiting
+    /// `inter_address_delay` between addresses and, if `confirm_timeout_ms` is set,
+    /// re-probing only the acknowledging addresses with that timeout to drop false
+    /// positives. The progress callback is only called during the main pass.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use hidapi::HidApi;
+    /// # fn main() -> Result<()> {
+    /// # let hid_api = HidApi::new()?;
+    /// # let device = Xr2280x::device_open_first(&hid_api)?;
+    /// let config = ScanConfig::thorough();
+    /// let found = device.i2c_scan_with_config(0x08, 0x77, &config, |_, _, _, _| {})?;
+    /// println!("Confirmed devices: {found:02X?}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn i2c_scan_with_config<F>(
+        &self,
+        start_addr: u8,
+        end_addr: u8,
+        config: &ScanConfig,
+        progress_callback: F,
+    ) -> Result<Vec<u8>>
+    where
+        F: FnMut(u8, bool, usize, usize),
+    {
+        scan_range_len(start_addr, end_addr)?;
+        self.test_firmware_responsiveness()?;
+        self.i2c_scan_pass(start_addr, end_addr, config, progress_callback)
+    }
+
+    fn i2c_scan_pass<F>(
+        &self,
+        start_addr: u8,
+        end_addr: u8,
+        config: &ScanConfig,
+        mut progress_callback: F,
+    ) -> Result<Vec<u8>>
+    where
+        F: FnMut(u8, bool, usize, usize),
+    {
+        let scan_start = Instant::now();
         let mut found_devices = Vec::new();
-        let flags = flags::i2c::START_BIT | flags::i2c::STOP_BIT;
-        let total_addresses = (end_addr - start_addr + 1) as usize;
+        let total_addresses = scan_range_len(start_addr, end_addr)?;
         let mut consecutive_timeouts = 0;
         const MAX_CONSECUTIVE_TIMEOUTS: usize = 1; // Fail immediately on stuck bus
 
-        let scan_start = Instant::now();
-
         for (idx, addr_7bit) in (start_addr..=end_addr).enumerate() {
-            let address = I2cAddress::new_7bit(addr_7bit)?;
+            if idx > 0
+                && let Some(delay) = config.inter_address_delay
+            {
+                std::thread::sleep(delay);
+            }
             let mut found = false;
 
             // Use the specified timeout, but fail fast on consecutive timeouts
-            match self.i2c_transfer_raw(address, None, None, flags, Some(scan_timeout_ms)) {
+            match self.i2c_ping_7bit(addr_7bit, config.timeout_ms) {
                 Ok(_) => {
                     found_devices.push(addr_7bit);
                     found = true;
@@ -818,7 +2038,7 @@ impl Xr2280x {
                     // Normal - no device at this address
                     consecutive_timeouts = 0;
                 }
-                Err(Error::I2cTimeout { .. }) => {
+                Err(Error::I2cTimeout { address }) => {
                     consecutive_timeouts += 1;
                     if consecutive_timeouts >= MAX_CONSECUTIVE_TIMEOUTS {
                         warn!(
@@ -852,6 +2072,19 @@ impl Xr2280x {
             progress_callback(addr_7bit, found, idx, total_addresses);
         }
 
+        if let Some(confirm_timeout_ms) = config.confirm_timeout_ms {
+            let mut confirmed = Vec::with_capacity(found_devices.len());
+            for addr_7bit in found_devices {
+                match self.i2c_ping_7bit(addr_7bit, confirm_timeout_ms) {
+                    Ok(_) => confirmed.push(addr_7bit),
+                    Err(Error::I2cNack { .. }) => {
+                        debug!("Address 0x{addr_7bit:02X} did not acknowledge again, dropping it");
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            found_devices = confirmed;
+        }
         debug!(
             "Scan completed in {:?}, found {} devices",
             scan_start.elapsed(),
@@ -864,13 +2097,20 @@ impl Xr2280x {
     /// This catches firmware hangs before they can cause 29+ second delays.
     /// Uses an ultra-short timeout to fail fast if firmware is stuck.
     fn test_firmware_responsiveness(&self) -> Result<()> {
+        // Use ultra-short timeout - if firmware is going to hang, it hangs immediately
+        self.i2c_probe_firmware(self.default_timeouts().probe)
+    }
+
+    /// Addresses the reserved I2C address 0x00 and fails with [`Error::I2cTimeout`] if
+    /// the firmware doesn't answer within `timeout_ms`. Other I2C errors (e.g. a NACK)
+    /// still prove the firmware is alive; errors outside I2C are returned as they are.
+    pub(crate) fn i2c_probe_firmware(&self, timeout_ms: i32) -> Result<()> {
         let test_address = I2cAddress::new_7bit(0x00)?; // Reserved address
         let flags = flags::i2c::START_BIT | flags::i2c::STOP_BIT;
 
-        debug!("Testing firmware responsiveness with 3ms timeout on reserved address");
+        debug!("Testing firmware responsiveness with {timeout_ms}ms timeout on reserved address");
 
-        // Use ultra-short timeout - if firmware is going to hang, it hangs immediately
-        match self.i2c_transfer_raw(test_address, None, None, flags, Some(timeouts::PROBE)) {
+        match self.i2c_transfer_raw(test_address, None, None, flags, Some(timeout_ms)) {
             Ok(_) => {
                 // Reserved address shouldn't respond, but firmware is working
                 debug!("Reserved address responded - unusual but firmware is responsive");
@@ -883,20 +2123,21 @@ impl Xr2280x {
             }
             Err(Error::I2cTimeout { .. }) => {
                 // This indicates firmware or bus is stuck - fail immediately
-                warn!("Firmware failed to respond within 3ms - bus likely stuck");
+                warn!("Firmware failed to respond within {timeout_ms}ms - bus likely stuck");
                 Err(Error::I2cTimeout {
                     address: test_address,
                 })
             }
-            Err(e) => {
-                // Other error types still indicate firmware is responsive
+            Err(e) if e.category() == ErrorCategory::I2c => {
+                // Other I2C errors still indicate firmware is responsive
                 debug!("Firmware responsive, other error: {e}");
                 Ok(())
             }
+            Err(e) => Err(e),
         }
     }
 
-    // Internal I2C transfer implementation
+    // Internal I2C transfer implementation, returns the number of bytes read
     fn i2c_transfer(
         &self,
         slave_addr: I2cAddress,
@@ -904,77 +2145,36 @@ impl Xr2280x {
         read_buffer: Option<&mut [u8]>,
         flags: u8,
         timeout_ms: Option<i32>,
-    ) -> Result<()> {
-        let timeout = timeout_ms.unwrap_or(timeouts::READ);
+    ) -> Result<usize> {
+        let read_len = read_buffer.as_ref().map_or(0, |buffer| buffer.len());
+        let status = self.i2c_exchange(slave_addr, write_data, read_buffer, flags, timeout_ms)?;
+        check_i2c_status(slave_addr, status.status_flags, write_data.len(), read_len)?;
+        Ok(status.bytes_read)
+    }
+
+    // Sends one I2C_SLAVE_OUT report and reads the response without checking its status flags
+    fn i2c_exchange(
+        &self,
+        slave_addr: I2cAddress,
+        write_data: &[u8],
+        read_buffer: Option<&mut [u8]>,
+        flags: u8,
+        timeout_ms: Option<i32>,
+    ) -> Result<I2cTransferStatus> {
+        let timeout = timeout_ms.unwrap_or_else(|| self.default_timeouts().read);
         let write_len = write_data.len();
         let read_len = read_buffer.as_ref().map(|b| b.len()).unwrap_or(0);
 
-        // Validate sizes
-        if write_len > consts::i2c::REPORT_MAX_DATA_SIZE {
-            return Err(Error::OperationTooLarge {
-                max: consts::i2c::REPORT_MAX_DATA_SIZE,
-                actual: write_len,
-            });
-        }
-        if read_len > consts::i2c::REPORT_MAX_DATA_SIZE {
-            return Err(Error::OperationTooLarge {
-                max: consts::i2c::REPORT_MAX_DATA_SIZE,
-                actual: read_len,
-            });
-        }
+        let out_buf = build_i2c_request(slave_addr, write_data, read_len, flags)?;
 
-        // Prepare OUT report buffer (no Report ID byte needed for write())
-        let mut out_buf = vec![0u8; consts::i2c::OUT_REPORT_WRITE_BUF_SIZE];
-
-        // Set flags - add 10-bit address flag if needed
-        let mut final_flags = flags;
-        if matches!(slave_addr, I2cAddress::Bit10(_)) {
-            final_flags |= consts::i2c::out_flags::TEN_BIT_ADDR;
-        }
-
-        out_buf[request_offsets::FLAGS] = final_flags;
-        out_buf[request_offsets::WRITE_LENGTH] = write_len as u8;
-        out_buf[request_offsets::READ_LENGTH] = read_len as u8;
-
-        // Set slave address based on type
-        match slave_addr {
-            // For 7-bit addresses, shift left by 1 to create the 8-bit wire format
-            // The I2C protocol requires the 7-bit address in bits 7:1, with bit 0 reserved for R/W
-            I2cAddress::Bit7(addr) => out_buf[request_offsets::SLAVE_ADDRESS] = addr << 1,
-            I2cAddress::Bit10(addr) => {
-                // For 10-bit, use special encoding per datasheet
-                // High byte in [3], low byte in first data position [4]
-                out_buf[request_offsets::SLAVE_ADDRESS] = (((addr >> 8) & 0x03) << 1) as u8 | 0xF0; // 11110xx0 pattern
-                if write_len > 0 {
-                    // If writing data, shift it and insert low addr byte
-                    out_buf[request_offsets::WRITE_DATA_10BIT_START
-                        ..request_offsets::WRITE_DATA_10BIT_START + write_len]
-                        .copy_from_slice(write_data);
-                    out_buf[request_offsets::ADDR_10BIT_LOW] = (addr & 0xFF) as u8;
-                    out_buf[request_offsets::WRITE_LENGTH] = (write_len + 1) as u8; // Increase write size
-                } else {
-                    // Read-only, low byte goes in data[0]
-                    out_buf[request_offsets::ADDR_10BIT_LOW] = (addr & 0xFF) as u8;
-                    out_buf[request_offsets::WRITE_LENGTH] = 1; // Write size = 1 for address
-                }
-            }
-        }
-
-        // Copy write data for 7-bit addresses
-        if matches!(slave_addr, I2cAddress::Bit7(_)) && write_len > 0 {
-            out_buf
-                [request_offsets::WRITE_DATA_START..request_offsets::WRITE_DATA_START + write_len]
-                .copy_from_slice(write_data);
-        }
-
-        debug!(
-            "I2C transfer to {slave_addr}: write {write_len} bytes, read {read_len} bytes, flags=0x{flags:02X}"
-        );
-        trace!("I2C OUT buffer: {:02X?}", &out_buf);
+        debug!(
+            "I2C transfer to {slave_addr}: write {write_len} bytes, read {read_len} bytes, flags=0x{flags:02X}"
+        );
+        trace!("I2C OUT buffer: {:02X?}", &out_buf);
 
         // Send the OUT report
-        let i2c_device = self.i2c_device.as_ref().ok_or(Error::DeviceNotFound)?;
-        let written = i2c_device.write(&out_buf).map_err(Error::Hid)?;
+        let transport = self.i2c_transport()?;
+        let written = transport.write_report(&out_buf)?;
 
         if written != out_buf.len() {
             warn!("Partial write: sent {} of {} bytes", written, out_buf.len());
@@ -984,9 +2184,7 @@ impl Xr2280x {
 
         // Always read the status response from device (even for write-only operations)
         let mut in_buf = vec![0u8; consts::i2c::IN_REPORT_READ_BUF_SIZE];
-        let received = i2c_device
-            .read_timeout(&mut in_buf, timeout)
-            .map_err(Error::Hid)?;
+        let received = transport.read_report(&mut in_buf, timeout)?;
 
         trace!(
             "Received {} bytes from device: {:02X?}",
@@ -994,67 +2192,1406 @@ impl Xr2280x {
             &in_buf[..received]
         );
 
-        if received == 0 {
-            // The device did not send a response report within the timeout.
-            // This typically happens when the XR2280x gets a NACK and fails to send back
-            // an I2C_SLAVE_IN report due to a firmware quirk.
-            return Err(Error::I2cTimeout {
-                address: slave_addr,
-            });
+        parse_i2c_response_raw(slave_addr, &in_buf[..received], read_buffer)
+    }
+}
+
+/// Builds the I2C_SLAVE_OUT report for a transfer.
+fn build_i2c_request(
+    slave_addr: I2cAddress,
+    write_data: &[u8],
+    read_len: usize,
+    flags: u8,
+) -> Result<Vec<u8>> {
+    let write_len = write_data.len();
+
+    // Validate sizes. The 10-bit write limit leaves room for the low address byte, so
+    // every request that passes fits the OUT report.
+    let (operation, max_write_len) = match slave_addr {
+        I2cAddress::Bit7(_) => ("I2C write", consts::i2c::REPORT_MAX_DATA_SIZE),
+        I2cAddress::Bit10(_) => ("I2C 10-bit write", consts::i2c::REPORT_MAX_10BIT_WRITE_SIZE),
+    };
+    if write_len > max_write_len {
+        return Err(Error::OperationTooLarge {
+            operation: operation.to_string(),
+            max: max_write_len,
+            actual: write_len,
+        });
+    }
+    if read_len > consts::i2c::REPORT_MAX_DATA_SIZE {
+        return Err(Error::OperationTooLarge {
+            operation: "I2C read".to_string(),
+            max: consts::i2c::REPORT_MAX_DATA_SIZE,
+            actual: read_len,
+        });
+    }
+    // With TEN_BIT_ADDR the address field holds the 11110xx0 prefix byte and the low
+    // eight address bits lead the write data, so they count towards the write length
+    let address_bytes = match slave_addr {
+        I2cAddress::Bit7(_) => 0,
+        I2cAddress::Bit10(_) => 1,
+    };
+
+    // Prepare OUT report buffer (no Report ID byte needed for write())
+    let mut out_buf = vec![0u8; consts::i2c::OUT_REPORT_WRITE_BUF_SIZE];
+
+    // Set flags - add 10-bit address flag if needed
+    let mut final_flags = flags;
+    if matches!(slave_addr, I2cAddress::Bit10(_)) {
+        final_flags |= consts::i2c::out_flags::TEN_BIT_ADDR;
+    }
+
+    out_buf[request_offsets::FLAGS] = final_flags;
+    out_buf[request_offsets::WRITE_LENGTH] = (address_bytes + write_len) as u8;
+    out_buf[request_offsets::READ_LENGTH] = read_len as u8;
+    // The 8-bit wire format: the 7-bit address in bits 7:1, or the 10-bit prefix byte
+    out_buf[request_offsets::SLAVE_ADDRESS] = slave_addr.wire_byte_write();
+
+    let data_start = match slave_addr {
+        I2cAddress::Bit10(addr) => {
+            out_buf[request_offsets::ADDR_10BIT_LOW] = (addr & 0xFF) as u8;
+            request_offsets::WRITE_DATA_10BIT_START
         }
+        _ => request_offsets::WRITE_DATA_START,
+    };
+    out_buf[data_start..data_start + write_len].copy_from_slice(write_data);
+
+    Ok(out_buf)
+}
+
+/// Validates the I2C_SLAVE_IN report size and copies read data into `read_buffer`,
+/// leaving the status flags unchecked.
+fn parse_i2c_response_raw(
+    slave_addr: I2cAddress,
+    response: &[u8],
+    read_buffer: Option<&mut [u8]>,
+) -> Result<I2cTransferStatus> {
+    let received = response.len();
+    if received == 0 {
+        // The device did not send a response report within the timeout.
+        // This typically happens when the XR2280x gets a NACK and fails to send back
+        // an I2C_SLAVE_IN report due to a firmware quirk.
+        return Err(Error::I2cTimeout {
+            address: slave_addr,
+        });
+    }
+
+    if received < response_offsets::MIN_RESPONSE_SIZE {
+        return Err(Error::InvalidReport(received));
+    }
+
+    let status_flags = response[response_offsets::STATUS_FLAGS];
+
+    // Extract read data only if reading was requested
+    let Some(read_buf) = read_buffer.filter(|b| !b.is_empty()) else {
+        return Ok(I2cTransferStatus {
+            status_flags,
+            bytes_read: 0,
+        });
+    };
+    let read_len = read_buf.len();
+    let reported_read_len = response[response_offsets::READ_LENGTH] as usize;
+    if reported_read_len != read_len {
+        warn!("I2C read length mismatch: expected {read_len}, got {reported_read_len}");
+    }
+    let actual_read_len = reported_read_len
+        .min(read_len)
+        .min(received.saturating_sub(response_offsets::READ_DATA_START));
+
+    read_buf[..actual_read_len].copy_from_slice(
+        &response[response_offsets::READ_DATA_START
+            ..response_offsets::READ_DATA_START + actual_read_len],
+    );
+    Ok(I2cTransferStatus {
+        status_flags,
+        bytes_read: actual_read_len,
+    })
+}
 
-        if received < response_offsets::MIN_RESPONSE_SIZE {
-            return Err(Error::InvalidReport(received));
+/// Converts the error bits of an I2C_SLAVE_IN status byte to an error.
+///
+/// `write_len` and `read_len` describe the request, for the context of
+/// [`Error::I2cRequestError`].
+fn check_i2c_status(
+    slave_addr: I2cAddress,
+    status_flags: u8,
+    write_len: usize,
+    read_len: usize,
+) -> Result<()> {
+    if status_flags & consts::i2c::in_flags::REQUEST_ERROR != 0 {
+        return Err(Error::I2cRequestError {
+            address: slave_addr,
+            write_len,
+            read_len,
+        });
+    }
+    if status_flags & consts::i2c::in_flags::NAK_RECEIVED != 0 {
+        return Err(Error::I2cNack {
+            address: slave_addr,
+        });
+    }
+    if status_flags & consts::i2c::in_flags::ARBITRATION_LOST != 0 {
+        return Err(Error::I2cArbitrationLost {
+            address: slave_addr,
+        });
+    }
+    if status_flags & consts::i2c::in_flags::TIMEOUT != 0 {
+        return Err(Error::I2cTimeout {
+            address: slave_addr,
+        });
+    }
+    if status_flags & 0x0F != 0 {
+        // Any other error bits set
+        return Err(Error::I2cUnknownError {
+            address: slave_addr,
+            flags: status_flags,
+        });
+    }
+    Ok(())
+}
+
+/// Number of addresses in the inclusive 7-bit scan range `start_addr..=end_addr`.
+fn scan_range_len(start_addr: u8, end_addr: u8) -> Result<usize> {
+    if start_addr > end_addr || end_addr > 0x7F {
+        return Err(Error::ArgumentOutOfRange(format!(
+            "I2C scan range 0x{start_addr:02X}..=0x{end_addr:02X} must be ascending within 0x00..=0x7F"
+        )));
+    }
+    Ok(usize::from(end_addr - start_addr) + 1)
+}
+
+/// Converts a speed in kHz to Hz, checking it is between 1 kHz and
+/// [`consts::i2c::MAX_SPEED_HZ`].
+fn speed_khz_to_hz(speed_khz: u32) -> Result<u32> {
+    let max_khz = consts::i2c::MAX_SPEED_HZ / 1000;
+    if speed_khz == 0 || speed_khz > max_khz {
+        return Err(Error::ArgumentOutOfRange(format!(
+            "I2C speed {speed_khz} kHz out of range (1-{max_khz})"
+        )));
+    }
+    Ok(speed_khz * 1000)
+}
+
+/// The single HID timeout covering a write phase and a read phase.
+fn combined_timeout(write_timeout_ms: i32, read_timeout_ms: i32) -> Result<i32> {
+    if write_timeout_ms < 0 || read_timeout_ms < 0 {
+        return Err(Error::ArgumentOutOfRange(format!(
+            "I2C phase timeouts must not be negative (got {write_timeout_ms}ms write, {read_timeout_ms}ms read)"
+        )));
+    }
+    Ok(write_timeout_ms.saturating_add(read_timeout_ms))
+}
+
+/// Replaces the bits of `current` selected by `mask` with those of `value`.
+fn updated_bits(current: u8, mask: u8, value: u8) -> u8 {
+    (current & !mask) | (value & mask)
+}
+
+/// Multiplies a timeout by `scale`, rounding and keeping it within 1..=i32::MAX ms.
+fn scale_timeout(base_ms: i32, scale: f64) -> i32 {
+    (f64::from(base_ms) * scale)
+        .round()
+        .clamp(1.0, f64::from(i32::MAX)) as i32
+}
+
+/// Computes the (SCL_LOW, SCL_HIGH) register values for a bus speed in Hz.
+fn scl_timing(speed_hz: u32) -> Result<(u16, u16)> {
+    if !(consts::i2c::MIN_SPEED_HZ..=consts::i2c::MAX_SPEED_HZ).contains(&speed_hz) {
+        return Err(Error::ArgumentOutOfRange(format!(
+            "I2C speed {speed_hz} Hz out of range ({}-{})",
+            consts::i2c::MIN_SPEED_HZ,
+            consts::i2c::MAX_SPEED_HZ
+        )));
+    }
+    let target_total_cycles = consts::i2c::SCL_CLOCK_HZ / speed_hz;
+    let low_cycles = target_total_cycles / 2;
+    let high_cycles = target_total_cycles - low_cycles;
+    let (min_low, min_high) = if speed_hz <= 100_000 {
+        consts::i2c::SCL_MIN_CYCLES_STANDARD
+    } else {
+        consts::i2c::SCL_MIN_CYCLES_FAST
+    };
+    Ok((
+        low_cycles.max(min_low) as u16,
+        high_cycles.max(min_high) as u16,
+    ))
+}
+
+/// A simulated I2C bus for unit tests, plugged in with `Xr2280x::with_i2c_transport`.
+///
+/// Each target is a 7-bit address with 256 byte-wide registers and an auto-incrementing
+/// register pointer: a transfer with START sets the pointer from its first written byte,
+/// further written bytes are stored from the pointer on and reads return the registers
+/// from the pointer on. Addresses without a target NACK, and a hung engine answers
+/// nothing at all. Clones share the same bus, so a test can keep one to inspect the
+/// registers, the OUT reports sent and the timeouts the IN reports were read with.
+#[cfg(test)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MockI2cTransport {
+    bus: std::sync::Arc<std::sync::Mutex<MockI2cBus>>,
+}
+
+#[cfg(test)]
+#[derive(Debug, Default)]
+struct MockI2cBus {
+    targets: HashMap<u8, MockI2cTarget>,
+    requests: Vec<Vec<u8>>,
+    read_timeouts: Vec<i32>,
+    /// IN report answering the last OUT report, returned by the next read.
+    response: Option<Vec<u8>>,
+    hung: bool,
+    /// Longest write or read phase the engine accepts, `None` for no limit.
+    request_limit: Option<usize>,
+}
+
+#[cfg(test)]
+#[derive(Debug)]
+struct MockI2cTarget {
+    registers: [u8; 256],
+    pointer: u8,
+    /// Transfers still acknowledged, `None` for no limit.
+    acks_left: Option<usize>,
+}
+
+#[cfg(test)]
+impl MockI2cTransport {
+    /// Adds a target at `address` with all registers 0 except those in `registers`.
+    pub(crate) fn with_target(self, address: u8, registers: &[(u8, u8)]) -> Self {
+        let mut target = MockI2cTarget {
+            registers: [0; 256],
+            pointer: 0,
+            acks_left: None,
+        };
+        for &(reg, value) in registers {
+            target.registers[usize::from(reg)] = value;
         }
+        self.bus.lock().unwrap().targets.insert(address, target);
+        self
+    }
 
-        // Check status flags
-        let status_flags = in_buf[response_offsets::STATUS_FLAGS];
-        if status_flags & consts::i2c::in_flags::REQUEST_ERROR != 0 {
-            return Err(Error::I2cRequestError {
-                address: slave_addr,
-            });
+    /// Adds a target at `address` that acknowledges its first `acks` transfers and
+    /// NACKs every later one.
+    pub(crate) fn with_flaky_target(self, address: u8, acks: usize) -> Self {
+        let bus = self.with_target(address, &[]);
+        bus.bus
+            .lock()
+            .unwrap()
+            .targets
+            .get_mut(&address)
+            .unwrap()
+            .acks_left = Some(acks);
+        bus
+    }
+
+    /// Makes the engine accept OUT reports but never send an IN report, like firmware
+    /// stuck on a held bus.
+    pub(crate) fn with_hung_engine(self) -> Self {
+        self.bus.lock().unwrap().hung = true;
+        self
+    }
+
+    /// Makes the engine answer requests with a write or read phase longer than `max`
+    /// bytes with the request error status, without addressing the target.
+    pub(crate) fn with_request_limit(self, max: usize) -> Self {
+        self.bus.lock().unwrap().request_limit = Some(max);
+        self
+    }
+
+    /// Current value of register `reg` of the target at `address`.
+    pub(crate) fn register(&self, address: u8, reg: u8) -> u8 {
+        self.bus.lock().unwrap().targets[&address].registers[usize::from(reg)]
+    }
+
+    /// Every OUT report sent so far.
+    pub(crate) fn requests(&self) -> Vec<Vec<u8>> {
+        self.bus.lock().unwrap().requests.clone()
+    }
+
+    /// The timeout in milliseconds of every IN report read so far.
+    pub(crate) fn read_timeouts(&self) -> Vec<i32> {
+        self.bus.lock().unwrap().read_timeouts.clone()
+    }
+}
+
+#[cfg(test)]
+impl I2cTransport for MockI2cTransport {
+    fn write_report(&self, report: &[u8]) -> Result<usize> {
+        let mut bus = self.bus.lock().unwrap();
+        bus.requests.push(report.to_vec());
+        if bus.hung {
+            return Ok(report.len());
         }
-        if status_flags & consts::i2c::in_flags::NAK_RECEIVED != 0 {
-            return Err(Error::I2cNack {
-                address: slave_addr,
-            });
+
+        let flags = report[request_offsets::FLAGS];
+        let write_len = usize::from(report[request_offsets::WRITE_LENGTH]);
+        let read_len = usize::from(report[request_offsets::READ_LENGTH]);
+        let mut data = &report[request_offsets::WRITE_DATA_START..][..write_len];
+        let address = report[request_offsets::SLAVE_ADDRESS] >> 1;
+
+        let mut response = vec![0u8; response_offsets::READ_DATA_START + read_len];
+        if bus
+            .request_limit
+            .is_some_and(|max| write_len > max || read_len > max)
+        {
+            response[response_offsets::STATUS_FLAGS] = consts::i2c::in_flags::REQUEST_ERROR;
+            bus.response = Some(response);
+            return Ok(report.len());
         }
-        if status_flags & consts::i2c::in_flags::ARBITRATION_LOST != 0 {
-            return Err(Error::I2cArbitrationLost {
-                address: slave_addr,
-            });
+        let target = bus.targets.get_mut(&address).filter(|target| {
+            flags & consts::i2c::out_flags::TEN_BIT_ADDR == 0 && target.acks_left != Some(0)
+        });
+        match target {
+            Some(target) => {
+                if let Some(acks) = &mut target.acks_left {
+                    *acks -= 1;
+                }
+                if flags & consts::i2c::out_flags::START_BIT != 0
+                    && let Some((&pointer, rest)) = data.split_first()
+                {
+                    target.pointer = pointer;
+                    data = rest;
+                }
+                for &byte in data {
+                    target.registers[usize::from(target.pointer)] = byte;
+                    target.pointer = target.pointer.wrapping_add(1);
+                }
+                for byte in &mut response[response_offsets::READ_DATA_START..] {
+                    *byte = target.registers[usize::from(target.pointer)];
+                    target.pointer = target.pointer.wrapping_add(1);
+                }
+                response[response_offsets::READ_LENGTH] = read_len as u8;
+            }
+            _ => response[response_offsets::STATUS_FLAGS] = consts::i2c::in_flags::NAK_RECEIVED,
         }
-        if status_flags & consts::i2c::in_flags::TIMEOUT != 0 {
-            return Err(Error::I2cTimeout {
-                address: slave_addr,
+        bus.response = Some(response);
+        Ok(report.len())
+    }
+
+    fn read_report(&self, buffer: &mut [u8], timeout_ms: i32) -> Result<usize> {
+        let mut bus = self.bus.lock().unwrap();
+        bus.read_timeouts.push(timeout_ms);
+        let Some(response) = bus.response.take() else {
+            return Ok(0);
+        };
+        buffer[..response.len()].copy_from_slice(&response);
+        Ok(response.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Flags, write data and read length of every 7-bit OUT report sent.
+    fn sent(bus: &MockI2cTransport) -> Vec<(u8, Vec<u8>, usize)> {
+        bus.requests()
+            .iter()
+            .map(|report| {
+                let write_len = usize::from(report[request_offsets::WRITE_LENGTH]);
+                (
+                    report[request_offsets::FLAGS],
+                    report[request_offsets::WRITE_DATA_START..][..write_len].to_vec(),
+                    usize::from(report[request_offsets::READ_LENGTH]),
+                )
+            })
+            .collect()
+    }
+
+    /// 7-bit addresses of every OUT report sent.
+    fn addressed(bus: &MockI2cTransport) -> Vec<u8> {
+        bus.requests()
+            .iter()
+            .map(|report| report[request_offsets::SLAVE_ADDRESS] >> 1)
+            .collect()
+    }
+
+    /// A target whose every register holds its own address.
+    fn counting_target(bus: MockI2cTransport, address: u8) -> MockI2cTransport {
+        let registers: Vec<(u8, u8)> = (0..=255).map(|reg| (reg, reg)).collect();
+        bus.with_target(address, &registers)
+    }
+
+    #[test]
+    fn test_scan_confirmation_drops_flaky_address() {
+        let config = ScanConfig {
+            confirm_timeout_ms: Some(timeouts::READ),
+            ..ScanConfig::default()
+        };
+        // 0x3C acknowledges once, e.g. because of a glitch, then never again
+        let bus = MockI2cTransport::default()
+            .with_target(0x50, &[])
+            .with_flaky_target(0x3C, 1);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let mut progress = Vec::new();
+        let found = device
+            .i2c_scan_with_config(0x08, 0x77, &config, |addr, found, _, _| {
+                progress.push((addr, found))
+            })
+            .unwrap();
+
+        assert_eq!(found, vec![0x50]);
+        assert_eq!(progress.len(), 0x70);
+        assert!(progress.contains(&(0x3C, true)));
+        // The firmware probe and the main pass, then only the two acknowledging
+        // addresses are re-probed, with the longer timeout
+        let timeouts = bus.read_timeouts();
+        assert_eq!(timeouts.len(), 1 + 0x70 + 2);
+        assert_eq!(timeouts[0], timeouts::PROBE);
+        assert!(timeouts[1..=0x70].iter().all(|&t| t == timeouts::SCAN));
+        assert_eq!(addressed(&bus)[1 + 0x70..], [0x3C, 0x50]);
+        assert_eq!(timeouts[1 + 0x70..], [timeouts::READ, timeouts::READ]);
+    }
+
+    #[test]
+    fn test_scan_without_confirmation_keeps_single_ack() {
+        let bus = MockI2cTransport::default().with_flaky_target(0x3C, 1);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        assert_eq!(device.i2c_scan(0x3C, 0x3D).unwrap(), vec![0x3C]);
+        assert_eq!(addressed(&bus), [0x00, 0x3C, 0x3D]);
+        assert_eq!(bus.read_timeouts()[1..], [timeouts::SCAN, timeouts::SCAN]);
+    }
+
+    #[test]
+    fn test_scan_stops_on_timeout() {
+        // The firmware probe catches a hung engine before the scan starts
+        let bus = MockI2cTransport::default().with_hung_engine();
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let result =
+            device.i2c_scan_with_config(0x08, 0x77, &ScanConfig::thorough(), |_, _, _, _| {
+                panic!("no progress expected")
             });
+        assert!(matches!(result, Err(Error::I2cTimeout { .. })));
+        assert_eq!(addressed(&bus), [0x00]);
+
+        // The scan itself stops at the first timeout too
+        let bus = MockI2cTransport::default().with_hung_engine();
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let result = device.i2c_scan_pass(0x08, 0x77, &ScanConfig::thorough(), |_, _, _, _| {
+            panic!("no progress expected")
+        });
+        assert!(matches!(result, Err(Error::I2cTimeout { .. })));
+        assert_eq!(addressed(&bus), [0x08]);
+    }
+
+    #[test]
+    fn test_scl_timing_hz_matches_khz() {
+        assert_eq!(scl_timing(100_000).unwrap(), (300, 300));
+        for khz in 1..=400u32 {
+            // Formula used by the original kHz-only implementation
+            let total = 60_000 / khz;
+            let (min_low, min_high) = if khz <= 100 { (252, 240) } else { (78, 36) };
+            let expected = (
+                (total / 2).max(min_low) as u16,
+                (total - total / 2).max(min_high) as u16,
+            );
+            assert_eq!(scl_timing(khz * 1000).unwrap(), expected, "{khz} kHz");
         }
-        if status_flags & 0x0F != 0 {
-            // Any other error bits set
-            return Err(Error::I2cUnknownError {
-                address: slave_addr,
-                flags: status_flags,
-            });
+    }
+
+    #[test]
+    fn test_speed_limit_boundaries() {
+        let device = Xr2280x::new_for_test(8);
+        let (min_hz, max_hz) = device.i2c_speed_limits();
+        assert_eq!((min_hz, max_hz), (458, 400_000));
+        assert!(scl_timing(min_hz).is_ok());
+        assert!(scl_timing(max_hz).is_ok());
+        match scl_timing(max_hz + 1) {
+            Err(Error::ArgumentOutOfRange(message)) => assert!(message.contains("458-400000")),
+            other => panic!("expected ArgumentOutOfRange, got {other:?}"),
+        }
+        assert!(matches!(
+            scl_timing(1_000_000),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+        match speed_khz_to_hz(401) {
+            Err(Error::ArgumentOutOfRange(message)) => assert!(message.contains("1-400")),
+            other => panic!("expected ArgumentOutOfRange, got {other:?}"),
         }
+        assert_eq!(speed_khz_to_hz(400).unwrap(), max_hz);
+        assert!(matches!(
+            device.i2c_set_speed_khz(401),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_scl_timing_sub_khz() {
+        assert_eq!(scl_timing(500).unwrap(), (60_000, 60_000));
+        let (low, high) = scl_timing(consts::i2c::MIN_SPEED_HZ).unwrap();
+        assert!(low > 65_000 && high > 65_000);
+        assert!(scl_timing(consts::i2c::MIN_SPEED_HZ - 1).is_err());
+        assert!(scl_timing(0).is_err());
+        assert!(scl_timing(400_001).is_err());
+    }
+
+    fn response(status: u8, read_len: u8, data: &[u8]) -> Vec<u8> {
+        let mut buf = vec![status, 0, read_len, 0];
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    #[test]
+    fn test_health_from_scan_with_devices() {
+        let bus = MockI2cTransport::default()
+            .with_target(0x20, &[])
+            .with_target(0x48, &[])
+            .with_target(0x68, &[]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus);
+        let health = device.i2c_health_check().unwrap();
+        assert_eq!(health.device_count, 3);
+        assert_eq!(health.addresses, vec![0x20, 0x48, 0x68]);
+        assert!(!health.bus_stuck);
+    }
+
+    #[test]
+    fn test_health_from_scan_stuck_bus() {
+        let bus = MockI2cTransport::default().with_hung_engine();
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus);
+        let health = device.i2c_health_check().unwrap();
+        assert!(health.bus_stuck);
+        assert_eq!(health.device_count, 0);
+        assert!(health.addresses.is_empty());
+
+        let contention = Err(Error::I2cArbitrationLost {
+            address: I2cAddress::new_7bit(0x08).unwrap(),
+        });
+        assert!(matches!(
+            BusHealth::from_scan(contention, std::time::Duration::ZERO),
+            Err(Error::I2cArbitrationLost { .. })
+        ));
+    }
+
+    #[test]
+    fn test_read_register_block_single_pointer_write_then_chunks() {
+        use consts::i2c::out_flags::{ACK_LAST_READ, START_BIT, STOP_BIT};
+        let bus = counting_target(MockI2cTransport::default(), 0x68);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let data = device.i2c_read_registers(0x68, 0x10, 70).unwrap();
+
+        assert_eq!(
+            sent(&bus),
+            vec![
+                (START_BIT | ACK_LAST_READ, vec![0x10], 32),
+                (ACK_LAST_READ, vec![], 32),
+                (STOP_BIT, vec![], 6),
+            ]
+        );
+        assert_eq!(
+            bus.read_timeouts(),
+            [timeouts::WRITE_READ, timeouts::READ, timeouts::READ]
+        );
+        assert_eq!(data, (0x10..0x10 + 70).collect::<Vec<u8>>());
+
+        // A 16-bit pointer goes out high byte first, once
+        let bus = MockI2cTransport::default().with_target(0x68, &[]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        device.i2c_read_registers_16bit(0x68, 0x1234, 70).unwrap();
+        let writes: Vec<_> = sent(&bus).into_iter().map(|(_, write, _)| write).collect();
+        assert_eq!(writes, [vec![0x12, 0x34], vec![], vec![]]);
+    }
+
+    #[test]
+    fn test_read_stream_chunks() {
+        use consts::i2c::out_flags::{ACK_LAST_READ, START_BIT, STOP_BIT};
+        let stream = |count| {
+            let bus = counting_target(MockI2cTransport::default(), 0x68);
+            let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+            let mut chunks = Vec::new();
+            device
+                .i2c_read_7bit_stream(0x68, count, |chunk| chunks.push(chunk.to_vec()))
+                .unwrap();
+            assert!(sent(&bus).iter().all(|(_, write, _)| write.is_empty()));
+            assert!(bus.read_timeouts().iter().all(|&t| t == timeouts::READ));
+            let flags: Vec<u8> = sent(&bus).into_iter().map(|(flags, _, _)| flags).collect();
+            (flags, chunks)
+        };
+
+        let (flags, chunks) = stream(70);
+        assert_eq!(
+            flags,
+            vec![START_BIT | ACK_LAST_READ, ACK_LAST_READ, STOP_BIT]
+        );
+        assert_eq!(
+            chunks,
+            vec![
+                (0..32).collect::<Vec<u8>>(),
+                (32..64).collect(),
+                (64..70).collect()
+            ]
+        );
+
+        let (flags, chunks) = stream(64);
+        assert_eq!(flags, vec![START_BIT | ACK_LAST_READ, STOP_BIT]);
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [32, 32]);
 
-        // Extract read data only if reading was requested
-        if read_len > 0 {
-            let reported_read_len = in_buf[response_offsets::READ_LENGTH] as usize;
-            if reported_read_len != read_len {
-                warn!("I2C read length mismatch: expected {read_len}, got {reported_read_len}");
+        let (flags, chunks) = stream(5);
+        assert_eq!(flags, vec![START_BIT | STOP_BIT]);
+        assert_eq!(chunks, vec![vec![0, 1, 2, 3, 4]]);
+
+        assert_eq!(stream(0), (vec![], vec![]));
+
+        // A failed chunk stops the stream before its data is handed out
+        let bus = MockI2cTransport::default().with_flaky_target(0x68, 2);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus);
+        let mut handled = 0;
+        let result = device.i2c_read_7bit_stream(0x68, 70, |_| handled += 1);
+        assert!(matches!(result, Err(Error::I2cNack { .. })));
+        assert_eq!(handled, 2);
+    }
+
+    #[test]
+    fn test_write_then_read_no_stop_flag_sequence() {
+        use consts::i2c::out_flags::{START_BIT, STOP_BIT};
+        let bus = MockI2cTransport::default().with_target(0x48, &[(0xA5, 1), (0xA6, 2), (0xA7, 3)]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let mut buffer = [0u8; 3];
+        device
+            .i2c_write_then_read_no_stop(0x48, &[0xA5], &mut buffer)
+            .unwrap();
+
+        assert_eq!(
+            sent(&bus),
+            vec![(START_BIT, vec![0xA5], 0), (STOP_BIT, vec![], 3)]
+        );
+        assert_eq!(bus.read_timeouts(), [timeouts::WRITE, timeouts::READ]);
+        assert_eq!(START_BIT, 0x01);
+        assert_eq!(STOP_BIT, 0x02);
+        assert_eq!(buffer, [1, 2, 3]);
+
+        // A failed write does not issue the read
+        let bus = MockI2cTransport::default();
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let result = device.i2c_write_then_read_no_stop(0x48, &[0xA5], &mut buffer);
+        assert!(matches!(result, Err(Error::I2cNack { .. })));
+        assert_eq!(bus.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_read_register_block_single_chunk() {
+        use consts::i2c::out_flags::{START_BIT, STOP_BIT};
+        let bus = MockI2cTransport::default().with_target(0x68, &[]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        device.i2c_read_registers(0x68, 0x3B, 14).unwrap();
+        assert_eq!(sent(&bus), vec![(START_BIT | STOP_BIT, vec![0x3B], 14)]);
+
+        let data = device.i2c_read_registers(0x68, 0x3B, 0).unwrap();
+        assert!(data.is_empty());
+        assert_eq!(bus.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_ping_request_is_zero_length_write() {
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        let out_buf = build_i2c_request(addr, &[], 0, PING_FLAGS).unwrap();
+        assert_eq!(
+            out_buf[request_offsets::FLAGS],
+            consts::i2c::out_flags::START_BIT | consts::i2c::out_flags::STOP_BIT
+        );
+        assert_eq!(out_buf[request_offsets::WRITE_LENGTH], 0);
+        assert_eq!(out_buf[request_offsets::READ_LENGTH], 0);
+        assert_eq!(out_buf[request_offsets::SLAVE_ADDRESS], 0xA0);
+        assert!(
+            out_buf[request_offsets::WRITE_DATA_START..]
+                .iter()
+                .all(|&b| b == 0)
+        );
+    }
+
+    #[test]
+    fn test_build_request_10bit_write_boundary() {
+        let addr = I2cAddress::new_10bit(0x150).unwrap();
+        let data = [0xA5u8; 31];
+        let out_buf = build_i2c_request(addr, &data, 0, START_STOP).unwrap();
+        assert_eq!(out_buf.len(), consts::i2c::OUT_REPORT_WRITE_BUF_SIZE);
+        assert_eq!(out_buf[request_offsets::WRITE_LENGTH], 32);
+        assert_eq!(out_buf[request_offsets::ADDR_10BIT_LOW], 0x50);
+        assert_eq!(
+            &out_buf[request_offsets::WRITE_DATA_10BIT_START..],
+            &data[..]
+        );
+
+        let data = [0xA5u8; 32];
+        match build_i2c_request(addr, &data, 0, 0) {
+            Err(Error::OperationTooLarge {
+                operation,
+                max,
+                actual,
+            }) => {
+                assert_eq!(operation, "I2C 10-bit write");
+                assert_eq!(max, 31);
+                assert_eq!(actual, 32);
             }
-            let actual_read_len = reported_read_len
-                .min(read_len)
-                .min(received.saturating_sub(response_offsets::READ_DATA_START));
-
-            if let Some(read_buf) = read_buffer {
-                read_buf[..actual_read_len].copy_from_slice(
-                    &in_buf[response_offsets::READ_DATA_START
-                        ..response_offsets::READ_DATA_START + actual_read_len],
-                );
+            other => panic!("expected OperationTooLarge, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_10bit_request_report_bytes() {
+        let addr = I2cAddress::new_10bit(0x2A5).unwrap();
+        let ten_bit = consts::i2c::out_flags::TEN_BIT_ADDR;
+        // flags, write length, read length, 11110xx0 prefix, low address byte, data
+
+        let report = build_i2c_request(addr, &[0x10, 0x20], 0, START_STOP).unwrap();
+        assert_eq!(
+            report[..7],
+            [START_STOP | ten_bit, 3, 0, 0xF4, 0xA5, 0x10, 0x20]
+        );
+
+        // A read sends the low address byte as its only write data
+        let report = build_i2c_request(addr, &[], 4, START_STOP).unwrap();
+        assert_eq!(report[..5], [START_STOP | ten_bit, 1, 4, 0xF4, 0xA5]);
+
+        // The register pointer of a write-read follows the low address byte
+        let report = build_i2c_request(addr, &[0x07], 2, START_STOP).unwrap();
+        assert_eq!(report[..6], [START_STOP | ten_bit, 2, 2, 0xF4, 0xA5, 0x07]);
+
+        // Requests without START carry the same address bytes
+        let stop = consts::i2c::out_flags::STOP_BIT;
+        let report = build_i2c_request(addr, &[], 8, stop).unwrap();
+        assert_eq!(report[..5], [stop | ten_bit, 1, 8, 0xF4, 0xA5]);
+        assert!(report[5..].iter().all(|&b| b == 0));
+
+        // 7-bit addresses have no address byte in the data
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        let report = build_i2c_request(addr, &[0x07], 2, START_STOP).unwrap();
+        assert_eq!(report[..5], [START_STOP, 1, 2, 0xA0, 0x07]);
+    }
+
+    #[test]
+    fn test_build_request_10bit_max_length_write_does_not_panic() {
+        // Regression: a 32-byte 10-bit write used to index past the end of the OUT report
+        let addr = I2cAddress::new_10bit(0x3FF).unwrap();
+        let result = std::panic::catch_unwind(|| build_i2c_request(addr, &[0u8; 32], 0, 0));
+        assert!(matches!(
+            result,
+            Ok(Err(Error::OperationTooLarge { max: 31, .. }))
+        ));
+        assert!(build_i2c_request(addr, &[0u8; 31], 0, 0).is_ok());
+    }
+
+    #[test]
+    fn test_build_request_distinguishes_write_and_read_limits() {
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        assert!(build_i2c_request(addr, &[0u8; 32], 32, 0).is_ok());
+        assert!(matches!(
+            build_i2c_request(addr, &[0u8; 33], 0, 0),
+            Err(Error::OperationTooLarge { operation, .. }) if operation == "I2C write"
+        ));
+        assert!(matches!(
+            build_i2c_request(addr, &[], 33, 0),
+            Err(Error::OperationTooLarge { operation, .. }) if operation == "I2C read"
+        ));
+    }
+
+    #[test]
+    fn test_parse_response_reports_short_read() {
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        let mut buffer = [0xEEu8; 8];
+        let status =
+            parse_i2c_response_raw(addr, &response(0, 3, &[1, 2, 3, 0, 0]), Some(&mut buffer))
+                .unwrap();
+        assert_eq!(status.bytes_read, 3);
+        assert_eq!(&buffer[..3], &[1, 2, 3]);
+        assert_eq!(&buffer[3..], &[0xEE; 5]);
+    }
+
+    #[test]
+    fn test_parse_response_clamps_to_buffer_and_report() {
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        let mut buffer = [0u8; 2];
+        let status =
+            parse_i2c_response_raw(addr, &response(0, 4, &[9, 8, 7, 6]), Some(&mut buffer))
+                .unwrap();
+        assert_eq!(status.bytes_read, 2);
+        assert_eq!(buffer, [9, 8]);
+
+        let mut buffer = [0u8; 8];
+        let status =
+            parse_i2c_response_raw(addr, &response(0, 8, &[9, 8]), Some(&mut buffer)).unwrap();
+        assert_eq!(status.bytes_read, 2);
+    }
+
+    #[test]
+    fn test_bound_device_delegates_to_its_address() {
+        let bus = MockI2cTransport::default().with_target(0x48, &[(0x10, 0x5A)]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let handle = device.i2c_device_7bit(0x48).unwrap();
+
+        handle.write(&[1, 2]).unwrap();
+        let mut buffer = [0u8; 3];
+        handle.read(&mut buffer).unwrap();
+        handle.write_read(&[7], &mut buffer[..2]).unwrap();
+        assert_eq!(handle.read_reg8(0x10).unwrap(), 0x5A);
+        handle.write_reg8(0x01, 0x60).unwrap();
+        handle.ping().unwrap();
+
+        assert!(addressed(&bus).iter().all(|&addr| addr == 0x48));
+        assert_eq!(bus.register(0x48, 0x01), 0x60);
+        assert_eq!(
+            sent(&bus)
+                .into_iter()
+                .map(|(_, write, read)| (write, read))
+                .collect::<Vec<_>>(),
+            vec![
+                (vec![1, 2], 0),
+                (vec![], 3),
+                (vec![7], 2),
+                (vec![0x10], 1),
+                (vec![0x01, 0x60], 0),
+                (vec![], 0),
+            ]
+        );
+        assert_eq!(
+            bus.read_timeouts(),
+            [
+                timeouts::WRITE,
+                timeouts::READ,
+                timeouts::WRITE_READ,
+                timeouts::WRITE_READ,
+                timeouts::WRITE,
+                timeouts::PROBE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_reg16_byte_order_and_sign() {
+        let bus = MockI2cTransport::default().with_target(
+            0x48,
+            &[
+                (0x00, 0x12),
+                (0x01, 0x34),
+                (0x02, 0xFF),
+                (0x03, 0x38),
+                (0x04, 0x38),
+                (0x05, 0xFF),
+            ],
+        );
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+
+        assert_eq!(device.i2c_read_reg_be16(0x48, 0x00).unwrap(), 0x1234);
+        assert_eq!(device.i2c_read_reg_le16(0x48, 0x00).unwrap(), 0x3412);
+
+        // 0xFF38 is -200 big-endian; little-endian it is 0x38FF, which is positive
+        assert_eq!(device.i2c_read_reg_be16(0x48, 0x02).unwrap(), 0xFF38);
+        assert_eq!(device.i2c_read_reg_be_i16(0x48, 0x02).unwrap(), -200);
+        assert_eq!(device.i2c_read_reg_le_i16(0x48, 0x02).unwrap(), 0x38FF);
+        assert_eq!(device.i2c_read_reg_le_i16(0x48, 0x04).unwrap(), -200);
+
+        // One write-read of two bytes per value
+        let sent = sent(&bus);
+        assert_eq!(sent.len(), 6);
+        assert_eq!(sent[5], (START_STOP, vec![0x04], 2));
+        assert!(
+            bus.read_timeouts()
+                .iter()
+                .all(|&t| t == timeouts::WRITE_READ)
+        );
+
+        assert!(matches!(
+            device.i2c_read_reg_be16(0x80, 0x00),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_reg_writes_only_changes() {
+        assert_eq!(
+            updated_bits(0b1010_1010, 0b0000_1111, 0b0101_0101),
+            0b1010_0101
+        );
+        assert_eq!(updated_bits(0xFF, 0x00, 0x00), 0xFF);
+        assert_eq!(updated_bits(0x00, 0xFF, 0x3C), 0x3C);
+        // Bits of `value` outside `mask` are ignored
+        assert_eq!(updated_bits(0x00, 0x01, 0xFF), 0x01);
+
+        // Register 0x01 holds 0x5A (0101_1010): bit 1 is already set, bit 0 is not
+        let bus = MockI2cTransport::default().with_target(0x48, &[(0x01, 0x5A)]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        assert!(!device.i2c_update_reg8(0x48, 0x01, 0x02, 0xFF).unwrap());
+        assert!(device.i2c_update_reg8(0x48, 0x01, 0x03, 0x01).unwrap());
+        assert_eq!(bus.register(0x48, 0x01), 0x59);
+        assert!(
+            device
+                .i2c_update_reg8_16bit(0x48, 0x1234, 0x80, 0x80)
+                .unwrap()
+        );
+        let transfers: Vec<_> = sent(&bus)
+            .into_iter()
+            .map(|(_, write, read)| (write, read))
+            .collect();
+        assert_eq!(
+            transfers[..3],
+            [(vec![0x01], 1), (vec![0x01], 1), (vec![0x01, 0x59], 0)]
+        );
+        assert_eq!(
+            transfers[3..],
+            [(vec![0x12, 0x34], 1), (vec![0x12, 0x34, 0x80], 0)]
+        );
+        assert_eq!(
+            bus.read_timeouts(),
+            [
+                timeouts::WRITE_READ,
+                timeouts::WRITE_READ,
+                timeouts::WRITE,
+                timeouts::WRITE_READ,
+                timeouts::WRITE
+            ]
+        );
+    }
+
+    #[test]
+    fn test_device_handle_validates_address() {
+        let device = Xr2280x::new_for_test(8);
+        let sensor = device.i2c_device_7bit(0x48).unwrap();
+        assert_eq!(sensor.address(), I2cAddress::Bit7(0x48));
+        let eeprom = device.i2c_device_10bit(0x2A0).unwrap();
+        assert_eq!(eeprom.address(), I2cAddress::Bit10(0x2A0));
+        assert!(device.i2c_device_7bit(0x80).is_err());
+        assert!(device.i2c_device_10bit(0x400).is_err());
+        // Transfers go to the device of the handle
+        assert!(matches!(sensor.read_reg8(0), Err(Error::DeviceNotFound)));
+    }
+
+    #[test]
+    fn test_address_from_str() {
+        let parse = |s: &str| s.parse::<I2cAddress>();
+        assert_eq!(parse("0x50").unwrap(), I2cAddress::Bit7(0x50));
+        assert_eq!(parse("7:0x50").unwrap(), I2cAddress::Bit7(0x50));
+        assert_eq!(parse(" 7:0X7f ").unwrap(), I2cAddress::Bit7(0x7F));
+        assert_eq!(parse("80").unwrap(), I2cAddress::Bit7(0x50));
+        assert_eq!(parse("10:0x123").unwrap(), I2cAddress::Bit10(0x123));
+        assert_eq!(parse("10:0x123").unwrap().bits(), 0x123);
+        assert_eq!(parse("0x50").unwrap().bits(), 0x50);
+
+        for invalid in [
+            "", "0x", "0x80", "0x123", "7:300", "8:0x50", "10:", "0xZZ", "-1",
+        ] {
+            assert!(
+                matches!(parse(invalid), Err(Error::ArgumentOutOfRange(_))),
+                "{invalid:?} should not parse"
+            );
+        }
+        assert!(matches!(
+            parse("10:0x400"),
+            Err(Error::InvalidI2c10BitAddress(0x400))
+        ));
+    }
+
+    #[test]
+    fn test_speed_readback_mismatch_fails() {
+        use crate::device::MockRegisterFile;
+        let timing = scl_timing(100_000).unwrap();
+
+        // The high time register keeps coming back with a stale value
+        let stale = timing.1 ^ 0x0100;
+        let registers = MockRegisterFile::default()
+            .with_read_sequence(consts::i2c::REG_SCL_HIGH, &[stale, stale, stale]);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let result = device.i2c_set_speed_hz(100_000);
+        assert!(matches!(
+            result,
+            Err(Error::RegisterVerificationFailed { register, expected, .. })
+                if register == consts::i2c::REG_SCL_HIGH && expected == timing.1
+        ));
+        assert_eq!(
+            registers.writes(),
+            [
+                (consts::i2c::REG_SCL_LOW, timing.0),
+                (consts::i2c::REG_SCL_HIGH, timing.1),
+                (consts::i2c::REG_SCL_HIGH, timing.1),
+                (consts::i2c::REG_SCL_HIGH, timing.1),
+            ]
+        );
+
+        // Without verification nothing is read back
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        device.i2c_set_speed_hz_fast(100_000).unwrap();
+        assert_eq!(registers.writes().len(), 2);
+        assert!(registers.reads().is_empty());
+    }
+
+    #[test]
+    fn test_scan_and_identify() {
+        // 0x76 acknowledges the scan but not the ID read
+        let bus = MockI2cTransport::default()
+            .with_target(0x1D, &[(0x0F, 0x33)])
+            .with_target(0x68, &[(0x0F, 0x71)])
+            .with_flaky_target(0x76, 1);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let inventory = device.i2c_scan_and_identify(0x08, 0x77, 0x0F).unwrap();
+        assert_eq!(
+            inventory,
+            vec![(0x1D, Some(0x33)), (0x68, Some(0x71)), (0x76, None)]
+        );
+
+        // One ID read per device found, after the scan, with the scan timeout
+        assert_eq!(addressed(&bus)[1 + 0x70..], [0x1D, 0x68, 0x76]);
+        assert_eq!(
+            sent(&bus)[1 + 0x70..]
+                .iter()
+                .map(|(_, write, read)| (write.clone(), *read))
+                .collect::<Vec<_>>(),
+            vec![(vec![0x0F], 1); 3]
+        );
+        assert!(
+            bus.read_timeouts()[1..]
+                .iter()
+                .all(|&t| t == timeouts::SCAN)
+        );
+    }
+
+    #[test]
+    fn test_address_wire_bytes() {
+        for (addr, write) in [
+            (0x50, 0xA0),
+            (0x68, 0xD0),
+            (0x77, 0xEE),
+            (0x3C, 0x78),
+            (0x48, 0x90),
+        ] {
+            let address = I2cAddress::new_7bit(addr).unwrap();
+            assert_eq!(address.wire_byte_write(), write);
+            assert_eq!(address.wire_byte_read(), write | 1);
+            assert_eq!(I2cAddress::from_wire_byte(write), address);
+            assert_eq!(I2cAddress::from_wire_byte(write | 1), address);
+        }
+        assert_eq!(I2cAddress::Bit7(0x7F).wire_byte_read(), 0xFF);
+
+        // 10-bit addresses start with the 11110 prefix and address bits 9-8
+        assert_eq!(I2cAddress::Bit10(0x3FF).wire_byte_write(), 0xF6);
+        assert_eq!(I2cAddress::Bit10(0x123).wire_byte_read(), 0xF3);
+        assert_eq!(I2cAddress::Bit10(0x0FF).wire_byte_write(), 0xF0);
+    }
+
+    #[test]
+    fn test_scan_range_bounds() {
+        let scan = |start, end| {
+            let bus = MockI2cTransport::default();
+            let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+            let mut totals = Vec::new();
+            device
+                .i2c_scan_with_progress(start, end, |_, _, _, total| totals.push(total))
+                .map(|_| (addressed(&bus), totals))
+                .inspect_err(|_| assert!(bus.requests().is_empty()))
+        };
+
+        for (start, end) in [(0x50, 0x4F), (0x00, 0x80), (0x80, 0xFF), (0x00, 0xFF)] {
+            assert!(
+                matches!(scan(start, end), Err(Error::ArgumentOutOfRange(_))),
+                "0x{start:02X}..=0x{end:02X} should be rejected"
+            );
+        }
+
+        // Every scan starts with the firmware probe at 0x00
+        let (probed, totals) = scan(0x50, 0x50).unwrap();
+        assert_eq!((probed, totals), (vec![0x00, 0x50], vec![1]));
+
+        let (probed, totals) = scan(0x00, 0x7F).unwrap();
+        assert_eq!(probed[1..], (0x00..=0x7F).collect::<Vec<u8>>());
+        assert!(totals.iter().all(|&total| total == 128));
+    }
+
+    #[test]
+    fn test_split_timeout_is_sum_of_phases() {
+        assert_eq!(combined_timeout(5000, 100).unwrap(), 5100);
+        assert_eq!(combined_timeout(0, timeouts::READ).unwrap(), timeouts::READ);
+        assert_eq!(combined_timeout(i32::MAX, 100).unwrap(), i32::MAX);
+        assert!(matches!(
+            combined_timeout(-1, 100),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+        assert!(combined_timeout(100, -1).is_err());
+    }
+
+    #[test]
+    fn test_tuned_timeouts_replace_defaults() {
+        let bus = MockI2cTransport::default().with_target(0x50, &[]);
+        let mut device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let mut buffer = [0u8; 2];
+        assert_eq!(device.timeouts(), Timeouts::default());
+
+        // i2c_read_7bit and other plain reads use the `read` field
+        device.timeouts_mut().read = 300;
+        device.i2c_read_7bit(0x50, &mut buffer).unwrap();
+        device.i2c_write_7bit(0x50, &[0x00]).unwrap();
+        device.i2c_set_timeout_scale(2.0).unwrap();
+        device.i2c_read_7bit(0x50, &mut buffer).unwrap();
+        assert_eq!(bus.read_timeouts(), [300, timeouts::WRITE, 600]);
+
+        let slow = Timeouts {
+            scan: 50,
+            ..Timeouts::default()
+        };
+        device.set_timeouts(slow).unwrap();
+        assert_eq!(device.timeouts(), slow);
+        let invalid = Timeouts { probe: 0, ..slow };
+        assert!(device.set_timeouts(invalid).is_err());
+        assert_eq!(device.timeouts(), slow);
+    }
+
+    #[test]
+    fn test_timeout_scale_applies_to_defaults() {
+        let bus = MockI2cTransport::default().with_target(0x50, &[]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let mut buffer = [0u8; 2];
+        assert_eq!(device.i2c_timeout_scale(), 1.0);
+        device.i2c_read_7bit(0x50, &mut buffer).unwrap();
+
+        device.i2c_set_timeout_scale(2.0).unwrap();
+        device.i2c_read_7bit(0x50, &mut buffer).unwrap();
+        device.i2c_write_7bit(0x50, &[0x00]).unwrap();
+        assert_eq!(
+            bus.read_timeouts(),
+            [timeouts::READ, 2 * timeouts::READ, 2 * timeouts::WRITE]
+        );
+        // Explicit timeouts are not scaled
+        device.i2c_ping_7bit(0x50, timeouts::PROBE).unwrap();
+        assert_eq!(bus.read_timeouts().last(), Some(&timeouts::PROBE));
+
+        assert!(device.i2c_set_timeout_scale(0.0).is_err());
+        assert!(device.i2c_set_timeout_scale(f64::NAN).is_err());
+        assert_eq!(device.i2c_timeout_scale(), 2.0);
+
+        assert_eq!(scale_timeout(3, 0.1), 1);
+        assert_eq!(scale_timeout(100, 1.5), 150);
+        assert_eq!(scale_timeout(i32::MAX, 4.0), i32::MAX);
+    }
+
+    #[test]
+    fn test_raw_status_surfaces_flags_unchanged() {
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        // Sequence number 0xA plus NAK and arbitration lost together
+        let flags =
+            0xA0 | consts::i2c::in_flags::NAK_RECEIVED | consts::i2c::in_flags::ARBITRATION_LOST;
+        let mut buffer = [0u8; 2];
+        let status =
+            parse_i2c_response_raw(addr, &response(flags, 2, &[4, 5]), Some(&mut buffer)).unwrap();
+        assert_eq!(
+            status,
+            I2cTransferStatus {
+                status_flags: 0xA6,
+                bytes_read: 2
             }
+        );
+        assert_eq!(buffer, [4, 5]);
+        assert!(matches!(
+            check_i2c_status(addr, status.status_flags, 0, 2),
+            Err(Error::I2cNack { .. })
+        ));
+
+        // Hard failures are still errors
+        assert!(matches!(
+            parse_i2c_response_raw(addr, &[], None),
+            Err(Error::I2cTimeout { .. })
+        ));
+        assert!(matches!(
+            parse_i2c_response_raw(addr, &[0, 0], None),
+            Err(Error::InvalidReport(2))
+        ));
+    }
+
+    #[test]
+    fn test_parse_response_status_errors() {
+        let addr = I2cAddress::new_7bit(0x50).unwrap();
+        let nak = response(consts::i2c::in_flags::NAK_RECEIVED, 0, &[]);
+        let status = parse_i2c_response_raw(addr, &nak, None).unwrap();
+        assert!(matches!(
+            check_i2c_status(addr, status.status_flags, 1, 0),
+            Err(Error::I2cNack { .. })
+        ));
+
+        let status = parse_i2c_response_raw(addr, &response(0, 0, &[]), None).unwrap();
+        assert_eq!(status.bytes_read, 0);
+        check_i2c_status(addr, status.status_flags, 1, 0).unwrap();
+    }
+
+    #[test]
+    fn test_request_error_carries_lengths() {
+        let bus = MockI2cTransport::default()
+            .with_target(0x50, &[])
+            .with_request_limit(16);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+
+        // A combined transfer the firmware rejected
+        let mut buffer = [0u8; 4];
+        let error = device
+            .i2c_write_read_7bit(0x50, &[0x00; 20], &mut buffer)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::I2cRequestError {
+                write_len: 20,
+                read_len: 4,
+                ..
+            }
+        ));
+        assert!(error.to_string().contains("(write 20 bytes, read 4 bytes)"));
+        assert_eq!(bus.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_cached_register_reads() {
+        let bus = MockI2cTransport::default().with_target(0x48, &[(0x0F, 0x20)]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let handle = device.i2c_device_7bit(0x48).unwrap();
+        // Changes the register behind the cache's back
+        let set_register = |value| device.i2c_write_7bit(0x48, &[0x0F, value]).unwrap();
+        let reads = || sent(&bus).iter().filter(|(_, _, read)| *read == 1).count();
+
+        // Not enabled: every read goes to the device
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x20);
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x20);
+        assert_eq!(reads(), 2);
+
+        handle.cache_reg8(0x0F, Duration::from_secs(60));
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x20);
+        // Within the TTL the cached value is returned without a transfer
+        set_register(0x21);
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x20);
+        assert_eq!(reads(), 3);
+
+        handle.invalidate(0x0F);
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x21);
+        assert_eq!(reads(), 4);
+
+        // Expired: read again
+        handle.cache_reg8(0x0F, Duration::from_millis(10));
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x21);
+        set_register(0x22);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(handle.read_reg8_cached(0x0F).unwrap(), 0x22);
+        assert_eq!(reads(), 6);
+
+        // Failed reads are not cached
+        let absent = device.i2c_device_7bit(0x49).unwrap();
+        absent.cache_reg8(0x0F, Duration::from_secs(60));
+        assert!(matches!(
+            absent.read_reg8_cached(0x0F),
+            Err(Error::I2cNack { .. })
+        ));
+        let key = (absent.address(), 0x0F);
+        let cache = device.i2c_register_cache.lock().unwrap();
+        assert_eq!(cache.get(key, Instant::now()), None);
+    }
+
+    #[test]
+    fn test_read_length_prefixed() {
+        use consts::i2c::out_flags::{ACK_LAST_READ, START_BIT, STOP_BIT};
+        let read = |count: u8| {
+            // The count at 0x9A, followed by 1, 2, 3, ...
+            let registers: Vec<(u8, u8)> = std::iter::once((0x9A, count))
+                .chain((0x9B..=0xFF).zip(1..))
+                .collect();
+            let bus = MockI2cTransport::default().with_target(0x0B, &registers);
+            let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+            let result = device.i2c_read_length_prefixed(0x0B, 0x9A);
+            (result, sent(&bus))
+        };
+
+        // Zero length: the transaction is still finished with NACK and STOP
+        let (result, calls) = read(0);
+        assert!(result.unwrap().is_empty());
+        assert_eq!(
+            calls,
+            vec![
+                (START_BIT | ACK_LAST_READ, vec![0x9A], 1),
+                (STOP_BIT, vec![], 1)
+            ]
+        );
+
+        let (result, calls) = read(32);
+        assert_eq!(result.unwrap(), (1..=32).collect::<Vec<u8>>());
+        assert_eq!(calls[1], (STOP_BIT, vec![], 32));
+
+        let (result, calls) = read(33);
+        assert!(matches!(
+            result,
+            Err(Error::OperationTooLarge {
+                max: 32,
+                actual: 33,
+                ..
+            })
+        ));
+        assert_eq!(calls[1], (STOP_BIT, vec![], 1));
+
+        // A failed count read issues nothing else
+        let bus = MockI2cTransport::default();
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let result = device.i2c_read_length_prefixed(0x0B, 0x9A);
+        assert!(matches!(result, Err(Error::I2cNack { .. })));
+        assert_eq!(bus.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_register_helpers_against_mock_bus() {
+        let bus = MockI2cTransport::default().with_target(0x48, &[(0x00, 0x12), (0x01, 0x34)]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+
+        assert_eq!(device.i2c_read_reg_be16(0x48, 0x00).unwrap(), 0x1234);
+        assert_eq!(device.i2c_read_reg_le16(0x48, 0x00).unwrap(), 0x3412);
+
+        assert!(device.i2c_update_reg8(0x48, 0x02, 0x0F, 0x05).unwrap());
+        assert_eq!(bus.register(0x48, 0x02), 0x05);
+        // Nothing changes, so only the read goes out
+        let sent = bus.requests().len();
+        assert!(!device.i2c_update_reg8(0x48, 0x02, 0x0F, 0x05).unwrap());
+        assert_eq!(bus.requests().len(), sent + 1);
+
+        assert!(matches!(
+            device.i2c_read_reg_be16(0x50, 0x00),
+            Err(Error::I2cNack { .. })
+        ));
+        assert_eq!(device.i2c_scan(0x40, 0x4F).unwrap(), vec![0x48]);
+    }
+
+    #[test]
+    fn test_multi_byte_register_writes() {
+        let bus = MockI2cTransport::default().with_target(0x48, &[]);
+        let device = Xr2280x::new_for_test(8).with_i2c_transport(bus.clone());
+        let registers = |start: u8, len: u8| -> Vec<u8> {
+            (start..start + len)
+                .map(|reg| bus.register(0x48, reg))
+                .collect()
+        };
+
+        device.i2c_write_reg_be16(0x48, 0x01, 0x60A0).unwrap();
+        assert_eq!(registers(0x01, 2), [0x60, 0xA0]);
+        device.i2c_write_reg_le16(0x48, 0x01, 0x60A0).unwrap();
+        assert_eq!(registers(0x01, 2), [0xA0, 0x60]);
+        assert_eq!(device.i2c_read_reg_le16(0x48, 0x01).unwrap(), 0x60A0);
+
+        device.i2c_write_reg_be32(0x48, 0x10, 0x1234_5678).unwrap();
+        assert_eq!(registers(0x10, 4), [0x12, 0x34, 0x56, 0x78]);
+        device.i2c_write_reg_le32(0x48, 0x10, 0x1234_5678).unwrap();
+        assert_eq!(registers(0x10, 4), [0x78, 0x56, 0x34, 0x12]);
+
+        // Register address and value go out together in one write
+        let sent = bus.requests().len();
+        device.i2c_write_reg_be32(0x48, 0x20, 0xDEAD_BEEF).unwrap();
+        let requests = bus.requests();
+        assert_eq!(requests.len(), sent + 1);
+        let report = &requests[sent];
+        assert_eq!(report[request_offsets::WRITE_LENGTH], 5);
+        assert_eq!(
+            report[request_offsets::WRITE_DATA_START..][..5],
+            [0x20, 0xDE, 0xAD, 0xBE, 0xEF]
+        );
+
+        // Every register the value lands in drops its cached value
+        let handle = device.i2c_device_7bit(0x48).unwrap();
+        for reg in [0x30, 0x33] {
+            handle.cache_reg8(reg, Duration::from_secs(60));
+            assert_eq!(handle.read_reg8_cached(reg).unwrap(), 0x00);
         }
+        device.i2c_write_reg_le32(0x48, 0x30, 0x4433_2211).unwrap();
+        assert_eq!(handle.read_reg8_cached(0x30).unwrap(), 0x11);
+        assert_eq!(handle.read_reg8_cached(0x33).unwrap(), 0x44);
 
-        Ok(())
+        assert!(matches!(
+            device.i2c_write_reg_be16(0x80, 0x01, 0),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
     }
 }
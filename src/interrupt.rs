@@ -1,13 +1,31 @@
 //! GPIO interrupt functionality for XR2280x devices.
+//!
+//! ## Acknowledging Interrupts
+//!
+//! There is no interrupt status or acknowledge register to clear: the EDGE register
+//! map (0x03C0-0x03DD) only holds the per-pin interrupt mask and edge selection.
+//! Each detected edge is delivered as a HID input report on the EDGE interface and
+//! queued by the operating system's HID driver. Reading a report with
+//! [`Xr2280x::read_gpio_interrupt_report`] consumes it, so acknowledgement is
+//! effectively read-to-clear and nothing needs to be written after handling it.
+//!
+//! The OS queue is bounded (for example, Linux hidraw keeps 64 reports per open
+//! handle), so edges are only lost if reports are not read promptly. Keep a thread
+//! reading reports instead of clearing anything between edges.
 
 use crate::consts;
 use crate::device::Xr2280x;
-use crate::error::{Error, Result};
-use crate::gpio::{GpioEdge, GpioPin};
+use crate::error::{Error, Result, hid_error};
+use crate::gpio::{GpioEdge, GpioGroup, GpioLevel, GpioPin, GpioPull, group_register_address};
 use log::{debug, trace, warn};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
 /// Default timeout for interrupt reads in milliseconds.
 const DEFAULT_INTERRUPT_TIMEOUT_MS: i32 = 1000;
+// Read buffer for interrupt reports; the largest high-speed interrupt packet. A read
+// that fills it completely may have been truncated by the OS.
+const INTERRUPT_REPORT_BUF_SIZE: usize = 1024;
 
 // HID Report Structure Constants - GPIO Interrupt Parsing
 // These constants define the structure of GPIO interrupt HID reports to eliminate magic numbers
@@ -119,6 +137,106 @@ pub struct ParsedGpioInterruptReport {
     pub current_state_group1: u16,
 }
 
+/// A single pin event decoded from a GPIO interrupt report.
+///
+/// Built by [`Xr2280x::parse_gpio_interrupt_events`]. The edge and level come from
+/// the same speculative report decoding as [`Xr2280x::parse_gpio_interrupt_pins`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpioEvent {
+    /// Pin that triggered.
+    pub pin: GpioPin,
+    /// Edge inferred from the reported level.
+    pub edge: GpioEdge,
+    /// Level of the pin reported alongside the trigger.
+    pub level: GpioLevel,
+    /// When the report containing this event was received.
+    pub timestamp: Instant,
+}
+
+/// Interrupt monitoring of a set of pins, created by [`Xr2280x::monitor_interrupts`].
+///
+/// The monitor borrows the device and reads interrupt reports on the calling thread
+/// when [`next_event`](Self::next_event) is called; events from one report that are
+/// not returned yet are buffered for the following calls. Only events of the
+/// monitored pins are returned.
+///
+/// Dropping the monitor (or calling [`stop`](Self::stop)) disables the interrupts
+/// first and then restores the interrupt mask and edge selection the monitored pins
+/// had before, leaving other pins' bits as they are at that point.
+#[derive(Debug)]
+#[must_use = "interrupts are restored as soon as the monitor is dropped"]
+pub struct InterruptMonitor<'a> {
+    device: &'a Xr2280x,
+    /// Monitored pins per group.
+    pins: [u16; 2],
+    saved: Vec<SavedInterruptRegister>,
+    pending: VecDeque<GpioEvent>,
+    restored: bool,
+}
+
+impl InterruptMonitor<'_> {
+    /// Returns the next event of a monitored pin, waiting up to `timeout`.
+    ///
+    /// Returns `Ok(None)` if no event arrived in time. A `timeout` too long to represent
+    /// as a deadline, such as [`Duration::MAX`], waits without limit. Reports are
+    /// decoded with [`Xr2280x::parse_gpio_interrupt_events`], so the same caveats
+    /// about the speculative report format apply.
+    pub fn next_event(&mut self, timeout: Duration) -> Result<Option<GpioEvent>> {
+        // `None` when the deadline overflows: wait without limit
+        let deadline = Instant::now().checked_add(timeout);
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+            let remaining = deadline.map_or(Duration::MAX, |end| {
+                end.saturating_duration_since(Instant::now())
+            });
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            let timeout_ms = remaining.as_millis().clamp(1, i32::MAX as u128) as i32;
+            let report = self.device.read_gpio_interrupt_report(Some(timeout_ms))?;
+            if report.raw_data.is_empty() {
+                return Ok(None);
+            }
+            let events = self
+                .device
+                .parse_gpio_interrupt_events(&report, Instant::now())?;
+            let pins = self.pins;
+            self.pending.extend(
+                events
+                    .into_iter()
+                    .filter(|event| pins[event.pin.group_index() as usize] & event.pin.mask() != 0),
+            );
+        }
+    }
+
+    /// Disables the interrupts and restores the previous configuration, returning the
+    /// first error.
+    ///
+    /// Dropping the monitor does the same but can only log errors.
+    pub fn stop(mut self) -> Result<()> {
+        self.restore()
+    }
+
+    fn restore(&mut self) -> Result<()> {
+        if self.restored {
+            return Ok(());
+        }
+        self.restored = true;
+        debug!("Restoring interrupt configuration of monitored pins");
+        self.device.restore_interrupt_registers(&self.saved)
+    }
+}
+
+impl Drop for InterruptMonitor<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.restore() {
+            warn!("Failed to restore interrupt configuration: {e}");
+        }
+    }
+}
+
 impl Xr2280x {
     // --- GPIO Interrupt Handling ---
     /// Configures interrupt settings for a GPIO pin (enable, edge selection).
@@ -133,17 +251,9 @@ impl Xr2280x {
         // Check support
         self.check_gpio_pin_support(pin)?;
 
-        let (reg_mask, reg_pos, reg_neg) = match pin.group_index() {
-            0 => (
-                consts::edge::REG_INTR_MASK_0,
-                consts::edge::REG_INTR_POS_EDGE_0,
-                consts::edge::REG_INTR_NEG_EDGE_0,
-            ),
-            _ => (
-                consts::edge::REG_INTR_MASK_1,
-                consts::edge::REG_INTR_POS_EDGE_1,
-                consts::edge::REG_INTR_NEG_EDGE_1,
-            ),
+        let reg_mask = match pin.group_index() {
+            0 => consts::edge::REG_INTR_MASK_0,
+            _ => consts::edge::REG_INTR_MASK_1,
         };
 
         debug!(
@@ -160,54 +270,144 @@ impl Xr2280x {
             true => mask_val | pin.mask(),
             false => mask_val & !pin.mask(),
         };
-        self.write_hid_register(reg_mask, new_mask)?;
+        self.write_hid_register_verified(reg_mask, new_mask, pin.mask())?;
 
         // Set edge detection if enabling
         if enable {
-            // Positive edge
-            let pos_val = self.read_hid_register(reg_pos)?;
-            let new_pos = match positive_edge {
-                true => pos_val | pin.mask(),
-                false => pos_val & !pin.mask(),
-            };
-            self.write_hid_register(reg_pos, new_pos)?;
-
-            // Negative edge
-            let neg_val = self.read_hid_register(reg_neg)?;
-            let new_neg = match negative_edge {
-                true => neg_val | pin.mask(),
-                false => neg_val & !pin.mask(),
-            };
-            self.write_hid_register(reg_neg, new_neg)?;
+            self.write_interrupt_edges(pin, positive_edge, negative_edge)?;
         }
 
         Ok(())
     }
 
+    /// Selects which edges trigger an interrupt for a GPIO pin, without enabling or disabling it.
+    ///
+    /// Only the positive and negative edge registers are written; the interrupt
+    /// mask (enable) register is left untouched. Use
+    /// [`gpio_configure_interrupt`](Self::gpio_configure_interrupt) to enable the interrupt.
+    pub fn gpio_set_interrupt_edges(
+        &self,
+        pin: GpioPin,
+        positive_edge: bool,
+        negative_edge: bool,
+    ) -> Result<()> {
+        self.check_gpio_pin_support(pin)?;
+        debug!(
+            "Setting interrupt edges for pin {}: pos_edge={}, neg_edge={}",
+            pin.number(),
+            positive_edge,
+            negative_edge
+        );
+        self.write_interrupt_edges(pin, positive_edge, negative_edge)
+    }
+
+    /// Configures a bank of interrupt-driven inputs in one call.
+    ///
+    /// All pins become inputs with the given pull (see
+    /// [`gpio_setup_inputs`](Self::gpio_setup_inputs)), then each group's edge
+    /// selection and interrupt mask registers are updated once for all of its pins:
+    /// the edges are written before the interrupts are enabled, so no interrupt fires
+    /// with a stale edge selection. Other pins' bits are preserved. If a pin is listed
+    /// more than once, its last edge wins.
+    ///
+    /// **Performance**: Uses 6 HID transactions for direction and pull plus 3
+    /// read-modify-write cycles of verified writes (9 transactions) for the
+    /// interrupt registers, per group with listed pins — independent of the number of
+    /// pins. Configuring pins one at a time costs around 15 transactions per pin.
+    pub fn gpio_setup_inputs_with_interrupts(
+        &self,
+        pins: &[(GpioPin, GpioEdge)],
+        pull: GpioPull,
+    ) -> Result<()> {
+        let input_pins: Vec<GpioPin> = pins.iter().map(|(pin, _)| *pin).collect();
+        self.gpio_setup_inputs(&input_pins, pull)?;
+        self.configure_interrupt_banks(pins)?;
+        debug!("Configured {} interrupt-driven input(s)", pins.len());
+        Ok(())
+    }
+
+    /// Enables interrupts for `pins` and returns an [`InterruptMonitor`] that reads
+    /// their events and restores the previous interrupt configuration when dropped.
+    ///
+    /// The interrupt mask and edge registers of each group with listed pins are saved,
+    /// then configured as by [`Xr2280x::gpio_setup_inputs_with_interrupts`]. Direction
+    /// and pulls are left unchanged, so set the pins up as inputs first. If configuring
+    /// fails, the saved configuration is restored before the error is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use xr2280x_hid::*;
+    /// # use std::time::Duration;
+    /// # fn example(device: &Xr2280x) -> Result<()> {
+    /// let button = GpioPin::new(3)?;
+    /// device.gpio_setup_input(button, GpioPull::Up)?;
+    /// let mut monitor = device.monitor_interrupts(&[(button, GpioEdge::Falling)])?;
+    /// while let Some(event) = monitor.next_event(Duration::from_secs(5))? {
+    ///     println!("Pin {} {:?} at {:?}", event.pin.number(), event.edge, event.timestamp);
+    /// }
+    /// // Dropping the monitor disables the interrupts again
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn monitor_interrupts(&self, pins: &[(GpioPin, GpioEdge)]) -> Result<InterruptMonitor<'_>> {
+        for &(pin, _) in pins {
+            self.check_gpio_pin_support(pin)?;
+        }
+        let saved = self.save_interrupt_registers(pins)?;
+        let mut monitor = InterruptMonitor {
+            device: self,
+            pins: [0; 2],
+            saved,
+            pending: VecDeque::new(),
+            restored: false,
+        };
+        for &(pin, _) in pins {
+            monitor.pins[pin.group_index() as usize] |= pin.mask();
+        }
+        // On error the monitor is dropped here, restoring the saved registers
+        self.configure_interrupt_banks(pins)?;
+        debug!("Monitoring interrupts of {} pin(s)", pins.len());
+        Ok(monitor)
+    }
+
+    /// Gets the interrupt edge selection of a GPIO pin as `(positive_edge, negative_edge)`.
+    pub fn gpio_get_interrupt_edges(&self, pin: GpioPin) -> Result<(bool, bool)> {
+        self.check_gpio_pin_support(pin)?;
+        let (reg_pos, reg_neg) = interrupt_edge_registers(pin);
+        let positive_edge = self.read_hid_register(reg_pos)? & pin.mask() != 0;
+        let negative_edge = self.read_hid_register(reg_neg)? & pin.mask() != 0;
+        trace!(
+            "Pin {} interrupt edges: pos_edge={}, neg_edge={}",
+            pin.number(),
+            positive_edge,
+            negative_edge
+        );
+        Ok((positive_edge, negative_edge))
+    }
+
     /// Reads a GPIO interrupt report with an optional timeout.
     /// Returns the raw interrupt data when an interrupt occurs.
     /// **Note:** The format of this data is currently unknown/undocumented.
+    ///
+    /// Reports are read into a 1024-byte buffer. A report that fills the buffer
+    /// completely may have been truncated by the OS, so it is rejected with
+    /// [`Error::InvalidReport`] instead of being returned incomplete.
     pub fn read_gpio_interrupt_report(
         &self,
         timeout_ms: Option<i32>,
     ) -> Result<GpioInterruptReport> {
         let timeout = timeout_ms.unwrap_or(DEFAULT_INTERRUPT_TIMEOUT_MS);
-        let mut buffer = vec![0u8; 64]; // Adjust size as needed
 
         debug!("Reading GPIO interrupt report with timeout {timeout}ms");
         let edge_device = self.edge_device.as_ref().ok_or(Error::DeviceNotFound)?;
-        match edge_device.read_timeout(&mut buffer, timeout) {
-            Ok(size) => {
-                trace!("Received interrupt report: {:02X?}", &buffer[..size]);
-                Ok(GpioInterruptReport {
-                    raw_data: buffer[..size].to_vec(),
-                })
-            }
-            Err(e) => {
+        let mut buffer = vec![0u8; INTERRUPT_REPORT_BUF_SIZE];
+        let size = edge_device
+            .read_timeout(&mut buffer, timeout)
+            .map_err(|e| {
                 warn!("Failed to read interrupt report: {e}");
-                Err(Error::Hid(e))
-            }
-        }
+                hid_error(e)
+            })?;
+        interrupt_report(buffer, size)
     }
 
     /// # UNSAFE: Attempts to parse a raw GPIO interrupt report with UNVERIFIED format assumptions
@@ -313,22 +513,6 @@ impl Xr2280x {
         &self,
         report: &GpioInterruptReport,
     ) -> Result<ParsedGpioInterruptReport> {
-        // Comprehensive input validation with detailed error messages
-        if report.raw_data.is_empty() {
-            return Err(Error::InterruptParseError(
-                "Interrupt report is empty - no data to parse".to_string(),
-            ));
-        }
-
-        if report.raw_data.len() < report_offsets::MIN_REPORT_SIZE {
-            return Err(Error::InterruptParseError(format!(
-                "Interrupt report too small: got {} bytes, need at least {} bytes (Report ID + 4 state bytes). \
-                    This may indicate an incompatible hardware report format.",
-                report.raw_data.len(),
-                report_offsets::MIN_REPORT_SIZE
-            )));
-        }
-
         // Log the raw data for debugging/verification purposes
         debug!(
             "⚠️  UNSAFE: Parsing GPIO interrupt report with UNVERIFIED format assumptions. \
@@ -337,49 +521,20 @@ impl Xr2280x {
             report.raw_data
         );
 
-        // UNSAFE ASSUMPTION: First 4 bytes after Report ID are GPIO states (2 bytes per group)
-        // WARNING: This assumption is NOT verified against hardware documentation
-        let current_state_group0 = u16::from_le_bytes([
-            report.raw_data[report_offsets::GROUP0_STATE_LOW],
-            report.raw_data[report_offsets::GROUP0_STATE_HIGH],
-        ]);
-        let current_state_group1 = u16::from_le_bytes([
-            report.raw_data[report_offsets::GROUP1_STATE_LOW],
-            report.raw_data[report_offsets::GROUP1_STATE_HIGH],
-        ]);
-
-        // UNSAFE ASSUMPTION: Additional bytes might contain trigger masks
-        // WARNING: This is pure speculation based on common patterns
-        let (trigger_mask_group0, trigger_mask_group1) = if report.raw_data.len()
-            >= report_offsets::MIN_REPORT_WITH_TRIGGERS
-        {
-            // Additional bounds checking for trigger mask data
-            if report.raw_data.len() < report_offsets::MIN_REPORT_WITH_TRIGGERS {
-                return Err(Error::InterruptParseError(format!(
-                    "Interrupt report claims trigger data but insufficient bytes: got {} bytes, need {}",
-                    report.raw_data.len(),
-                    report_offsets::MIN_REPORT_WITH_TRIGGERS
-                )));
-            }
-
-            (
-                u16::from_le_bytes([
-                    report.raw_data[report_offsets::GROUP0_TRIGGER_LOW],
-                    report.raw_data[report_offsets::GROUP0_TRIGGER_HIGH],
-                ]),
-                u16::from_le_bytes([
-                    report.raw_data[report_offsets::GROUP1_TRIGGER_LOW],
-                    report.raw_data[report_offsets::GROUP1_TRIGGER_HIGH],
-                ]),
-            )
-        } else {
+        let parsed = parse_report_bytes(&report.raw_data)?;
+        let ParsedGpioInterruptReport {
+            trigger_mask_group0,
+            trigger_mask_group1,
+            current_state_group0,
+            current_state_group1,
+        } = parsed;
+        if report.raw_data.len() < report_offsets::MIN_REPORT_WITH_TRIGGERS {
             warn!(
                 "GPIO interrupt report only {} bytes - no trigger mask data available. \
                 Setting trigger masks to 0.",
                 report.raw_data.len()
             );
-            (0, 0) // No trigger info available
-        };
+        }
 
         // Log parsed values for verification
         debug!(
@@ -392,12 +547,7 @@ impl Xr2280x {
             Application MUST validate these values against known hardware state."
         );
 
-        Ok(ParsedGpioInterruptReport {
-            trigger_mask_group0,
-            trigger_mask_group1,
-            current_state_group0,
-            current_state_group1,
-        })
+        Ok(parsed)
     }
 
     /// **SAFE**: Get raw GPIO interrupt report data without parsing assumptions.
@@ -518,56 +668,638 @@ impl Xr2280x {
     ) -> Result<Vec<(GpioPin, GpioEdge)>> {
         // Use existing parsing function to get raw data
         let parsed = unsafe { self.parse_gpio_interrupt_report(report)? };
+        let pin_events: Vec<_> = triggered_pins(&parsed)?
+            .into_iter()
+            .map(|(pin, edge, _)| (pin, edge))
+            .collect();
 
-        let mut pin_events = Vec::new();
+        debug!(
+            "Parsed {} GPIO interrupt events from report",
+            pin_events.len()
+        );
 
-        // Process Group 0 (pins 0-15)
-        let group0_triggers = parsed.trigger_mask_group0;
-        let group0_states = parsed.current_state_group0;
+        Ok(pin_events)
+    }
 
-        for bit_pos in 0..16 {
-            if group0_triggers & (1 << bit_pos) != 0 {
-                let pin_num = bit_pos;
-                let pin = GpioPin::new(pin_num)?;
-
-                // Determine edge type based on current state
-                let is_high = group0_states & (1 << bit_pos) != 0;
-                let edge = if is_high {
-                    GpioEdge::Rising
-                } else {
-                    GpioEdge::Falling
-                };
-
-                pin_events.push((pin, edge));
+    /// Parses a GPIO interrupt report into [`GpioEvent`]s stamped with `received_at`.
+    ///
+    /// Same decoding as [`Xr2280x::parse_gpio_interrupt_pins`], but each event also
+    /// carries the level reported for its pin and the time the report was received,
+    /// so events from successive reads can be ordered and their spacing measured.
+    /// Capture `received_at` right after [`Xr2280x::read_gpio_interrupt_report`]
+    /// returns; every event from one report shares it.
+    ///
+    /// ```rust,no_run
+    /// # use xr2280x_hid::*;
+    /// # fn example(device: &Xr2280x) -> Result<()> {
+    /// let report = device.read_gpio_interrupt_report(Some(1000))?;
+    /// let received_at = std::time::Instant::now();
+    /// for event in device.parse_gpio_interrupt_events(&report, received_at)? {
+    ///     println!("Pin {} {:?} -> {:?}", event.pin.number(), event.edge, event.level);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// **Note**: The underlying interrupt report parsing is still speculative; see
+    /// [`ParsedGpioInterruptReport`].
+    pub fn parse_gpio_interrupt_events(
+        &self,
+        report: &GpioInterruptReport,
+        received_at: Instant,
+    ) -> Result<Vec<GpioEvent>> {
+        let parsed = unsafe { self.parse_gpio_interrupt_report(report)? };
+        let events: Vec<_> = triggered_pins(&parsed)?
+            .into_iter()
+            .map(|(pin, edge, level)| GpioEvent {
+                pin,
+                edge,
+                level,
+                timestamp: received_at,
+            })
+            .collect();
+        debug!("Parsed {} GPIO interrupt events from report", events.len());
+        Ok(events)
+    }
+
+    /// Read-modify-writes the edge registers for `pin`.
+    fn write_interrupt_edges(
+        &self,
+        pin: GpioPin,
+        positive_edge: bool,
+        negative_edge: bool,
+    ) -> Result<()> {
+        let (reg_pos, reg_neg) = interrupt_edge_registers(pin);
+        for (reg, enable) in [(reg_pos, positive_edge), (reg_neg, negative_edge)] {
+            let current = self.read_hid_register(reg)?;
+            let new_value = match enable {
+                true => current | pin.mask(),
+                false => current & !pin.mask(),
+            };
+            self.write_hid_register_verified(reg, new_value, pin.mask())?;
+        }
+        Ok(())
+    }
+
+    /// Read-modify-writes the positive edge, negative edge and mask registers once per
+    /// group that has pins in `pins`, verifying the changed bits.
+    fn configure_interrupt_banks(&self, pins: &[(GpioPin, GpioEdge)]) -> Result<()> {
+        let mut banks = [InterruptBank::default(); 2];
+        for &(pin, edge) in pins {
+            let bank = &mut banks[pin.group_index() as usize];
+            let bit = pin.mask();
+            bank.mask |= bit;
+            bank.positive &= !bit;
+            bank.negative &= !bit;
+            if matches!(edge, GpioEdge::Rising | GpioEdge::Both) {
+                bank.positive |= bit;
+            }
+            if matches!(edge, GpioEdge::Falling | GpioEdge::Both) {
+                bank.negative |= bit;
+            }
+        }
+
+        for (group, bank) in [GpioGroup::Group0, GpioGroup::Group1]
+            .into_iter()
+            .zip(banks)
+        {
+            if bank.mask == 0 {
+                continue;
+            }
+            // Edges first, then enable
+            for (base, bits) in [
+                (consts::edge::REG_INTR_POS_EDGE_0, bank.positive),
+                (consts::edge::REG_INTR_NEG_EDGE_0, bank.negative),
+                (consts::edge::REG_INTR_MASK_0, bank.mask),
+            ] {
+                let reg = group_register_address(group, base);
+                let current = self.read_hid_register(reg)?;
+                self.write_hid_register_verified(reg, (current & !bank.mask) | bits, bank.mask)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the mask, positive edge and negative edge registers of every group with
+    /// pins in `pins`, mask register first.
+    fn save_interrupt_registers(
+        &self,
+        pins: &[(GpioPin, GpioEdge)],
+    ) -> Result<Vec<SavedInterruptRegister>> {
+        let mut masks = [0u16; 2];
+        for &(pin, _) in pins {
+            masks[pin.group_index() as usize] |= pin.mask();
+        }
+        let mut saved = Vec::new();
+        for (group, mask) in [GpioGroup::Group0, GpioGroup::Group1]
+            .into_iter()
+            .zip(masks)
+        {
+            if mask == 0 {
+                continue;
+            }
+            for base in [
+                consts::edge::REG_INTR_MASK_0,
+                consts::edge::REG_INTR_POS_EDGE_0,
+                consts::edge::REG_INTR_NEG_EDGE_0,
+            ] {
+                let register = group_register_address(group, base);
+                saved.push(SavedInterruptRegister {
+                    register,
+                    mask,
+                    value: self.read_hid_register(register)?,
+                });
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Writes the saved bits back in the order they were saved, so interrupts are
+    /// disabled before their edges change. Every register is attempted; the first error
+    /// is returned.
+    fn restore_interrupt_registers(&self, saved: &[SavedInterruptRegister]) -> Result<()> {
+        let mut first_error = None;
+        for entry in saved {
+            let result = self.read_hid_register(entry.register).and_then(|current| {
+                let value = (current & !entry.mask) | (entry.value & entry.mask);
+                self.write_hid_register_verified(entry.register, value, entry.mask)
+            });
+            if let Err(e) = result {
+                warn!(
+                    "Failed to restore interrupt register 0x{:04X}: {e}",
+                    entry.register
+                );
+                first_error.get_or_insert(e);
             }
         }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+/// Parses raw GPIO interrupt report bytes into [`GpioEvent`]s without a device.
+///
+/// `bytes` has the layout of [`GpioInterruptReport::raw_data`], starting with the HID
+/// Report ID, and is decoded like [`Xr2280x::parse_gpio_interrupt_events`]. Every event
+/// is stamped with the time of this call.
+///
+/// Any byte sequence is handled without panicking: reports shorter than the Report ID
+/// plus the two state words, or at least as long as the interrupt read buffer (which a
+/// real read never returns), are rejected with [`Error::InterruptParseError`]. Bytes
+/// beyond the trigger masks are ignored. This makes it suitable for fuzzing and for
+/// decoding reports captured elsewhere.
+///
+/// ```
+/// use xr2280x_hid::interrupt::try_parse_interrupt;
+///
+/// // Report ID, group 0 state, group 1 state, group 0 triggers, group 1 triggers
+/// let events = try_parse_interrupt(&[0x01, 0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00])?;
+/// assert_eq!(events.len(), 2);
+///
+/// assert!(try_parse_interrupt(&[0x01, 0x01]).is_err());
+/// # Ok::<(), xr2280x_hid::Error>(())
+/// ```
+///
+/// **Note**: The report decoding is still speculative; see [`ParsedGpioInterruptReport`].
+pub fn try_parse_interrupt(bytes: &[u8]) -> Result<Vec<GpioEvent>> {
+    let received_at = Instant::now();
+    if bytes.len() >= INTERRUPT_REPORT_BUF_SIZE {
+        return Err(Error::InterruptParseError(format!(
+            "Interrupt report too large: got {} bytes, a report read is at most {} bytes",
+            bytes.len(),
+            INTERRUPT_REPORT_BUF_SIZE - 1
+        )));
+    }
+    let parsed = parse_report_bytes(bytes)?;
+    Ok(triggered_pins(&parsed)?
+        .into_iter()
+        .map(|(pin, edge, level)| GpioEvent {
+            pin,
+            edge,
+            level,
+            timestamp: received_at,
+        })
+        .collect())
+}
+
+/// Decodes the speculative interrupt report layout (see [`report_offsets`]) with every
+/// access bounds-checked. The trigger masks are 0 if the report is too short for them.
+fn parse_report_bytes(bytes: &[u8]) -> Result<ParsedGpioInterruptReport> {
+    if bytes.is_empty() {
+        return Err(Error::InterruptParseError(
+            "Interrupt report is empty - no data to parse".to_string(),
+        ));
+    }
+    if bytes.len() < report_offsets::MIN_REPORT_SIZE {
+        return Err(Error::InterruptParseError(format!(
+            "Interrupt report too small: got {} bytes, need at least {} bytes (Report ID + 4 state bytes). \
+                This may indicate an incompatible hardware report format.",
+            bytes.len(),
+            report_offsets::MIN_REPORT_SIZE
+        )));
+    }
+
+    let word_at = |low_offset: usize| {
+        bytes
+            .get(low_offset..low_offset + 2)
+            .map(|word| u16::from_le_bytes([word[0], word[1]]))
+            .ok_or_else(|| {
+                Error::InterruptParseError(format!(
+                    "Interrupt report has no word at offset {low_offset}: got {} bytes",
+                    bytes.len()
+                ))
+            })
+    };
+
+    // UNSAFE ASSUMPTION: First 4 bytes after Report ID are GPIO states (2 bytes per group)
+    // WARNING: This assumption is NOT verified against hardware documentation
+    let current_state_group0 = word_at(report_offsets::GROUP0_STATE_LOW)?;
+    let current_state_group1 = word_at(report_offsets::GROUP1_STATE_LOW)?;
+
+    // UNSAFE ASSUMPTION: Additional bytes might contain trigger masks
+    // WARNING: This is pure speculation based on common patterns
+    let (trigger_mask_group0, trigger_mask_group1) =
+        if bytes.len() >= report_offsets::MIN_REPORT_WITH_TRIGGERS {
+            (
+                word_at(report_offsets::GROUP0_TRIGGER_LOW)?,
+                word_at(report_offsets::GROUP1_TRIGGER_LOW)?,
+            )
+        } else {
+            (0, 0) // No trigger info available
+        };
 
-        // Process Group 1 (pins 16-31)
-        let group1_triggers = parsed.trigger_mask_group1;
-        let group1_states = parsed.current_state_group1;
+    Ok(ParsedGpioInterruptReport {
+        trigger_mask_group0,
+        trigger_mask_group1,
+        current_state_group0,
+        current_state_group1,
+    })
+}
 
+/// Pins flagged in the trigger masks of `parsed`, with the edge implied by their
+/// reported level (high means rising, low means falling).
+fn triggered_pins(
+    parsed: &ParsedGpioInterruptReport,
+) -> Result<Vec<(GpioPin, GpioEdge, GpioLevel)>> {
+    let groups = [
+        (0, parsed.trigger_mask_group0, parsed.current_state_group0),
+        (16, parsed.trigger_mask_group1, parsed.current_state_group1),
+    ];
+    let mut pins = Vec::new();
+    for (first_pin, triggers, states) in groups {
         for bit_pos in 0..16 {
-            if group1_triggers & (1 << bit_pos) != 0 {
-                let pin_num = bit_pos + 16;
-                let pin = GpioPin::new(pin_num)?;
-
-                // Determine edge type based on current state
-                let is_high = group1_states & (1 << bit_pos) != 0;
-                let edge = if is_high {
-                    GpioEdge::Rising
-                } else {
-                    GpioEdge::Falling
-                };
-
-                pin_events.push((pin, edge));
+            if triggers & (1 << bit_pos) == 0 {
+                continue;
             }
+            let pin = GpioPin::new(first_pin + bit_pos)?;
+            let level = GpioLevel::from(states & (1 << bit_pos) != 0);
+            let edge = match level {
+                GpioLevel::High => GpioEdge::Rising,
+                GpioLevel::Low => GpioEdge::Falling,
+            };
+            pins.push((pin, edge, level));
         }
+    }
+    Ok(pins)
+}
 
-        debug!(
-            "Parsed {} GPIO interrupt events from report",
-            pin_events.len()
+/// Positive and negative edge register addresses for the group containing `pin`.
+fn interrupt_edge_registers(pin: GpioPin) -> (u16, u16) {
+    match pin.group_index() {
+        0 => (
+            consts::edge::REG_INTR_POS_EDGE_0,
+            consts::edge::REG_INTR_NEG_EDGE_0,
+        ),
+        _ => (
+            consts::edge::REG_INTR_POS_EDGE_1,
+            consts::edge::REG_INTR_NEG_EDGE_1,
+        ),
+    }
+}
+
+/// Interrupt register bits for the listed pins of one group.
+#[derive(Debug, Default, Clone, Copy)]
+struct InterruptBank {
+    mask: u16,
+    positive: u16,
+    negative: u16,
+}
+
+/// Saved bits of one interrupt register, restored by
+/// [`Xr2280x::restore_interrupt_registers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SavedInterruptRegister {
+    register: u16,
+    /// The bits that belong to the monitored pins.
+    mask: u16,
+    value: u16,
+}
+
+/// Trims an interrupt report read into `buffer` to its `size`, rejecting reads that
+/// fill the whole buffer.
+fn interrupt_report(mut buffer: Vec<u8>, size: usize) -> Result<GpioInterruptReport> {
+    if size >= buffer.len() {
+        warn!(
+            "Interrupt report filled the whole {}-byte buffer and may be truncated",
+            buffer.len()
         );
+        return Err(Error::InvalidReport(size));
+    }
+    trace!("Received interrupt report: {:02X?}", &buffer[..size]);
+    buffer.truncate(size);
+    Ok(GpioInterruptReport { raw_data: buffer })
+}
 
-        Ok(pin_events)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::device::MockRegisterFile;
+
+    /// The writes made to the interrupt mask and edge registers of both groups.
+    fn interrupt_register_writes(registers: &MockRegisterFile) -> Vec<(u16, u16)> {
+        use consts::edge::*;
+        let interrupt_registers = [
+            REG_INTR_MASK_0,
+            REG_INTR_POS_EDGE_0,
+            REG_INTR_NEG_EDGE_0,
+            REG_INTR_MASK_1,
+            REG_INTR_POS_EDGE_1,
+            REG_INTR_NEG_EDGE_1,
+        ];
+        registers
+            .writes()
+            .into_iter()
+            .filter(|(reg, _)| interrupt_registers.contains(reg))
+            .collect()
+    }
+
+    #[test]
+    fn test_interrupt_report_filling_buffer_is_rejected() {
+        let buffer = vec![0xAB; INTERRUPT_REPORT_BUF_SIZE];
+        let result = interrupt_report(buffer, INTERRUPT_REPORT_BUF_SIZE);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidReport(INTERRUPT_REPORT_BUF_SIZE))
+        ));
+    }
+
+    #[test]
+    fn test_interrupt_report_is_trimmed_to_read_size() {
+        let mut buffer = vec![0u8; INTERRUPT_REPORT_BUF_SIZE];
+        buffer[..5].copy_from_slice(&[0x01, 0x34, 0x12, 0x00, 0x00]);
+        let report = interrupt_report(buffer, 5).unwrap();
+        assert_eq!(report.raw_data, vec![0x01, 0x34, 0x12, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_set_interrupt_edges_leaves_mask_untouched() {
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_INTR_MASK_1, 0x00F0)
+            .with_register(consts::edge::REG_INTR_POS_EDGE_1, 0x0101)
+            .with_register(consts::edge::REG_INTR_NEG_EDGE_1, 0x0008);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let pin = GpioPin::new(19).unwrap(); // Group 1, bit 3
+
+        device.gpio_set_interrupt_edges(pin, true, false).unwrap();
+
+        assert!(
+            !registers
+                .writes()
+                .iter()
+                .any(|&(reg, _)| reg == consts::edge::REG_INTR_MASK_1)
+        );
+        assert_eq!(registers.register(consts::edge::REG_INTR_MASK_1), 0x00F0);
+        assert_eq!(
+            registers.register(consts::edge::REG_INTR_POS_EDGE_1),
+            0x0109
+        );
+        assert_eq!(
+            registers.register(consts::edge::REG_INTR_NEG_EDGE_1),
+            0x0000
+        );
+    }
+
+    #[test]
+    fn test_interrupt_banks_accumulate_per_group() {
+        use consts::edge::*;
+        let registers = MockRegisterFile::default()
+            .with_register(REG_INTR_MASK_0, 0x8000)
+            .with_register(REG_INTR_POS_EDGE_0, 0x8024)
+            .with_register(REG_INTR_NEG_EDGE_0, 0x0004)
+            .with_register(REG_INTR_NEG_EDGE_1, 0x0001);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let pin = |n| GpioPin::new(n).unwrap();
+
+        device
+            .gpio_setup_inputs_with_interrupts(
+                &[
+                    (pin(2), GpioEdge::Rising),
+                    (pin(5), GpioEdge::Falling),
+                    (pin(18), GpioEdge::Both),
+                    (pin(2), GpioEdge::Falling), // Last edge wins
+                ],
+                GpioPull::None,
+            )
+            .unwrap();
+
+        // One write per register per group, enable last
+        assert_eq!(
+            interrupt_register_writes(&registers),
+            vec![
+                (REG_INTR_POS_EDGE_0, 0x8000),
+                (REG_INTR_NEG_EDGE_0, 0x0024),
+                (REG_INTR_MASK_0, 0x8024),
+                (REG_INTR_POS_EDGE_1, 0x0004),
+                (REG_INTR_NEG_EDGE_1, 0x0005),
+                (REG_INTR_MASK_1, 0x0004),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interrupt_banks_skip_unused_group() {
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device
+            .gpio_setup_inputs_with_interrupts(
+                &[(GpioPin::new(3).unwrap(), GpioEdge::Rising)],
+                GpioPull::None,
+            )
+            .unwrap();
+        let written = interrupt_register_writes(&registers);
+        assert_eq!(written.len(), 3);
+        assert!(
+            written
+                .iter()
+                .all(|&(reg, _)| reg < consts::edge::REG_INTR_MASK_1)
+        );
+
+        let registers = MockRegisterFile::default();
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device
+            .gpio_setup_inputs_with_interrupts(&[], GpioPull::None)
+            .unwrap();
+        assert!(interrupt_register_writes(&registers).is_empty());
+    }
+
+    #[test]
+    fn test_interrupt_events_carry_timestamp() {
+        let device = Xr2280x::new_for_test(32);
+        // Group 0: pin 1 high, pin 4 low; group 1: pin 17 high
+        let report = GpioInterruptReport {
+            raw_data: vec![0x01, 0x02, 0x00, 0x02, 0x00, 0x12, 0x00, 0x02, 0x00],
+        };
+        let received_at = Instant::now();
+
+        let events = device
+            .parse_gpio_interrupt_events(&report, received_at)
+            .unwrap();
+        let pin = |n| GpioPin::new(n).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                GpioEvent {
+                    pin: pin(1),
+                    edge: GpioEdge::Rising,
+                    level: GpioLevel::High,
+                    timestamp: received_at,
+                },
+                GpioEvent {
+                    pin: pin(4),
+                    edge: GpioEdge::Falling,
+                    level: GpioLevel::Low,
+                    timestamp: received_at,
+                },
+                GpioEvent {
+                    pin: pin(17),
+                    edge: GpioEdge::Rising,
+                    level: GpioLevel::High,
+                    timestamp: received_at,
+                },
+            ]
+        );
+
+        // The tuple form reports the same pins and edges
+        let tuples = device.parse_gpio_interrupt_pins(&report).unwrap();
+        let from_events: Vec<_> = events.iter().map(|e| (e.pin, e.edge)).collect();
+        assert_eq!(tuples, from_events);
+    }
+
+    #[test]
+    fn test_interrupt_edge_registers_by_group() {
+        assert_eq!(
+            interrupt_edge_registers(GpioPin::new(15).unwrap()),
+            (
+                consts::edge::REG_INTR_POS_EDGE_0,
+                consts::edge::REG_INTR_NEG_EDGE_0
+            )
+        );
+        assert_eq!(
+            interrupt_edge_registers(GpioPin::new(16).unwrap()),
+            (
+                consts::edge::REG_INTR_POS_EDGE_1,
+                consts::edge::REG_INTR_NEG_EDGE_1
+            )
+        );
+    }
+
+    #[test]
+    fn test_monitor_restores_interrupt_config() {
+        use consts::edge::*;
+        let registers = MockRegisterFile::default()
+            .with_register(REG_INTR_MASK_0, 0x8001)
+            .with_register(REG_INTR_POS_EDGE_0, 0x0001)
+            .with_register(REG_INTR_NEG_EDGE_0, 0x8004);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let pin = |n| GpioPin::new(n).unwrap();
+
+        let monitor = device
+            .monitor_interrupts(&[(pin(2), GpioEdge::Rising), (pin(3), GpioEdge::Both)])
+            .unwrap();
+        assert_eq!(registers.register(REG_INTR_MASK_0), 0x800D);
+        // Another pin changes while monitoring
+        let registers = registers.with_register(REG_INTR_POS_EDGE_0, 0x010D);
+
+        let configured = interrupt_register_writes(&registers).len();
+        monitor.stop().unwrap();
+
+        // Interrupts are disabled before their edges are restored
+        assert_eq!(
+            interrupt_register_writes(&registers)[configured..],
+            [
+                (REG_INTR_MASK_0, 0x8001),
+                (REG_INTR_POS_EDGE_0, 0x0101),
+                (REG_INTR_NEG_EDGE_0, 0x8004),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_event_accepts_unbounded_timeout() {
+        let device = Xr2280x::new_for_test(8).with_register_transport(MockRegisterFile::default());
+        let mut monitor = device
+            .monitor_interrupts(&[(GpioPin::new(2).unwrap(), GpioEdge::Both)])
+            .unwrap();
+        // Without an EDGE interface the wait fails at once instead of overflowing
+        assert!(matches!(
+            monitor.next_event(Duration::MAX),
+            Err(Error::DeviceNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_restore_attempts_every_register() {
+        let registers = MockRegisterFile::default().with_failing_register(1);
+        let device = Xr2280x::new_for_test(8).with_register_transport(registers.clone());
+        let saved = [1, 2].map(|register| SavedInterruptRegister {
+            register,
+            mask: 0x0001,
+            value: 0x0001,
+        });
+        let result = device.restore_interrupt_registers(&saved);
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert_eq!(registers.writes(), vec![(2, 0x0001)]);
+    }
+
+    #[test]
+    fn test_try_parse_interrupt_rejects_malformed_reports() {
+        // Truncated reports, including every length short of the state words
+        for len in 0..report_offsets::MIN_REPORT_SIZE {
+            let bytes = vec![0xFF; len];
+            assert!(matches!(
+                try_parse_interrupt(&bytes),
+                Err(Error::InterruptParseError(_))
+            ));
+        }
+
+        // States only: no trigger masks, so no events
+        assert!(
+            try_parse_interrupt(&[0x01, 0xFF, 0xFF, 0xFF, 0xFF])
+                .unwrap()
+                .is_empty()
+        );
+        // Partial trigger masks are ignored
+        assert!(
+            try_parse_interrupt(&[0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])
+                .unwrap()
+                .is_empty()
+        );
+
+        // Trailing bytes beyond the trigger masks are ignored
+        let mut bytes = vec![0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00];
+        bytes.extend([0xAA; 16]);
+        let events = try_parse_interrupt(&bytes).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].pin, GpioPin::new(17).unwrap());
+        assert_eq!(events[0].edge, GpioEdge::Rising);
+
+        // Longer than any report a read can return
+        for len in [INTERRUPT_REPORT_BUF_SIZE, INTERRUPT_REPORT_BUF_SIZE * 4] {
+            assert!(matches!(
+                try_parse_interrupt(&vec![0xFF; len]),
+                Err(Error::InterruptParseError(_))
+            ));
+        }
+        let all_pins = try_parse_interrupt(&[0xFF; INTERRUPT_REPORT_BUF_SIZE - 1]).unwrap();
+        assert_eq!(all_pins.len(), 32);
     }
 }
@@ -518,11 +518,8 @@
 //! // Configure pins 0-7 as outputs (LED array)
 //! let led_mask = 0x00FF; // Pins 0-7
 //!
-//! // Assign pins to EDGE controller individually
-//! for pin_num in 0..8 {
-//!     let pin = GpioPin::new(pin_num)?;
-//!     device.gpio_assign_to_edge(pin)?;
-//! }
+//! // Assign all pins to the EDGE controller in one read-modify-write
+//! device.gpio_assign_to_edge_masked(GpioGroup::Group0, led_mask)?;
 //!
 //! // Set direction for all pins at once using mask
 //! device.gpio_set_direction_masked(GpioGroup::Group0, led_mask, GpioDirection::Output)?;
@@ -942,8 +939,8 @@
 //!     Err(Error::DeviceNotFoundByIndex { index, message }) => {
 //!         println!("No hardware device found at index {}: {}", index, message);
 //!     },
-//!     Err(Error::MultipleDevicesFound { count, message }) => {
-//!         println!("Found {} devices when expecting one: {}", count, message);
+//!     Err(Error::MultipleDevicesFound { count, serials }) => {
+//!         println!("Found {} devices when expecting one: {:?}", count, serials);
 //!     },
 //!     Err(e) => println!("Other error: {}", e),
 //! }
@@ -954,9 +951,10 @@
 //! ## Safety and Limitations
 //!
 //! - GPIO pins operate at 3.3V logic levels
-//! - Maximum I2C speed is device-dependent (typically 400kHz)
+//! - Maximum I2C speed is 400kHz on all XR2280x models (no Fast-mode Plus); see `i2c_speed_limits()`
 //! - PWM resolution depends on frequency (higher frequency = lower resolution)
 //! - No electrical isolation - use appropriate level shifters for 5V systems
+//! - GPIO drive strength and slew rate are fixed (no configuration register exists)
 //!
 //! ## Troubleshooting
 //!
@@ -1011,22 +1009,45 @@ mod consts;
 mod error;
 
 // Public modules
+#[cfg(feature = "async")]
+pub mod async_device;
 pub mod device;
 pub mod gpio;
 pub mod i2c;
 pub mod interrupt;
+pub mod pin_map;
 pub mod pwm;
+pub mod rgb_led;
+pub mod setup;
 
 // Re-export main types and functions
+#[cfg(feature = "async")]
+pub use async_device::AsyncXr2280x;
 pub use device::{
-    Capabilities, Xr2280x, XrDeviceDetails, XrDeviceInfo, device_find, device_find_all,
-    device_find_first,
+    Capabilities, DetectionConfidence, ReportSizes, Xr2280x, XrDeviceDetails, XrDeviceInfo,
+    device_find, device_find_all, device_find_first, device_find_strict,
+};
+pub use error::{Error, ErrorCategory, Result};
+pub use gpio::{
+    DropBehavior, Gpio32, GpioDirection, GpioEdge, GpioEdgeDetector, GpioGroup, GpioGroupRegisters,
+    GpioLevel, GpioPin, GpioPinConfig, GpioPolarity, GpioPull, GpioRegisterDump, GpioSnapshotRead,
+    GpioTransaction, GroupSpan, HidOp, Logical, OutputMode, PinFunction, gpio_diff,
+    gpio_group_masks, gpio_groups_for,
+};
+pub use i2c::{
+    BusHealth, I2cAddress, I2cDeviceHandle, I2cTransferStatus, ScanConfig, Timeouts, timeouts,
 };
-pub use error::{Error, Result};
-pub use gpio::{GpioDirection, GpioEdge, GpioGroup, GpioLevel, GpioPin, GpioPull, GpioTransaction};
-pub use i2c::{I2cAddress, timeouts};
-pub use interrupt::{GpioInterruptReport, ParsedGpioInterruptReport};
-pub use pwm::{PwmChannel, PwmCommand};
+pub use interrupt::{
+    GpioEvent, GpioInterruptReport, InterruptMonitor, ParsedGpioInterruptReport,
+    try_parse_interrupt,
+};
+pub use pin_map::PinMap;
+pub use pwm::{
+    PwmChannel, PwmCommand, PwmPeriod, PwmResolution, SOFT_PWM_MAX_FREQ_HZ,
+    pwm_frequency_resolution,
+};
+pub use rgb_led::RgbLed;
+pub use setup::{DeviceSetup, SetupStep};
 
 // Re-export essential hidapi types for multi-device selection
 pub use hidapi::{DeviceInfo, HidApi};
@@ -1042,6 +1063,12 @@ pub mod flags {
         // Re-export flags needed for i2c_transfer_raw
         pub use crate::consts::i2c::out_flags::{ACK_LAST_READ, START_BIT, STOP_BIT};
     }
+    /// Status flags reported by [`crate::Xr2280x::i2c_transfer_raw_status`].
+    pub mod i2c_status {
+        pub use crate::consts::i2c::in_flags::{
+            ARBITRATION_LOST, NAK_RECEIVED, REQUEST_ERROR, TIMEOUT,
+        };
+    }
     // Add other flags here if needed (e.g., for interrupts if a parsing API is added)
 }
 
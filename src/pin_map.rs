@@ -0,0 +1,135 @@
+//! Named GPIO pins.
+//!
+//! A [`PinMap`] keeps the board wiring in one place, so application code refers to
+//! pins by their signal names instead of numbers:
+//!
+//! ```
+//! use xr2280x_hid::{GpioPin, PinMap};
+//!
+//! # fn main() -> xr2280x_hid::Result<()> {
+//! let pins = PinMap::new()
+//!     .with("LED_POWER", GpioPin::new(0)?)
+//!     .with("SENSOR_RESET", GpioPin::new(4)?);
+//!
+//! assert_eq!(pins.pin("SENSOR_RESET")?.number(), 4);
+//! assert!(pins.pin("LED_STATUS").is_err());
+//!
+//! // The same map from label/number pairs, e.g. read from a configuration file
+//! let pins = PinMap::from_numbers([("LED_POWER", 0), ("SENSOR_RESET", 4)])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Error, Result};
+use crate::gpio::GpioPin;
+use std::collections::BTreeMap;
+
+/// A mapping from signal labels to GPIO pins.
+///
+/// Labels are case-sensitive. Several labels may name the same pin.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PinMap {
+    pins: BTreeMap<String, GpioPin>,
+}
+
+impl PinMap {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a map from label/pin number pairs, validating each number with
+    /// [`GpioPin::new`].
+    pub fn from_numbers<I, S>(entries: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (S, u8)>,
+        S: Into<String>,
+    {
+        let mut map = Self::new();
+        for (label, number) in entries {
+            map.insert(label, GpioPin::new(number)?);
+        }
+        Ok(map)
+    }
+
+    /// Adds `label` for `pin`, replacing an earlier pin with the same label.
+    pub fn with(mut self, label: impl Into<String>, pin: GpioPin) -> Self {
+        self.insert(label, pin);
+        self
+    }
+
+    /// Adds `label` for `pin`, returning the pin it previously named, if any.
+    pub fn insert(&mut self, label: impl Into<String>, pin: GpioPin) -> Option<GpioPin> {
+        self.pins.insert(label.into(), pin)
+    }
+
+    /// Looks up the pin named `label`.
+    ///
+    /// Returns [`Error::UnknownPinLabel`] if no pin has that label.
+    pub fn pin(&self, label: &str) -> Result<GpioPin> {
+        self.pins
+            .get(label)
+            .copied()
+            .ok_or_else(|| Error::UnknownPinLabel {
+                label: label.to_string(),
+            })
+    }
+
+    /// Iterates over the labels and their pins, sorted by label.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, GpioPin)> {
+        self.pins.iter().map(|(label, &pin)| (label.as_str(), pin))
+    }
+
+    /// Number of labels in the map.
+    pub fn len(&self) -> usize {
+        self.pins.len()
+    }
+
+    /// Whether the map has no labels.
+    pub fn is_empty(&self) -> bool {
+        self.pins.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_label() {
+        let pin = |n| GpioPin::new(n).unwrap();
+        let mut pins = PinMap::new()
+            .with("LED_POWER", pin(0))
+            .with("SENSOR_RESET", pin(4))
+            .with("LED_STATUS", pin(4));
+        assert_eq!(pins.pin("LED_POWER").unwrap(), pin(0));
+        assert_eq!(pins.pin("SENSOR_RESET").unwrap(), pin(4));
+        assert_eq!(pins.insert("LED_STATUS", pin(5)), Some(pin(4)));
+        assert_eq!(pins.pin("LED_STATUS").unwrap(), pin(5));
+        assert_eq!(pins.len(), 3);
+
+        let labels: Vec<_> = pins.iter().map(|(label, _)| label).collect();
+        assert_eq!(labels, ["LED_POWER", "LED_STATUS", "SENSOR_RESET"]);
+
+        assert_eq!(
+            PinMap::from_numbers([("LED_POWER", 0), ("SENSOR_RESET", 4), ("LED_STATUS", 5)])
+                .unwrap(),
+            pins
+        );
+    }
+
+    #[test]
+    fn test_unknown_label_and_invalid_number() {
+        let pins = PinMap::from_numbers([("LED_POWER", 0)]).unwrap();
+        assert!(matches!(
+            pins.pin("led_power"),
+            Err(Error::UnknownPinLabel { label }) if label == "led_power"
+        ));
+        assert!(PinMap::new().is_empty());
+
+        assert!(matches!(
+            PinMap::from_numbers([("LED_POWER", 32)]),
+            Err(Error::PinArgumentOutOfRange { pin: 32, .. })
+        ));
+    }
+}
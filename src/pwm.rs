@@ -3,8 +3,10 @@
 use crate::consts;
 use crate::device::Xr2280x;
 use crate::error::{Error, Result, pwm_hardware_error, pwm_parameter_error, unsupported_pwm_pin};
-use crate::gpio::GpioPin;
-use log::{debug, trace};
+use crate::gpio::{GpioLevel, GpioPin, GpioPull, PinFunction};
+use log::{debug, trace, warn};
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Represents the two PWM channels available.
 /// PWM channel identifier for XR2280x devices.
@@ -19,6 +21,29 @@ pub enum PwmChannel {
     Pwm1,
 }
 
+impl PwmChannel {
+    /// Both PWM channels, e.g. for setup and teardown loops.
+    pub fn all() -> [PwmChannel; 2] {
+        [PwmChannel::Pwm0, PwmChannel::Pwm1]
+    }
+
+    /// Address of this channel's control register.
+    pub(crate) fn ctrl_register(self) -> u16 {
+        match self {
+            PwmChannel::Pwm0 => consts::edge::REG_PWM0_CTRL,
+            PwmChannel::Pwm1 => consts::edge::REG_PWM1_CTRL,
+        }
+    }
+
+    /// Addresses of this channel's (high, low) period registers.
+    pub(crate) fn period_registers(self) -> (u16, u16) {
+        match self {
+            PwmChannel::Pwm0 => (consts::edge::REG_PWM0_HIGH, consts::edge::REG_PWM0_LOW),
+            PwmChannel::Pwm1 => (consts::edge::REG_PWM1_HIGH, consts::edge::REG_PWM1_LOW),
+        }
+    }
+}
+
 /// PWM command/mode for controlling PWM output behavior.
 ///
 /// These commands control how the PWM channel behaves after being enabled.
@@ -36,46 +61,362 @@ pub enum PwmCommand {
     Undefined(u16),
 }
 
-impl Xr2280x {
-    // --- PWM Configuration ---
-    /// Converts nanoseconds to PWM units (increments of ~266.667ns).
-    /// Returns `Err` if the time is out of range (1 - 4095 units).
-    pub fn ns_to_pwm_units(&self, nanoseconds: u64) -> Result<u16> {
-        if nanoseconds == 0 {
+/// Achievable PWM timing resolution at a requested output frequency.
+///
+/// Returned by [`pwm_frequency_resolution`]. PWM time is quantized to
+/// ~266.667ns units and each of the high/low periods is limited to 1-4095
+/// units, so high frequencies lose duty-cycle resolution and frequencies
+/// below ~458 Hz cannot be generated at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PwmResolution {
+    /// The frequency that was requested, in Hz.
+    pub requested_hz: f64,
+    /// The nearest frequency the hardware can generate, in Hz.
+    pub actual_hz: f64,
+    /// Total period (high + low) in PWM units for the nearest frequency.
+    pub period_units: u16,
+    /// Number of distinct duty-cycle settings available at this period.
+    pub duty_cycle_steps: u16,
+    /// True if the requested frequency was outside the supported range and clamped.
+    pub clamped: bool,
+}
+
+impl PwmResolution {
+    /// Relative deviation of the achievable frequency from the requested one, in percent.
+    ///
+    /// Returns [`Error::ArgumentOutOfRange`] if the requested frequency is not a positive
+    /// finite number (e.g. 0 Hz), as the relative deviation is undefined then.
+    pub fn frequency_error_percent(&self) -> Result<f64> {
+        if !(self.requested_hz.is_finite() && self.requested_hz > 0.0) {
+            return Err(Error::ArgumentOutOfRange(format!(
+                "PWM frequency error is undefined for a request of {} Hz",
+                self.requested_hz
+            )));
+        }
+        Ok((self.actual_hz - self.requested_hz) / self.requested_hz * 100.0)
+    }
+}
+
+/// Calculates the duty-cycle resolution and nearest representable frequency for a PWM target.
+///
+/// This is pure math and does not access the device, so it can be used to decide
+/// whether a frequency is viable before configuring a channel.
+///
+/// # Example
+/// ```
+/// use xr2280x_hid::pwm_frequency_resolution;
+///
+/// let res = pwm_frequency_resolution(1_000.0);
+/// assert_eq!(res.period_units, 3750);
+/// assert!(!res.clamped);
+/// ```
+pub fn pwm_frequency_resolution(freq_hz: f64) -> PwmResolution {
+    let min_units = 2 * consts::edge::PWM_MIN_UNITS;
+    let max_units = 2 * consts::edge::PWM_MAX_UNITS;
+
+    let ideal_units = 1_000_000_000.0 / (freq_hz * consts::edge::PWM_UNIT_TIME_NS);
+    let (period_units, clamped) = if ideal_units.is_nan() || ideal_units > max_units as f64 {
+        (max_units, true)
+    } else if ideal_units < min_units as f64 {
+        (min_units, true)
+    } else {
+        (ideal_units.round() as u16, false)
+    };
+
+    // High period ranges over every value that leaves both high and low within 1-4095 units
+    let high_min =
+        consts::edge::PWM_MIN_UNITS.max(period_units.saturating_sub(consts::edge::PWM_MAX_UNITS));
+    let high_max = consts::edge::PWM_MAX_UNITS.min(period_units - consts::edge::PWM_MIN_UNITS);
+
+    PwmResolution {
+        requested_hz: freq_hz,
+        actual_hz: 1_000_000_000.0 / (period_units as f64 * consts::edge::PWM_UNIT_TIME_NS),
+        period_units,
+        duty_cycle_steps: high_max - high_min + 1,
+        clamped,
+    }
+}
+
+/// Converts nanoseconds to PWM units, checking the 1-4095 unit hardware range.
+fn ns_to_units(nanoseconds: u64) -> Result<u16> {
+    if nanoseconds == 0 {
+        return Err(pwm_parameter_error(
+            255, // Generic channel since this is a utility function
+            "PWM time must be greater than 0 ns".to_string(),
+        ));
+    }
+    let units = (nanoseconds as f64 / consts::edge::PWM_UNIT_TIME_NS).round() as u64;
+    if units < consts::edge::PWM_MIN_UNITS as u64 {
+        Err(pwm_parameter_error(
+            255, // Generic channel since this is a utility function
+            format!(
+                "PWM time {} ns is too small (min {} ns)",
+                nanoseconds,
+                units_to_ns(consts::edge::PWM_MIN_UNITS)
+            ),
+        ))
+    } else if units > consts::edge::PWM_MAX_UNITS as u64 {
+        Err(pwm_parameter_error(
+            255, // Generic channel since this is a utility function
+            format!(
+                "PWM time {} ns is too large (max {} ns)",
+                nanoseconds,
+                units_to_ns(consts::edge::PWM_MAX_UNITS)
+            ),
+        ))
+    } else {
+        Ok(units as u16)
+    }
+}
+
+/// Converts PWM units to nanoseconds (units * 266.667ns).
+fn units_to_ns(units: u16) -> u64 {
+    (units as f64 * consts::edge::PWM_UNIT_TIME_NS).round() as u64
+}
+
+/// A PWM waveform expressed as hardware high/low periods.
+///
+/// Construct it from whichever description is natural for the application
+/// (frequency and duty cycle, high/low times, or period and pulse width) and
+/// apply it with [`Xr2280x::pwm_apply`]. The values are quantized to ~266.667ns
+/// PWM units on construction, so the getters report what the hardware will
+/// actually generate.
+///
+/// # Example
+/// ```
+/// use xr2280x_hid::PwmPeriod;
+///
+/// // Standard hobby servo: 20ms period, 1.5ms pulse (center position)
+/// let servo = PwmPeriod::from_pulse_width(20_000_000, 1_500_000);
+/// // ...is too long for the hardware, so pick a faster frame instead
+/// assert!(servo.is_err());
+///
+/// let led = PwmPeriod::from_freq_duty(1_000.0, 0.25)?;
+/// assert!((led.duty() - 0.25).abs() < 0.001);
+/// # Ok::<(), xr2280x_hid::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PwmPeriod {
+    high_units: u16,
+    low_units: u16,
+}
+
+impl PwmPeriod {
+    /// Creates a period from raw high/low PWM units (1-4095 each).
+    pub fn from_units(high_units: u16, low_units: u16) -> Result<Self> {
+        let range = consts::edge::PWM_MIN_UNITS..=consts::edge::PWM_MAX_UNITS;
+        if !range.contains(&high_units) || !range.contains(&low_units) {
             return Err(pwm_parameter_error(
-                255, // Generic channel since this is a utility function
-                "PWM time must be greater than 0 ns".to_string(),
+                255,
+                format!("PWM period units must be 1-4095 (got high={high_units}, low={low_units})"),
             ));
         }
-        let units = (nanoseconds as f64 / consts::edge::PWM_UNIT_TIME_NS).round() as u64;
-        if units < consts::edge::PWM_MIN_UNITS as u64 {
-            Err(pwm_parameter_error(
-                255, // Generic channel since this is a utility function
-                format!(
-                    "PWM time {} ns is too small (min {} ns)",
-                    nanoseconds,
-                    (consts::edge::PWM_MIN_UNITS as f64 * consts::edge::PWM_UNIT_TIME_NS).round()
-                        as u64
-                ),
-            ))
-        } else if units > consts::edge::PWM_MAX_UNITS as u64 {
-            Err(pwm_parameter_error(
-                255, // Generic channel since this is a utility function
-                format!(
-                    "PWM time {} ns is too large (max {} ns)",
-                    nanoseconds,
-                    (consts::edge::PWM_MAX_UNITS as f64 * consts::edge::PWM_UNIT_TIME_NS).round()
-                        as u64
+        Ok(Self {
+            high_units,
+            low_units,
+        })
+    }
+
+    /// Creates a period from the high and low times in nanoseconds.
+    pub fn from_high_low_ns(high_ns: u64, low_ns: u64) -> Result<Self> {
+        Self::from_units(ns_to_units(high_ns)?, ns_to_units(low_ns)?)
+    }
+
+    /// Creates a period from the total period and the high pulse width in nanoseconds.
+    pub fn from_pulse_width(period_ns: u64, pulse_ns: u64) -> Result<Self> {
+        if pulse_ns >= period_ns {
+            return Err(pwm_parameter_error(
+                255,
+                format!("PWM pulse width {pulse_ns} ns must be shorter than period {period_ns} ns"),
+            ));
+        }
+        Self::from_high_low_ns(pulse_ns, period_ns - pulse_ns)
+    }
+
+    /// Creates a period from a frequency in Hz and a duty cycle (fraction high).
+    ///
+    /// The total period is rounded to whole PWM units first, so the frequency is kept
+    /// as close as possible and the duty cycle is rounded within it.
+    ///
+    /// The hardware needs at least one PWM unit both high and low, so `duty` must lie
+    /// in the open interval (0.0, 1.0). 0.0, 1.0, and duties that round to no high or
+    /// no low time return [`Error::PwmParameterError`]; drive the pin as a GPIO output
+    /// for a constant level.
+    pub fn from_freq_duty(freq_hz: f64, duty: f64) -> Result<Self> {
+        if !(freq_hz.is_finite() && freq_hz > 0.0) {
+            return Err(pwm_parameter_error(
+                255,
+                format!("PWM frequency must be positive (got {freq_hz} Hz)"),
+            ));
+        }
+        if !(duty > 0.0 && duty < 1.0) {
+            return Err(pwm_parameter_error(
+                255,
+                format!("PWM duty cycle must be between 0.0 and 1.0 exclusive (got {duty})"),
+            ));
+        }
+        let total_units =
+            (1_000_000_000.0 / (freq_hz * consts::edge::PWM_UNIT_TIME_NS)).round() as u64;
+        let high_units = (total_units as f64 * duty).round() as u64;
+        let low_units = total_units - high_units;
+        if high_units > u16::MAX as u64 || low_units > u16::MAX as u64 {
+            return Err(pwm_parameter_error(
+                255,
+                format!("PWM frequency {freq_hz} Hz is too low for the hardware"),
+            ));
+        }
+        Self::from_units(high_units as u16, low_units as u16)
+    }
+
+    /// High period in PWM units.
+    pub fn high_units(&self) -> u16 {
+        self.high_units
+    }
+
+    /// Low period in PWM units.
+    pub fn low_units(&self) -> u16 {
+        self.low_units
+    }
+
+    /// High time in nanoseconds.
+    pub fn high_ns(&self) -> u64 {
+        units_to_ns(self.high_units)
+    }
+
+    /// Low time in nanoseconds.
+    pub fn low_ns(&self) -> u64 {
+        units_to_ns(self.low_units)
+    }
+
+    /// Output frequency in Hz.
+    pub fn frequency_hz(&self) -> f64 {
+        let total_units = self.high_units as f64 + self.low_units as f64;
+        1_000_000_000.0 / (total_units * consts::edge::PWM_UNIT_TIME_NS)
+    }
+
+    /// Duty cycle as the fraction of the period spent high, strictly between 0.0 and 1.0.
+    pub fn duty(&self) -> f64 {
+        self.high_units as f64 / (self.high_units as f64 + self.low_units as f64)
+    }
+}
+
+/// Computes a control register value with the enable bit and command replaced.
+fn control_value(
+    channel: PwmChannel,
+    current: u16,
+    enable: bool,
+    command: PwmCommand,
+) -> Result<u16> {
+    let enable_bits = if enable {
+        consts::edge::pwm_ctrl::ENABLE_MASK
+    } else {
+        0
+    };
+    let cmd_bits = match command {
+        PwmCommand::Idle => consts::edge::pwm_ctrl::CMD_IDLE,
+        PwmCommand::AssertLow => consts::edge::pwm_ctrl::CMD_ASSERT_LOW,
+        PwmCommand::OneShot => consts::edge::pwm_ctrl::CMD_ONE_SHOT,
+        PwmCommand::FreeRun => consts::edge::pwm_ctrl::CMD_FREE_RUN,
+        PwmCommand::Undefined(raw) => match raw & !0b111 {
+            0 => raw,
+            _ => {
+                return Err(pwm_parameter_error(
+                    channel as u8,
+                    "PWM command raw value must fit in 3 bits".to_string(),
+                ));
+            }
+        },
+    };
+    let cmd_shifted = cmd_bits << consts::edge::pwm_ctrl::CMD_SHIFT;
+    Ok(
+        (current & !(consts::edge::pwm_ctrl::ENABLE_MASK | consts::edge::pwm_ctrl::CMD_MASK))
+            | enable_bits
+            | cmd_shifted,
+    )
+}
+
+/// Period of a ~50% duty square wave at `freq_hz` for [`Xr2280x::pwm_tone`], or
+/// `None` for a rest (`0.0`).
+fn tone_period(freq_hz: f64) -> Result<Option<PwmPeriod>> {
+    if freq_hz == 0.0 {
+        return Ok(None);
+    }
+    PwmPeriod::from_freq_duty(freq_hz, 0.5).map(Some)
+}
+
+/// Highest frequency accepted by [`Xr2280x::gpio_soft_pwm`].
+///
+/// Every edge is a HID round-trip of roughly 1-4 ms, so above this the edges land
+/// late enough that the duty no longer resembles the one requested.
+pub const SOFT_PWM_MAX_FREQ_HZ: f64 = 50.0;
+
+/// High and low times of one software PWM period at `freq_hz` and `duty` (0.0-1.0).
+fn soft_pwm_times(freq_hz: f64, duty: f64) -> Result<(Duration, Duration)> {
+    if !(freq_hz > 0.0 && freq_hz <= SOFT_PWM_MAX_FREQ_HZ) {
+        return Err(Error::ArgumentOutOfRange(format!(
+            "Software PWM frequency must be above 0 and at most {SOFT_PWM_MAX_FREQ_HZ} Hz (got {freq_hz} Hz)"
+        )));
+    }
+    if !(0.0..=1.0).contains(&duty) {
+        return Err(Error::ArgumentOutOfRange(format!(
+            "Software PWM duty must be between 0.0 and 1.0 (got {duty})"
+        )));
+    }
+    let period = Duration::from_secs_f64(1.0 / freq_hz);
+    let high = period.mul_f64(duty);
+    Ok((high, period - high))
+}
+
+/// PWM pins assigned through a handle, used to reject routing both channels to one pin.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct PwmPinAssignments([Option<GpioPin>; 2]);
+
+impl PwmPinAssignments {
+    fn get(&self, channel: PwmChannel) -> Option<GpioPin> {
+        self.0[channel as usize]
+    }
+
+    fn record(&mut self, channel: PwmChannel, pin: GpioPin) {
+        self.0[channel as usize] = Some(pin);
+    }
+
+    /// The function of `pin` if it is assigned to the channel other than `channel`.
+    fn other_channel_function(&self, channel: PwmChannel, pin: GpioPin) -> Option<PinFunction> {
+        let (other, function) = match channel {
+            PwmChannel::Pwm0 => (PwmChannel::Pwm1, PinFunction::Pwm1),
+            PwmChannel::Pwm1 => (PwmChannel::Pwm0, PinFunction::Pwm0),
+        };
+        (self.get(other) == Some(pin)).then_some(function)
+    }
+}
+
+/// Rejects pins whose current function would be clobbered by routing `channel` to them.
+fn validate_pwm_pin(channel: PwmChannel, pin: GpioPin, function: PinFunction) -> Result<()> {
+    match (function, channel) {
+        (PinFunction::Pwm0, PwmChannel::Pwm1) | (PinFunction::Pwm1, PwmChannel::Pwm0) => {
+            Err(Error::PinFunctionConflict {
+                pin: pin.number(),
+                current: function,
+                message: format!(
+                    "cannot assign {channel:?}: pin is already assigned to {function:?}; assign {function:?} another pin first or use pwm_set_pin_forced"
                 ),
-            ))
-        } else {
-            Ok(units as u16)
+            })
         }
+        _ => Ok(()),
+    }
+}
+
+impl Xr2280x {
+    // --- PWM Configuration ---
+    /// Converts nanoseconds to PWM units (increments of ~266.667ns).
+    /// Returns `Err` if the time is out of range (1 - 4095 units).
+    pub fn ns_to_pwm_units(&self, nanoseconds: u64) -> Result<u16> {
+        ns_to_units(nanoseconds)
     }
 
     /// Converts PWM units to nanoseconds (units * 266.667ns).
     pub fn pwm_units_to_ns(&self, units: u16) -> u64 {
-        (units as f64 * consts::edge::PWM_UNIT_TIME_NS).round() as u64
+        units_to_ns(units)
     }
 
     /// Sets the high and low periods for a PWM channel in units (increments of ~266.667ns).
@@ -85,10 +426,7 @@ impl Xr2280x {
         high_units: u16,
         low_units: u16,
     ) -> Result<()> {
-        let (reg_high, reg_low) = match channel {
-            PwmChannel::Pwm0 => (consts::edge::REG_PWM0_HIGH, consts::edge::REG_PWM0_LOW),
-            PwmChannel::Pwm1 => (consts::edge::REG_PWM1_HIGH, consts::edge::REG_PWM1_LOW),
-        };
+        let (reg_high, reg_low) = channel.period_registers();
 
         match (high_units, low_units) {
             (1..=4095, 1..=4095) => {} // Valid range
@@ -114,12 +452,14 @@ impl Xr2280x {
         self.pwm_set_periods(channel, high_units, low_units)
     }
 
+    /// Applies a typed [`PwmPeriod`] to a PWM channel.
+    pub fn pwm_apply(&self, channel: PwmChannel, period: PwmPeriod) -> Result<()> {
+        self.pwm_set_periods(channel, period.high_units(), period.low_units())
+    }
+
     /// Gets the high and low periods for a PWM channel in units (increments of ~266.667ns).
     pub fn pwm_get_periods(&self, channel: PwmChannel) -> Result<(u16, u16)> {
-        let (reg_high, reg_low) = match channel {
-            PwmChannel::Pwm0 => (consts::edge::REG_PWM0_HIGH, consts::edge::REG_PWM0_LOW),
-            PwmChannel::Pwm1 => (consts::edge::REG_PWM1_HIGH, consts::edge::REG_PWM1_LOW),
-        };
+        let (reg_high, reg_low) = channel.period_registers();
         let high_units = self.read_pwm_register(channel, reg_high)?;
         let low_units = self.read_pwm_register(channel, reg_low)?;
         trace!("Read {channel:?} periods: high={high_units} units, low={low_units} units");
@@ -136,30 +476,79 @@ impl Xr2280x {
     }
 
     /// Sets the GPIO pin assigned to a PWM channel (0-31).
+    ///
+    /// The pin's current function is checked first: a pin driven by the other PWM
+    /// channel, or assigned to it through this handle (see
+    /// [`Xr2280x::pwm_get_assigned_pin`]) even while that channel is disabled, is
+    /// rejected with [`Error::PinFunctionConflict`]. Reassigning a pin to the same
+    /// channel is allowed.
+    /// A pin that is not yet assigned to the EDGE controller is assigned automatically.
+    /// Configuring the pin as a GPIO output beforehand is the expected setup and is
+    /// not treated as a conflict.
+    ///
+    /// **Performance**: Uses 4-5 HID transactions. Use [`Xr2280x::pwm_set_pin_forced`]
+    /// to skip the checks.
     pub fn pwm_set_pin(&self, channel: PwmChannel, pin: GpioPin) -> Result<()> {
+        self.check_pwm_pin_support(pin)?;
+        // An assignment made through this handle is known without reading the device
+        let assigned = self
+            .pwm_pins
+            .lock()
+            .unwrap()
+            .other_channel_function(channel, pin);
+        let function = match assigned {
+            Some(function) => function,
+            None => self.gpio_get_function(pin)?,
+        };
+        validate_pwm_pin(channel, pin, function)?;
+        if function == PinFunction::Unassigned {
+            self.gpio_assign_to_edge(pin)?;
+        }
+        self.write_pwm_pin(channel, pin)
+    }
+
+    /// Sets the GPIO pin assigned to a PWM channel without checking its current function.
+    ///
+    /// Unlike [`Xr2280x::pwm_set_pin`], this neither rejects conflicting pins nor assigns
+    /// the pin to the EDGE controller. Only use it when the pin's state is known.
+    pub fn pwm_set_pin_forced(&self, channel: PwmChannel, pin: GpioPin) -> Result<()> {
+        self.check_pwm_pin_support(pin)?;
+        self.write_pwm_pin(channel, pin)
+    }
+
+    fn check_pwm_pin_support(&self, pin: GpioPin) -> Result<()> {
         // XR22800/1 only support PWM on pins 0-7 (8 GPIOs)
         if self.capabilities.gpio_count == 8 && pin.number() > 7 {
             return Err(unsupported_pwm_pin(pin.number()));
         }
+        Ok(())
+    }
 
-        let reg = match channel {
-            PwmChannel::Pwm0 => consts::edge::REG_PWM0_CTRL,
-            PwmChannel::Pwm1 => consts::edge::REG_PWM1_CTRL,
-        };
+    fn write_pwm_pin(&self, channel: PwmChannel, pin: GpioPin) -> Result<()> {
+        let reg = channel.ctrl_register();
         let current = self.read_pwm_register(channel, reg)?;
         let new_value = (current & !consts::edge::pwm_ctrl::PIN_MASK)
             | ((pin.number() as u16) << consts::edge::pwm_ctrl::PIN_SHIFT);
         debug!("Setting {:?} to pin {}", channel, pin.number());
         self.write_pwm_register(channel, reg, new_value)?;
+        self.pwm_pins.lock().unwrap().record(channel, pin);
         Ok(())
     }
 
+    /// Gets the pin last assigned to a PWM channel through this handle with
+    /// [`Xr2280x::pwm_set_pin`] or [`Xr2280x::pwm_set_pin_forced`].
+    ///
+    /// Returns `None` if this handle hasn't assigned the channel yet. The device can't
+    /// tell an assigned channel from an unused one, whose pin field just holds its
+    /// reset value, so unlike [`Xr2280x::pwm_get_pin`] this doesn't read the device and
+    /// doesn't see assignments made by other handles.
+    pub fn pwm_get_assigned_pin(&self, channel: PwmChannel) -> Option<GpioPin> {
+        self.pwm_pins.lock().unwrap().get(channel)
+    }
+
     /// Gets the GPIO pin assigned to a PWM channel.
     pub fn pwm_get_pin(&self, channel: PwmChannel) -> Result<GpioPin> {
-        let reg = match channel {
-            PwmChannel::Pwm0 => consts::edge::REG_PWM0_CTRL,
-            PwmChannel::Pwm1 => consts::edge::REG_PWM1_CTRL,
-        };
+        let reg = channel.ctrl_register();
         let value = self.read_pwm_register(channel, reg)?;
         let pin_num =
             ((value & consts::edge::pwm_ctrl::PIN_MASK) >> consts::edge::pwm_ctrl::PIN_SHIFT) as u8;
@@ -173,36 +562,9 @@ impl Xr2280x {
         enable: bool,
         command: PwmCommand,
     ) -> Result<()> {
-        let reg = match channel {
-            PwmChannel::Pwm0 => consts::edge::REG_PWM0_CTRL,
-            PwmChannel::Pwm1 => consts::edge::REG_PWM1_CTRL,
-        };
+        let reg = channel.ctrl_register();
         let current = self.read_pwm_register(channel, reg)?;
-        let enable_bits = if enable {
-            consts::edge::pwm_ctrl::ENABLE_MASK
-        } else {
-            0
-        };
-        let cmd_bits = match command {
-            PwmCommand::Idle => consts::edge::pwm_ctrl::CMD_IDLE,
-            PwmCommand::AssertLow => consts::edge::pwm_ctrl::CMD_ASSERT_LOW,
-            PwmCommand::OneShot => consts::edge::pwm_ctrl::CMD_ONE_SHOT,
-            PwmCommand::FreeRun => consts::edge::pwm_ctrl::CMD_FREE_RUN,
-            PwmCommand::Undefined(raw) => match raw & !0b111 {
-                0 => raw,
-                _ => {
-                    return Err(pwm_parameter_error(
-                        channel as u8,
-                        "PWM command raw value must fit in 3 bits".to_string(),
-                    ));
-                }
-            },
-        };
-        let cmd_shifted = cmd_bits << consts::edge::pwm_ctrl::CMD_SHIFT;
-        let new_value = (current
-            & !(consts::edge::pwm_ctrl::ENABLE_MASK | consts::edge::pwm_ctrl::CMD_MASK))
-            | enable_bits
-            | cmd_shifted;
+        let new_value = control_value(channel, current, enable, command)?;
         debug!(
             "Setting {channel:?}: enable={enable}, command={command:?} (ctrl=0x{new_value:04X})"
         );
@@ -210,12 +572,167 @@ impl Xr2280x {
         Ok(())
     }
 
+    /// Disables both PWM channels and sets them to [`PwmCommand::Idle`].
+    ///
+    /// Use this for a clean shutdown that does not leave a PWM output running. Both
+    /// channels are attempted even if the first fails; the first error is returned.
+    /// Pin assignments and periods are left unchanged.
+    ///
+    /// **Performance**: Uses 4 HID transactions (1 read + 1 write per channel).
+    pub fn pwm_disable_all(&self) -> Result<()> {
+        debug!("Disabling all PWM channels");
+        let mut first_error = None;
+        for channel in PwmChannel::all() {
+            let reg = channel.ctrl_register();
+            let result = self
+                .read_pwm_register(channel, reg)
+                .and_then(|current| control_value(channel, current, false, PwmCommand::Idle))
+                .and_then(|value| self.write_pwm_register(channel, reg, value));
+            if let Err(e) = result {
+                warn!("Failed to disable {channel:?}: {e}");
+                first_error.get_or_insert(e);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Plays a tone of `freq_hz` on `pin` for `duration`, e.g. on a piezo buzzer.
+    ///
+    /// The pin is set up as a GPIO output driving Low and routed to `channel` as
+    /// [`Xr2280x::pwm_set_pin`] does, then a ~50% duty square wave runs in
+    /// [`PwmCommand::FreeRun`] for `duration`. Afterwards the channel is left enabled
+    /// with [`PwmCommand::AssertLow`], so the pin is held Low. This call blocks for
+    /// `duration`.
+    ///
+    /// The period is rounded to whole PWM units, so the frequency is off by up to
+    /// ~0.05% at 4 kHz. Frequencies below ~458 Hz (each half period is at most 4095
+    /// units) are rejected with [`Error::PwmParameterError`]; play those an octave up.
+    pub fn pwm_tone(
+        &self,
+        channel: PwmChannel,
+        pin: GpioPin,
+        freq_hz: f64,
+        duration: Duration,
+    ) -> Result<()> {
+        if freq_hz == 0.0 {
+            return Err(pwm_parameter_error(
+                channel as u8,
+                "PWM tone frequency must be positive (got 0 Hz)".to_string(),
+            ));
+        }
+        self.pwm_play_melody(channel, pin, &[(freq_hz, duration)])
+    }
+
+    /// Plays `notes` of `(freq_hz, duration)` back to back on `pin`, as
+    /// [`Xr2280x::pwm_tone`] does for one note. A frequency of `0.0` is a rest.
+    ///
+    /// Every note is checked before anything is written. The pin is held Low after the
+    /// last note, and also when a note fails to play.
+    ///
+    /// **Performance**: Uses 4 HID transactions per note in addition to the pin setup.
+    /// Notes start late by that USB latency, which matters for very short notes.
+    pub fn pwm_play_melody(
+        &self,
+        channel: PwmChannel,
+        pin: GpioPin,
+        notes: &[(f64, Duration)],
+    ) -> Result<()> {
+        let periods = notes
+            .iter()
+            .map(|&(freq_hz, _)| tone_period(freq_hz))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.gpio_setup_output(pin, GpioLevel::Low, GpioPull::None)?;
+        self.pwm_set_pin(channel, pin)?;
+        self.pwm_control(channel, true, PwmCommand::AssertLow)?;
+
+        let played = periods
+            .iter()
+            .zip(notes)
+            .try_for_each(|(period, &(_, duration))| {
+                match period {
+                    Some(period) => {
+                        trace!("{channel:?} tone at {:.1} Hz", period.frequency_hz());
+                        self.pwm_apply(channel, *period)?;
+                        self.pwm_control(channel, true, PwmCommand::FreeRun)?;
+                    }
+                    None => self.pwm_control(channel, true, PwmCommand::AssertLow)?,
+                }
+                thread::sleep(duration);
+                Ok(())
+            });
+        let silenced = self.pwm_control(channel, true, PwmCommand::AssertLow);
+        played.and(silenced)
+    }
+
+    /// Drives `pin` with software PWM at `freq_hz` and `duty` (0.0-1.0) for `duration`,
+    /// for when no hardware PWM channel is free.
+    ///
+    /// The pin is set up as a GPIO output driving Low and toggled with
+    /// [`Xr2280x::gpio_write_fast`]; afterwards it is left as an output driving Low.
+    /// A duty of 0.0 or 1.0 holds the pin at a constant level. This call blocks for
+    /// `duration`.
+    ///
+    /// Each toggle is a USB round-trip of roughly 1-4 ms, so the edges jitter by about
+    /// that much and frequencies above [`SOFT_PWM_MAX_FREQ_HZ`] are rejected with
+    /// [`Error::ArgumentOutOfRange`]. This is enough to dim an LED (with visible
+    /// flicker at low frequencies) but not for servos or audio; use
+    /// [`Xr2280x::pwm_set_pin`] for those. Periods missed because of USB latency are
+    /// skipped rather than made up in a burst. A `duration` too long to represent as
+    /// a deadline, such as [`Duration::MAX`], runs until the process is stopped.
+    pub fn gpio_soft_pwm(
+        &self,
+        pin: GpioPin,
+        freq_hz: f64,
+        duty: f64,
+        duration: Duration,
+    ) -> Result<()> {
+        let (high, low) = soft_pwm_times(freq_hz, duty)?;
+        self.gpio_setup_output(pin, GpioLevel::Low, GpioPull::None)?;
+        debug!(
+            "Software PWM on pin {} at {freq_hz} Hz, duty {duty}",
+            pin.number()
+        );
+
+        // `None` when the deadline overflows: run without end
+        let end = Instant::now().checked_add(duration);
+        let toggled = if low.is_zero() || high.is_zero() {
+            self.gpio_write_fast(pin, GpioLevel::from(low.is_zero()))
+                .map(|()| {
+                    thread::sleep(end.map_or(Duration::MAX, |end| {
+                        end.saturating_duration_since(Instant::now())
+                    }))
+                })
+        } else {
+            self.soft_pwm_loop(pin, high, low, end)
+        };
+        let restored = self.gpio_write_fast(pin, GpioLevel::Low);
+        toggled.and(restored)
+    }
+
+    fn soft_pwm_loop(
+        &self,
+        pin: GpioPin,
+        high: Duration,
+        low: Duration,
+        end: Option<Instant>,
+    ) -> Result<()> {
+        let mut period_start = Instant::now();
+        while end.is_none_or(|end| period_start < end) {
+            self.gpio_write_fast(pin, GpioLevel::High)?;
+            thread::sleep((period_start + high).saturating_duration_since(Instant::now()));
+            self.gpio_write_fast(pin, GpioLevel::Low)?;
+            let next = period_start + high + low;
+            let next = end.map_or(next, |end| next.min(end));
+            thread::sleep(next.saturating_duration_since(Instant::now()));
+            period_start = next.max(Instant::now());
+        }
+        Ok(())
+    }
+
     /// Gets the current state of a PWM channel (enabled, command mode).
     pub fn pwm_get_control(&self, channel: PwmChannel) -> Result<(bool, PwmCommand)> {
-        let reg = match channel {
-            PwmChannel::Pwm0 => consts::edge::REG_PWM0_CTRL,
-            PwmChannel::Pwm1 => consts::edge::REG_PWM1_CTRL,
-        };
+        let reg = channel.ctrl_register();
         let value = self.read_pwm_register(channel, reg)?;
         let enabled = (value & consts::edge::pwm_ctrl::ENABLE_MASK) != 0;
         let cmd_raw =
@@ -266,3 +783,257 @@ impl Xr2280x {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_period_from_freq_duty() {
+        let period = PwmPeriod::from_freq_duty(1_000.0, 0.25).unwrap();
+        assert_eq!(period.high_units(), 938);
+        assert_eq!(period.low_units(), 2812);
+        assert!((period.frequency_hz() - 1_000.0).abs() < 0.01);
+        assert!((period.duty() - 0.25).abs() < 0.001);
+
+        assert!(PwmPeriod::from_freq_duty(1_000.0, 0.0).is_err());
+        assert!(PwmPeriod::from_freq_duty(1_000.0, 1.0).is_err());
+        assert!(PwmPeriod::from_freq_duty(1_000.0, f64::NAN).is_err());
+        assert!(PwmPeriod::from_freq_duty(1_000.0, 1.5).is_err());
+        assert!(PwmPeriod::from_freq_duty(-1.0, 0.5).is_err());
+        assert!(PwmPeriod::from_freq_duty(50.0, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_period_from_high_low_ns() {
+        let period = PwmPeriod::from_high_low_ns(100_000, 300_000).unwrap();
+        assert_eq!(period.high_units(), 375);
+        assert_eq!(period.low_units(), 1125);
+        assert_eq!(period.high_ns(), 100_000);
+        assert_eq!(period.low_ns(), 300_000);
+        assert!((period.frequency_hz() - 2_500.0).abs() < 0.01);
+        assert!((period.duty() - 0.25).abs() < 1e-9);
+
+        assert!(PwmPeriod::from_high_low_ns(0, 300_000).is_err());
+        assert!(PwmPeriod::from_high_low_ns(100_000, 2_000_000).is_err());
+    }
+
+    #[test]
+    fn test_period_from_pulse_width() {
+        let period = PwmPeriod::from_pulse_width(1_000_000, 200_000).unwrap();
+        assert_eq!(period.high_units(), 750);
+        assert_eq!(period.low_units(), 3000);
+        assert!((period.frequency_hz() - 1_000.0).abs() < 0.01);
+        assert!((period.duty() - 0.2).abs() < 1e-9);
+
+        assert!(PwmPeriod::from_pulse_width(1_000_000, 1_000_000).is_err());
+    }
+
+    #[test]
+    fn test_soft_pwm_times() {
+        let (high, low) = soft_pwm_times(20.0, 0.25).unwrap();
+        assert_eq!(high, Duration::from_micros(12_500));
+        assert_eq!(low, Duration::from_micros(37_500));
+
+        let (high, low) = soft_pwm_times(10.0, 0.0).unwrap();
+        assert_eq!((high, low), (Duration::ZERO, Duration::from_millis(100)));
+        let (high, low) = soft_pwm_times(10.0, 1.0).unwrap();
+        assert_eq!((high, low), (Duration::from_millis(100), Duration::ZERO));
+
+        assert!(soft_pwm_times(0.0, 0.5).is_err());
+        assert!(soft_pwm_times(SOFT_PWM_MAX_FREQ_HZ + 1.0, 0.5).is_err());
+        assert!(soft_pwm_times(f64::NAN, 0.5).is_err());
+        assert!(soft_pwm_times(10.0, 1.5).is_err());
+    }
+
+    #[test]
+    fn test_channel_all_and_registers() {
+        assert_eq!(PwmChannel::all(), [PwmChannel::Pwm0, PwmChannel::Pwm1]);
+        assert_eq!(
+            PwmChannel::Pwm1.ctrl_register(),
+            consts::edge::REG_PWM1_CTRL
+        );
+        assert_eq!(
+            PwmChannel::Pwm0.period_registers(),
+            (consts::edge::REG_PWM0_HIGH, consts::edge::REG_PWM0_LOW)
+        );
+    }
+
+    #[test]
+    fn test_disable_all_idles_both_channels() {
+        use crate::device::MockRegisterFile;
+        let running = consts::edge::pwm_ctrl::ENABLE_MASK
+            | (consts::edge::pwm_ctrl::CMD_FREE_RUN << consts::edge::pwm_ctrl::CMD_SHIFT);
+        let registers = MockRegisterFile::default()
+            .with_register(consts::edge::REG_PWM0_CTRL, running | 4)
+            .with_register(consts::edge::REG_PWM1_CTRL, running | 5);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        device.pwm_disable_all().unwrap();
+        // Enable cleared, command idle, pin assignment preserved
+        assert_eq!(
+            registers.writes(),
+            vec![
+                (consts::edge::REG_PWM0_CTRL, 4),
+                (consts::edge::REG_PWM1_CTRL, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disable_all_attempts_both_channels_on_error() {
+        use crate::device::MockRegisterFile;
+        let registers =
+            MockRegisterFile::default().with_failing_register(consts::edge::REG_PWM0_CTRL);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+        let result = device.pwm_disable_all();
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert_eq!(registers.writes(), vec![(consts::edge::REG_PWM1_CTRL, 0)]);
+    }
+
+    #[test]
+    fn test_pwm_pin_driven_by_other_channel_is_rejected() {
+        let pin = GpioPin::new(3).unwrap();
+        assert!(matches!(
+            validate_pwm_pin(PwmChannel::Pwm0, pin, PinFunction::Pwm1),
+            Err(Error::PinFunctionConflict {
+                pin: 3,
+                current: PinFunction::Pwm1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            validate_pwm_pin(PwmChannel::Pwm1, pin, PinFunction::Pwm0),
+            Err(Error::PinFunctionConflict {
+                current: PinFunction::Pwm0,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_pwm_pin_assigned_to_other_channel_is_rejected() {
+        let pin3 = GpioPin::new(3).unwrap();
+        let mut assignments = PwmPinAssignments::default();
+        assert_eq!(
+            assignments.other_channel_function(PwmChannel::Pwm0, pin3),
+            None
+        );
+        assignments.record(PwmChannel::Pwm0, pin3);
+        assert_eq!(assignments.get(PwmChannel::Pwm0), Some(pin3));
+        assert_eq!(assignments.get(PwmChannel::Pwm1), None);
+
+        // Reassigning within the same channel is fine
+        assert_eq!(
+            assignments.other_channel_function(PwmChannel::Pwm0, pin3),
+            None
+        );
+        assert_eq!(
+            assignments.other_channel_function(PwmChannel::Pwm1, pin3),
+            Some(PinFunction::Pwm0)
+        );
+
+        // Once PWM0 moves to another pin, pin 3 is free for PWM1
+        assignments.record(PwmChannel::Pwm0, GpioPin::new(4).unwrap());
+        assert_eq!(
+            assignments.other_channel_function(PwmChannel::Pwm1, pin3),
+            None
+        );
+
+        // The assignment is known without I/O and fails like a device-reported conflict
+        let device = Xr2280x::new_for_test(8);
+        device
+            .pwm_pins
+            .lock()
+            .unwrap()
+            .record(PwmChannel::Pwm1, pin3);
+        assert_eq!(device.pwm_get_assigned_pin(PwmChannel::Pwm1), Some(pin3));
+        assert!(matches!(
+            device.pwm_set_pin(PwmChannel::Pwm0, pin3),
+            Err(Error::PinFunctionConflict {
+                pin: 3,
+                current: PinFunction::Pwm1,
+                ref message,
+            }) if message.contains("Pwm1")
+        ));
+    }
+
+    #[test]
+    fn test_pwm_free_pin_is_accepted() {
+        let pin = GpioPin::new(3).unwrap();
+        for function in [
+            PinFunction::Unassigned,
+            PinFunction::Gpio,
+            PinFunction::Pwm0,
+        ] {
+            assert!(validate_pwm_pin(PwmChannel::Pwm0, pin, function).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_resolution_50hz_is_below_range() {
+        let res = pwm_frequency_resolution(50.0);
+        assert!(res.clamped);
+        assert_eq!(res.period_units, 8190);
+        assert_eq!(res.duty_cycle_steps, 1);
+        assert!(res.actual_hz > 450.0 && res.actual_hz < 460.0);
+    }
+
+    #[test]
+    fn test_resolution_1khz() {
+        let res = pwm_frequency_resolution(1_000.0);
+        assert!(!res.clamped);
+        assert_eq!(res.period_units, 3750);
+        assert_eq!(res.duty_cycle_steps, 3749);
+        assert!(res.frequency_error_percent().unwrap().abs() < 0.01);
+    }
+
+    #[test]
+    fn test_resolution_20khz() {
+        let res = pwm_frequency_resolution(20_000.0);
+        assert!(!res.clamped);
+        assert!((187..=188).contains(&res.period_units));
+        assert!((186..=187).contains(&res.duty_cycle_steps));
+        assert!(res.frequency_error_percent().unwrap().abs() < 0.5);
+    }
+
+    #[test]
+    fn test_resolution_above_range() {
+        let res = pwm_frequency_resolution(10_000_000.0);
+        assert!(res.clamped);
+        assert_eq!(res.period_units, 2);
+        assert_eq!(res.duty_cycle_steps, 1);
+    }
+
+    #[test]
+    fn test_resolution_zero_hz_has_no_frequency_error() {
+        let res = pwm_frequency_resolution(0.0);
+        assert!(res.clamped);
+        assert!(matches!(
+            res.frequency_error_percent(),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_tone_periods() {
+        assert_eq!(tone_period(0.0).unwrap(), None);
+
+        let a5 = tone_period(880.0).unwrap().unwrap();
+        assert_eq!((a5.high_units(), a5.low_units()), (2131, 2130));
+        assert!((a5.frequency_hz() - 880.0).abs() < 0.2);
+
+        let khz = tone_period(1_000.0).unwrap().unwrap();
+        assert_eq!((khz.high_units(), khz.low_units()), (1875, 1875));
+
+        // An odd number of units can't be split evenly
+        let high = tone_period(2_000.0).unwrap().unwrap();
+        assert_eq!((high.high_units(), high.low_units()), (938, 937));
+
+        // A4 needs 4261 units per half period, more than the hardware allows
+        assert!(matches!(
+            tone_period(440.0),
+            Err(Error::PwmParameterError { .. })
+        ));
+        assert!(tone_period(-1.0).is_err());
+    }
+}
@@ -0,0 +1,305 @@
+//! RGB LED helper driving three GPIO pins with PWM.
+//!
+//! The XR2280x has only two hardware PWM channels, so [`RgbLed`] drives red and green
+//! with `PWM0` and `PWM1` and blue with software PWM: a worker thread toggles the
+//! blue pin through ordinary GPIO writes.
+//!
+//! Software PWM runs at a 50 Hz frame because every toggle is a USB round-trip, and
+//! its timing depends on the host scheduler and USB latency. Intermediate blue
+//! levels therefore flicker visibly and have coarse, slightly jittery brightness
+//! steps, while 0 and 255 are steady. Wire the color that needs smooth dimming least
+//! to the third pin.
+//!
+//! ```no_run
+//! use xr2280x_hid::{GpioPin, Xr2280x};
+//! # use hidapi::HidApi;
+//!
+//! # fn main() -> xr2280x_hid::Result<()> {
+//! # let hid_api = HidApi::new()?;
+//! let device = Xr2280x::device_open_first(&hid_api)?;
+//! let led = device.rgb_led(GpioPin::new(0)?, GpioPin::new(1)?, GpioPin::new(2)?)?;
+//!
+//! led.set_color(255, 128, 0)?; // orange
+//! std::thread::sleep(std::time::Duration::from_secs(1));
+//!
+//! // Turn the LED off and get the device back
+//! let device = led.release()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::device::Xr2280x;
+use crate::error::{Error, Result};
+use crate::gpio::{GpioLevel, GpioPin, GpioPull};
+use crate::pwm::{PwmChannel, PwmCommand, PwmPeriod};
+use log::{debug, warn};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Hardware PWM frame length in PWM units (1 kHz).
+const HARDWARE_PERIOD_UNITS: u16 = 3750;
+
+/// Software PWM frame length (50 Hz).
+const SOFTWARE_PERIOD: Duration = Duration::from_millis(20);
+
+/// A color change sent to the worker thread, with a channel for its result.
+struct SetColor {
+    color: [u8; 3],
+    reply: mpsc::Sender<Result<()>>,
+}
+
+/// An RGB LED on three GPIO pins, created by [`Xr2280x::rgb_led`].
+///
+/// The device handle is moved to a worker thread that owns it for the lifetime of the
+/// LED; [`RgbLed::release`] turns the LED off and returns it. Dropping the `RgbLed`
+/// turns the LED off and closes the device.
+#[derive(Debug)]
+pub struct RgbLed {
+    commands: Option<mpsc::Sender<SetColor>>,
+    worker: Option<thread::JoinHandle<Xr2280x>>,
+}
+
+impl Xr2280x {
+    /// Turns this device into an [`RgbLed`] on the given red, green and blue pins.
+    ///
+    /// Red is driven by `PWM0` and green by `PWM1` at 1 kHz; blue uses software PWM
+    /// (see the [module documentation](crate::rgb_led) for the flicker tradeoff). All
+    /// three pins are configured as outputs without pulls and the LED starts off.
+    /// The pins must be distinct; the checks of [`Xr2280x::pwm_set_pin`] apply to red
+    /// and green.
+    ///
+    /// On error the device is dropped; reopen it to continue.
+    pub fn rgb_led(self, red: GpioPin, green: GpioPin, blue: GpioPin) -> Result<RgbLed> {
+        if red == green || red == blue || green == blue {
+            return Err(Error::ArgumentOutOfRange(format!(
+                "RGB LED pins must be distinct (got {}, {}, {})",
+                red.number(),
+                green.number(),
+                blue.number()
+            )));
+        }
+        for (channel, pin) in [(PwmChannel::Pwm0, red), (PwmChannel::Pwm1, green)] {
+            self.gpio_setup_output(pin, GpioLevel::Low, GpioPull::None)?;
+            self.pwm_set_pin(channel, pin)?;
+            set_hardware_value(&self, channel, 0)?;
+        }
+        self.gpio_setup_output(blue, GpioLevel::Low, GpioPull::None)?;
+        debug!(
+            "RGB LED on pins {}, {}, {} (blue uses software PWM)",
+            red.number(),
+            green.number(),
+            blue.number()
+        );
+
+        let (commands, receiver) = mpsc::channel();
+        let worker = thread::Builder::new()
+            .name("xr2280x-rgb-led".to_string())
+            .spawn(move || run_worker(self, blue, receiver))?;
+        Ok(RgbLed {
+            commands: Some(commands),
+            worker: Some(worker),
+        })
+    }
+}
+
+impl RgbLed {
+    /// Sets the color, each component from 0 (off) to 255 (full brightness).
+    ///
+    /// If a software PWM write failed since the last call, that error is returned
+    /// instead and the color is left unchanged.
+    pub fn set_color(&self, red: u8, green: u8, blue: u8) -> Result<()> {
+        let (reply, result) = mpsc::channel();
+        self.commands
+            .as_ref()
+            .ok_or_else(worker_stopped)?
+            .send(SetColor {
+                color: [red, green, blue],
+                reply,
+            })
+            .map_err(|_| worker_stopped())?;
+        result.recv().map_err(|_| worker_stopped())?
+    }
+
+    /// Turns the LED off, stops the worker thread and returns the device.
+    ///
+    /// Both PWM channels are disabled and all three pins are left as outputs driving Low.
+    pub fn release(mut self) -> Result<Xr2280x> {
+        self.stop().ok_or_else(worker_stopped)
+    }
+
+    fn stop(&mut self) -> Option<Xr2280x> {
+        // Closing the channel makes the worker turn the LED off and exit
+        self.commands.take();
+        self.worker.take()?.join().ok()
+    }
+}
+
+impl Drop for RgbLed {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Hardware PWM period for an 8-bit value, or `None` for 0 (output held low).
+///
+/// The high time is rounded to whole PWM units and kept within 1 unit of either end,
+/// since the hardware needs a non-zero low time.
+fn hardware_period(value: u8) -> Option<PwmPeriod> {
+    if value == 0 {
+        return None;
+    }
+    let total = u32::from(HARDWARE_PERIOD_UNITS);
+    let high = ((total * u32::from(value) + 127) / 255).clamp(1, total - 1) as u16;
+    PwmPeriod::from_units(high, HARDWARE_PERIOD_UNITS - high).ok()
+}
+
+/// Software PWM high time per frame for an 8-bit value.
+fn software_on_time(value: u8) -> Duration {
+    SOFTWARE_PERIOD * u32::from(value) / 255
+}
+
+fn set_hardware_value(device: &Xr2280x, channel: PwmChannel, value: u8) -> Result<()> {
+    match hardware_period(value) {
+        Some(period) => {
+            device.pwm_apply(channel, period)?;
+            device.pwm_control(channel, true, PwmCommand::FreeRun)
+        }
+        None => device.pwm_control(channel, true, PwmCommand::AssertLow),
+    }
+}
+
+/// Software PWM state of the blue pin.
+struct SoftwarePwm {
+    pin: GpioPin,
+    on_time: Duration,
+    high: bool,
+    /// When to toggle next; `None` while the level is constant.
+    next_toggle: Option<Instant>,
+}
+
+impl SoftwarePwm {
+    fn set_value(&mut self, device: &Xr2280x, value: u8) -> Result<()> {
+        self.on_time = software_on_time(value);
+        self.high = value != 0;
+        self.next_toggle = None;
+        device.gpio_write_fast(self.pin, GpioLevel::from(self.high))?;
+        if self.on_time > Duration::ZERO && self.on_time < SOFTWARE_PERIOD {
+            self.next_toggle = Some(Instant::now() + self.on_time);
+        }
+        Ok(())
+    }
+
+    fn toggle(&mut self, device: &Xr2280x) -> Result<()> {
+        let Some(due) = self.next_toggle else {
+            return Ok(());
+        };
+        self.high = !self.high;
+        let phase = if self.high {
+            self.on_time
+        } else {
+            SOFTWARE_PERIOD - self.on_time
+        };
+        // Skip missed frames instead of toggling in a burst to catch up
+        let now = Instant::now();
+        self.next_toggle = Some(if now > due + SOFTWARE_PERIOD {
+            now + phase
+        } else {
+            due + phase
+        });
+        device.gpio_write_fast(self.pin, GpioLevel::from(self.high))
+    }
+}
+
+/// Handles color changes and software PWM toggles until the command channel closes.
+fn run_worker(device: Xr2280x, blue: GpioPin, commands: mpsc::Receiver<SetColor>) -> Xr2280x {
+    let mut software = SoftwarePwm {
+        pin: blue,
+        on_time: Duration::ZERO,
+        high: false,
+        next_toggle: None,
+    };
+    let mut toggle_error = None;
+
+    loop {
+        let received = match software.next_toggle {
+            Some(due) => commands.recv_timeout(due.saturating_duration_since(Instant::now())),
+            None => commands.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(SetColor { color, reply }) => {
+                let result = match toggle_error.take() {
+                    Some(e) => Err(e),
+                    None => apply_color(&device, &mut software, color),
+                };
+                let _ = reply.send(result);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if let Err(e) = software.toggle(&device) {
+                    warn!("Software PWM write to pin {} failed: {e}", blue.number());
+                    software.next_toggle = None;
+                    toggle_error = Some(e);
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    debug!("Turning RGB LED off");
+    if let Err(e) = device.gpio_write_fast(blue, GpioLevel::Low) {
+        warn!("Failed to turn off software PWM pin {}: {e}", blue.number());
+    }
+    for channel in PwmChannel::all() {
+        if let Err(e) = device.pwm_control(channel, false, PwmCommand::Idle) {
+            warn!("Failed to disable {channel:?}: {e}");
+        }
+    }
+    device
+}
+
+fn apply_color(device: &Xr2280x, software: &mut SoftwarePwm, color: [u8; 3]) -> Result<()> {
+    let [red, green, blue] = color;
+    debug!("Setting RGB LED color to ({red}, {green}, {blue})");
+    set_hardware_value(device, PwmChannel::Pwm0, red)?;
+    set_hardware_value(device, PwmChannel::Pwm1, green)?;
+    software.set_value(device, blue)
+}
+
+fn worker_stopped() -> Error {
+    Error::DeviceDisconnected {
+        message: "RGB LED worker thread has stopped".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duty_from_color_value() {
+        assert!(hardware_period(0).is_none());
+
+        let full = hardware_period(255).unwrap();
+        assert_eq!((full.high_units(), full.low_units()), (3749, 1));
+
+        let half = hardware_period(128).unwrap();
+        assert_eq!((half.high_units(), half.low_units()), (1882, 1868));
+        assert!((half.frequency_hz() - 1000.0).abs() < 0.1);
+
+        // The smallest non-zero value still produces a pulse
+        let dim = hardware_period(1).unwrap();
+        assert_eq!((dim.high_units(), dim.low_units()), (15, 3735));
+
+        assert_eq!(software_on_time(0), Duration::ZERO);
+        assert_eq!(software_on_time(51), Duration::from_millis(4));
+        assert_eq!(software_on_time(255), SOFTWARE_PERIOD);
+    }
+
+    #[test]
+    fn test_rgb_led_rejects_duplicate_pins() {
+        let device = Xr2280x::new_for_test(8);
+        let pin = |n| GpioPin::new(n).unwrap();
+        let result = device.rgb_led(pin(0), pin(1), pin(0));
+        assert!(matches!(result, Err(Error::ArgumentOutOfRange(_))));
+    }
+}
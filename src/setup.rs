@@ -0,0 +1,449 @@
+//! Declarative device setup spanning I2C, GPIO and PWM.
+//!
+//! A [`DeviceSetup`], created by [`Xr2280x::setup`], collects the startup configuration
+//! of a device and applies it in one call, in an order where every step's prerequisites
+//! are already in place:
+//!
+//! 1. I2C bus speed.
+//! 2. EDGE function assignment of every GPIO and PWM pin.
+//! 3. Pull resistors of the GPIO pins.
+//! 4. Output levels, written while the pins are still in their previous direction so
+//!    they start driving the right level.
+//! 5. Directions.
+//! 6. PWM pin assignment, then periods, then enabling each channel.
+//!
+//! ```no_run
+//! use xr2280x_hid::{GpioLevel, GpioPin, GpioPull, PwmChannel, PwmCommand, PwmPeriod, Xr2280x};
+//! # use hidapi::HidApi;
+//!
+//! # fn main() -> xr2280x_hid::Result<()> {
+//! # let hid_api = HidApi::new()?;
+//! let device = Xr2280x::device_open_first(&hid_api)?;
+//! let steps = device
+//!     .setup()
+//!     .i2c_speed_khz(400)
+//!     .gpio_output(GpioPin::new(0)?, GpioLevel::Low, GpioPull::None)
+//!     .gpio_input(GpioPin::new(1)?, GpioPull::Up)
+//!     .pwm(
+//!         PwmChannel::Pwm0,
+//!         GpioPin::new(2)?,
+//!         PwmPeriod::from_freq_duty(1000.0, 0.5)?,
+//!         PwmCommand::FreeRun,
+//!     )
+//!     .apply()?;
+//! println!("Applied {} setup steps", steps.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::device::Xr2280x;
+use crate::error::{Error, Result};
+use crate::gpio::{GpioDirection, GpioLevel, GpioPin, GpioPull};
+use crate::pwm::{PwmChannel, PwmCommand, PwmPeriod};
+use log::debug;
+
+/// A single configuration step performed by [`DeviceSetup::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupStep {
+    /// Set the I2C bus speed.
+    I2cSpeed {
+        /// The speed in kHz.
+        speed_khz: u32,
+    },
+    /// Assign a pin to the EDGE controller.
+    AssignToEdge {
+        /// The pin being assigned.
+        pin: GpioPin,
+    },
+    /// Set a pin's pull resistors.
+    Pull {
+        /// The pin being configured.
+        pin: GpioPin,
+        /// The pull to apply.
+        pull: GpioPull,
+    },
+    /// Write a pin's output level.
+    Level {
+        /// The pin being written.
+        pin: GpioPin,
+        /// The level to drive.
+        level: GpioLevel,
+    },
+    /// Set a pin's direction.
+    Direction {
+        /// The pin being configured.
+        pin: GpioPin,
+        /// The new direction.
+        direction: GpioDirection,
+    },
+    /// Route a PWM channel to a pin.
+    PwmPin {
+        /// The channel being routed.
+        channel: PwmChannel,
+        /// The pin it drives.
+        pin: GpioPin,
+    },
+    /// Set a PWM channel's high and low times.
+    PwmPeriod {
+        /// The channel being configured.
+        channel: PwmChannel,
+        /// The period to apply.
+        period: PwmPeriod,
+    },
+    /// Enable a PWM channel with a command.
+    PwmEnable {
+        /// The channel being enabled.
+        channel: PwmChannel,
+        /// The command to run.
+        command: PwmCommand,
+    },
+}
+
+/// Queued configuration of one GPIO pin.
+#[derive(Debug, Clone, Copy)]
+struct GpioSetup {
+    pin: GpioPin,
+    pull: GpioPull,
+    /// Initial level for outputs; `None` for inputs.
+    level: Option<GpioLevel>,
+}
+
+/// Queued configuration of one PWM channel.
+#[derive(Debug, Clone, Copy)]
+struct PwmSetup {
+    pin: GpioPin,
+    period: PwmPeriod,
+    command: PwmCommand,
+}
+
+/// A queued device configuration, created by [`Xr2280x::setup`].
+///
+/// Queuing performs no device communication. Queuing the same pin or PWM channel
+/// again replaces its earlier configuration. See the
+/// [module documentation](crate::setup) for the order [`apply`](Self::apply) uses.
+#[derive(Debug)]
+#[must_use = "a DeviceSetup does nothing until apply() is called"]
+pub struct DeviceSetup<'a> {
+    device: &'a Xr2280x,
+    i2c_speed_khz: Option<u32>,
+    gpio: Vec<GpioSetup>,
+    pwm: [Option<PwmSetup>; 2],
+}
+
+impl Xr2280x {
+    /// Starts a [`DeviceSetup`] for configuring I2C, GPIO and PWM in one block.
+    pub fn setup(&self) -> DeviceSetup<'_> {
+        DeviceSetup {
+            device: self,
+            i2c_speed_khz: None,
+            gpio: Vec::new(),
+            pwm: [None; 2],
+        }
+    }
+}
+
+impl<'a> DeviceSetup<'a> {
+    /// Queues the I2C bus speed, as for [`Xr2280x::i2c_set_speed_khz`].
+    pub fn i2c_speed_khz(mut self, speed_khz: u32) -> Self {
+        self.i2c_speed_khz = Some(speed_khz);
+        self
+    }
+
+    /// Queues `pin` as an output starting at `level`.
+    pub fn gpio_output(self, pin: GpioPin, level: GpioLevel, pull: GpioPull) -> Self {
+        self.with_gpio(GpioSetup {
+            pin,
+            pull,
+            level: Some(level),
+        })
+    }
+
+    /// Queues `pin` as an input.
+    pub fn gpio_input(self, pin: GpioPin, pull: GpioPull) -> Self {
+        self.with_gpio(GpioSetup {
+            pin,
+            pull,
+            level: None,
+        })
+    }
+
+    /// Queues `channel` to drive `pin` with `period`, enabled with `command`.
+    ///
+    /// The pin is made an output driving Low before the channel is routed to it, as
+    /// expected by [`Xr2280x::pwm_set_pin`].
+    pub fn pwm(
+        mut self,
+        channel: PwmChannel,
+        pin: GpioPin,
+        period: PwmPeriod,
+        command: PwmCommand,
+    ) -> Self {
+        self.pwm[channel_index(channel)] = Some(PwmSetup {
+            pin,
+            period,
+            command,
+        });
+        self
+    }
+
+    fn with_gpio(mut self, setup: GpioSetup) -> Self {
+        match self.gpio.iter_mut().find(|queued| queued.pin == setup.pin) {
+            Some(queued) => *queued = setup,
+            None => self.gpio.push(setup),
+        }
+        self
+    }
+
+    /// Returns the steps [`apply`](Self::apply) would perform, without touching the device.
+    ///
+    /// Returns [`Error::ArgumentOutOfRange`] if a pin is queued both as a GPIO and for
+    /// PWM, or for both PWM channels.
+    pub fn plan(&self) -> Result<Vec<SetupStep>> {
+        let pwm: Vec<(PwmChannel, PwmSetup)> = PwmChannel::all()
+            .into_iter()
+            .filter_map(|channel| Some((channel, self.pwm[channel_index(channel)]?)))
+            .collect();
+        if let [(_, first), (_, second)] = pwm.as_slice()
+            && first.pin == second.pin
+        {
+            return Err(pin_conflict(first.pin, "both PWM channels"));
+        }
+        if let Some((_, setup)) = pwm
+            .iter()
+            .find(|(_, setup)| self.gpio.iter().any(|gpio| gpio.pin == setup.pin))
+        {
+            return Err(pin_conflict(setup.pin, "both GPIO and PWM"));
+        }
+
+        let mut steps = Vec::new();
+        if let Some(speed_khz) = self.i2c_speed_khz {
+            steps.push(SetupStep::I2cSpeed { speed_khz });
+        }
+        let gpio_pins = self.gpio.iter().map(|gpio| gpio.pin);
+        let pwm_pins = pwm.iter().map(|(_, setup)| setup.pin);
+        steps.extend(
+            gpio_pins
+                .chain(pwm_pins.clone())
+                .map(|pin| SetupStep::AssignToEdge { pin }),
+        );
+        steps.extend(
+            self.gpio
+                .iter()
+                .map(|gpio| SetupStep::Pull {
+                    pin: gpio.pin,
+                    pull: gpio.pull,
+                })
+                .chain(pwm_pins.clone().map(|pin| SetupStep::Pull {
+                    pin,
+                    pull: GpioPull::None,
+                })),
+        );
+        steps.extend(
+            self.gpio
+                .iter()
+                .filter_map(|gpio| Some((gpio.pin, gpio.level?)))
+                .chain(pwm_pins.clone().map(|pin| (pin, GpioLevel::Low)))
+                .map(|(pin, level)| SetupStep::Level { pin, level }),
+        );
+        steps.extend(
+            self.gpio
+                .iter()
+                .map(|gpio| SetupStep::Direction {
+                    pin: gpio.pin,
+                    direction: match gpio.level {
+                        Some(_) => GpioDirection::Output,
+                        None => GpioDirection::Input,
+                    },
+                })
+                .chain(pwm_pins.map(|pin| SetupStep::Direction {
+                    pin,
+                    direction: GpioDirection::Output,
+                })),
+        );
+        for &(channel, setup) in &pwm {
+            steps.push(SetupStep::PwmPin {
+                channel,
+                pin: setup.pin,
+            });
+        }
+        for &(channel, setup) in &pwm {
+            steps.push(SetupStep::PwmPeriod {
+                channel,
+                period: setup.period,
+            });
+        }
+        for &(channel, setup) in &pwm {
+            steps.push(SetupStep::PwmEnable {
+                channel,
+                command: setup.command,
+            });
+        }
+        Ok(steps)
+    }
+
+    /// Applies the queued configuration and returns the steps performed.
+    ///
+    /// Conflicting pins are rejected as in [`plan`](Self::plan) before anything is
+    /// written. If a step fails its error is returned and the remaining steps are
+    /// skipped; the earlier steps stay applied.
+    pub fn apply(self) -> Result<Vec<SetupStep>> {
+        let steps = self.plan()?;
+        let device = self.device;
+        debug!("Applying device setup with {} steps", steps.len());
+        for step in &steps {
+            apply_step(device, step).inspect_err(|e| debug!("Setup step {step:?} failed: {e}"))?;
+        }
+        Ok(steps)
+    }
+}
+
+fn apply_step(device: &Xr2280x, step: &SetupStep) -> Result<()> {
+    match *step {
+        SetupStep::I2cSpeed { speed_khz } => device.i2c_set_speed_khz(speed_khz),
+        SetupStep::AssignToEdge { pin } => device.gpio_assign_to_edge(pin),
+        SetupStep::Pull { pin, pull } => device.gpio_set_pull(pin, pull),
+        SetupStep::Level { pin, level } => device.gpio_write_fast(pin, level),
+        SetupStep::Direction { pin, direction } => device.gpio_set_direction(pin, direction),
+        SetupStep::PwmPin { channel, pin } => device.pwm_set_pin(channel, pin),
+        SetupStep::PwmPeriod { channel, period } => device.pwm_apply(channel, period),
+        SetupStep::PwmEnable { channel, command } => device.pwm_control(channel, true, command),
+    }
+}
+
+fn channel_index(channel: PwmChannel) -> usize {
+    match channel {
+        PwmChannel::Pwm0 => 0,
+        PwmChannel::Pwm1 => 1,
+    }
+}
+
+fn pin_conflict(pin: GpioPin, uses: &str) -> Error {
+    Error::ArgumentOutOfRange(format!(
+        "Pin {} is queued for {uses} in the device setup",
+        pin.number()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_plan_order() {
+        let device = Xr2280x::new_for_test(32);
+        let pin = |n| GpioPin::new(n).unwrap();
+        let period = crate::pwm::PwmPeriod::from_units(100, 200).unwrap();
+
+        let setup = device
+            .setup()
+            .pwm(PwmChannel::Pwm1, pin(7), period, PwmCommand::FreeRun)
+            .gpio_input(pin(3), GpioPull::Up)
+            .gpio_output(pin(20), GpioLevel::High, GpioPull::None)
+            .gpio_input(pin(3), GpioPull::Down) // Replaces the first entry
+            .i2c_speed_khz(400);
+
+        use SetupStep::*;
+        assert_eq!(
+            setup.plan().unwrap(),
+            vec![
+                I2cSpeed { speed_khz: 400 },
+                AssignToEdge { pin: pin(3) },
+                AssignToEdge { pin: pin(20) },
+                AssignToEdge { pin: pin(7) },
+                Pull {
+                    pin: pin(3),
+                    pull: GpioPull::Down
+                },
+                Pull {
+                    pin: pin(20),
+                    pull: GpioPull::None
+                },
+                Pull {
+                    pin: pin(7),
+                    pull: GpioPull::None
+                },
+                Level {
+                    pin: pin(20),
+                    level: GpioLevel::High
+                },
+                Level {
+                    pin: pin(7),
+                    level: GpioLevel::Low
+                },
+                Direction {
+                    pin: pin(3),
+                    direction: GpioDirection::Input
+                },
+                Direction {
+                    pin: pin(20),
+                    direction: GpioDirection::Output
+                },
+                Direction {
+                    pin: pin(7),
+                    direction: GpioDirection::Output
+                },
+                PwmPin {
+                    channel: PwmChannel::Pwm1,
+                    pin: pin(7)
+                },
+                PwmPeriod {
+                    channel: PwmChannel::Pwm1,
+                    period
+                },
+                PwmEnable {
+                    channel: PwmChannel::Pwm1,
+                    command: PwmCommand::FreeRun
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_setup_apply_stops_at_first_failure() {
+        use crate::device::MockRegisterFile;
+        let pin = |n| GpioPin::new(n).unwrap();
+        // Pin 3's direction step fails, pin 20's comes after it
+        let registers =
+            MockRegisterFile::default().with_failing_register(crate::consts::edge::REG_DIR_0);
+        let device = Xr2280x::new_for_test(32).with_register_transport(registers.clone());
+
+        let result = device
+            .setup()
+            .gpio_input(pin(3), GpioPull::Up)
+            .gpio_output(pin(20), GpioLevel::High, GpioPull::None)
+            .apply();
+        assert!(matches!(result, Err(Error::Timeout)));
+        let written: Vec<u16> = registers.writes().iter().map(|&(reg, _)| reg).collect();
+        assert!(written.contains(&crate::consts::edge::REG_SET_1));
+        assert!(!written.contains(&crate::consts::edge::REG_DIR_1));
+    }
+
+    #[test]
+    fn test_setup_rejects_pin_conflicts() {
+        let device = Xr2280x::new_for_test(32);
+        let pin = GpioPin::new(4).unwrap();
+        let period = PwmPeriod::from_units(100, 200).unwrap();
+
+        let both_channels = device
+            .setup()
+            .pwm(PwmChannel::Pwm0, pin, period, PwmCommand::FreeRun)
+            .pwm(PwmChannel::Pwm1, pin, period, PwmCommand::FreeRun);
+        assert!(matches!(
+            both_channels.plan(),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+
+        let gpio_and_pwm = device.setup().gpio_input(pin, GpioPull::None).pwm(
+            PwmChannel::Pwm0,
+            pin,
+            period,
+            PwmCommand::FreeRun,
+        );
+        assert!(matches!(
+            gpio_and_pwm.apply(),
+            Err(Error::ArgumentOutOfRange(_))
+        ));
+
+        assert_eq!(device.setup().apply().unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,27 @@
+//! Integration tests for the async device wrapper (`async` feature).
+//!
+//! These tests require XR2280x hardware and are marked with #[ignore].
+#![cfg(feature = "async")]
+
+use xr2280x_hid::{AsyncXr2280x, GpioDirection, GpioPin, Result};
+
+#[tokio::test]
+#[ignore] // Requires hardware
+async fn test_async_scan_and_gpio() -> Result<()> {
+    let device = AsyncXr2280x::open_first().await?;
+
+    let found = device.i2c_scan_default().await?;
+    println!("Found {} I2C devices: {found:02X?}", found.len());
+
+    let pin = GpioPin::new(0)?;
+    device.gpio_set_direction(pin, GpioDirection::Input).await?;
+    let level = device.gpio_read(pin).await?;
+    println!("Pin 0 reads {level:?}");
+
+    // Clones share the same worker and device
+    let clone = device.clone();
+    let (a, b) = tokio::join!(device.gpio_read(pin), clone.gpio_read(pin));
+    a?;
+    b?;
+    Ok(())
+}
@@ -148,3 +148,117 @@ fn test_pwm_basic_output() -> Result<()> {
 // - I2C read/write with a specific device
 // - PWM one-shot mode
 // - GPIO interrupt configuration + read_gpio_interrupt_report (hard to automate verification)
+
+#[test]
+#[ignore] // Ignore by default, requires hardware
+fn test_gpio_drop_behavior_restores_inputs() -> Result<()> {
+    let pin = GpioPin::new(0)?;
+    {
+        let device = open_test_device();
+        device.gpio_setup_output(pin, GpioLevel::High, GpioPull::None)?;
+        device.gpio_set_drop_behavior(xr2280x_hid::DropBehavior::RestoreInputs)?;
+    } // Drop applies the configured behavior
+
+    let device = open_test_device();
+    assert_eq!(
+        device.gpio_get_direction(pin)?,
+        GpioDirection::Input,
+        "Pin should be an input after the previous handle was dropped"
+    );
+    Ok(())
+}
+
+#[test]
+#[ignore] // Ignore by default, requires hardware
+fn test_interrupt_monitor_restores_config() -> Result<()> {
+    let device = open_test_device();
+    let pin = GpioPin::new(1)?;
+    device.gpio_setup_input(pin, GpioPull::Up)?;
+    let before = device.gpio_get_interrupt_edges(pin)?;
+
+    {
+        let mut monitor = device.monitor_interrupts(&[(pin, xr2280x_hid::GpioEdge::Falling)])?;
+        assert_eq!(device.gpio_get_interrupt_edges(pin)?, (false, true));
+        // Nothing drives the pin, so no event is expected
+        println!(
+            "Event: {:?}",
+            monitor.next_event(Duration::from_millis(100))?
+        );
+    } // Drop restores the previous configuration
+
+    assert_eq!(device.gpio_get_interrupt_edges(pin)?, before);
+    Ok(())
+}
+
+#[test]
+#[ignore] // Ignore by default, requires hardware
+fn test_config_persistence_unsupported() -> Result<()> {
+    let device = open_test_device();
+    assert!(matches!(
+        device.device_save_config(),
+        Err(xr2280x_hid::Error::UnsupportedFeature(_))
+    ));
+    assert!(matches!(
+        device.device_load_config(),
+        Err(xr2280x_hid::Error::UnsupportedFeature(_))
+    ));
+    Ok(())
+}
+
+#[test]
+#[ignore] // Ignore by default, requires hardware
+fn test_device_identify_restores_pin() -> Result<()> {
+    let device = open_test_device();
+    let pin = GpioPin::new(0)?;
+    device.gpio_assign_to_edge(pin)?;
+    device.gpio_setup_input(pin, GpioPull::Up)?;
+
+    // Watch the board: an LED on E0 should blink for about two seconds
+    device.device_identify(pin, Duration::from_secs(2))?;
+    assert_eq!(device.gpio_get_direction(pin)?, GpioDirection::Input);
+    assert_eq!(device.gpio_get_pull(pin)?, GpioPull::Up);
+
+    device.gpio_setup_output(pin, GpioLevel::High, GpioPull::None)?;
+    device.device_identify(pin, Duration::from_secs(1))?;
+    assert_eq!(device.gpio_get_direction(pin)?, GpioDirection::Output);
+    assert_eq!(device.gpio_read(pin)?, GpioLevel::High);
+    Ok(())
+}
+
+#[test]
+#[ignore] // Ignore by default, requires hardware
+fn test_i2c_read_registers_across_reports() -> Result<()> {
+    let device = open_test_device();
+    let eeprom = 0x50; // CHANGE THIS to a 24C02-style EEPROM (8-bit word address) on your bus
+    device.i2c_set_speed_khz(100)?;
+
+    // 64 bytes take two IN reports, the second continuing the first without START
+    let block = device.i2c_read_registers(eeprom, 0x00, 64)?;
+    assert_eq!(block.len(), 64);
+
+    // Reads that fit a single report don't depend on the continuation
+    let first = device.i2c_read_registers(eeprom, 0x00, 32)?;
+    let second = device.i2c_read_registers(eeprom, 0x20, 32)?;
+    assert_eq!(&block[..32], &first[..]);
+    assert_eq!(&block[32..], &second[..]);
+    Ok(())
+}
+
+#[test]
+#[ignore] // Ignore by default, requires hardware
+fn test_i2c_read_10bit_repeated_start() -> Result<()> {
+    let device = open_test_device();
+    let target = 0x250; // CHANGE THIS to a 10-bit device with an 8-bit register pointer
+    device.i2c_set_speed_khz(100)?;
+
+    device.i2c_write_10bit(target, &[0x00, 0xA5, 0x5A])?;
+    thread::sleep(Duration::from_millis(10)); // EEPROM write cycle
+    device.i2c_write_10bit(target, &[0x00])?;
+
+    // Without Sr + 11110xx1 after the low address byte the target never sees a read
+    // and the transfer NACKs or returns bus idle bytes
+    let mut buffer = [0u8; 2];
+    device.i2c_read_10bit(target, &mut buffer)?;
+    assert_eq!(buffer, [0xA5, 0x5A]);
+    Ok(())
+}